@@ -0,0 +1,171 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+use tarjama::catalogue::Catalogue;
+use tarjama::catalogue::CatalogueBag;
+use tarjama::context;
+use tarjama::formatter::icu::IcuFormatter;
+use tarjama::formatter::Formatter;
+use tarjama::locale::EnglishVariant;
+use tarjama::locale::Locale;
+use tarjama::Translator;
+
+const GREETING: &str = "Hello, {name}! You have {count} new messages.";
+const APPLES: &str = "{0} There are no apples | {one} There is one apple | {2..4} There are few apples | There are {?} apples";
+const INVITE: &str = "{gender, male: He invited {?} people | female: She invited {?} people | other: They invited {?} people}";
+
+fn translator(
+    formatter: Box<dyn Formatter>,
+    messages: &[(&str, &str)],
+) -> Translator {
+    let mut catalogue =
+        Catalogue::new(Locale::English(EnglishVariant::Default));
+    for (id, message) in messages {
+        catalogue.insert("messages", id, message);
+    }
+
+    Translator::new(
+        formatter,
+        CatalogueBag::with_catalogues(vec![catalogue]),
+        None,
+    )
+}
+
+fn format(c: &mut Criterion) {
+    let formatter: Box<dyn Formatter> = Default::default();
+    let locale = Locale::English(EnglishVariant::Default);
+
+    let mut group = c.benchmark_group("format");
+    group.bench_function("greeting", |b| {
+        let context = context!(name = "Saif", count = 5);
+        b.iter(|| formatter.format(&locale, black_box(GREETING), &context))
+    });
+    group.bench_function("plural", |b| {
+        let context = context!(? = 12);
+        b.iter(|| formatter.format(&locale, black_box(APPLES), &context))
+    });
+    group.bench_function("select", |b| {
+        let context = context!(gender = "female", ? = 3);
+        b.iter(|| formatter.format(&locale, black_box(INVITE), &context))
+    });
+    group.finish();
+}
+
+fn trans(c: &mut Criterion) {
+    let messages =
+        [("greeting", GREETING), ("apples", APPLES), ("invite", INVITE)];
+    let translator = translator(Default::default(), &messages);
+
+    let mut group = c.benchmark_group("trans");
+    group.bench_function("greeting", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en",
+                "messages",
+                black_box("greeting"),
+                context!(name = "Saif", count = 5),
+            )
+        })
+    });
+    group.bench_function("plural", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en",
+                "messages",
+                black_box("apples"),
+                context!(? = 12),
+            )
+        })
+    });
+    group.bench_function("select", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en",
+                "messages",
+                black_box("invite"),
+                context!(gender = "female", ? = 3),
+            )
+        })
+    });
+    group.bench_function("fallback", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en_US",
+                "messages",
+                black_box("apples"),
+                context!(? = 1),
+            )
+        })
+    });
+    group.finish();
+}
+
+fn locales(c: &mut Criterion) {
+    let mut bag = CatalogueBag::new();
+    for code in [
+        "ar", "ar_TN", "de", "de_AT", "es", "es_MX", "fr", "fr_CA", "it",
+        "ja", "ko", "nl", "pt", "pt_BR", "ru", "sv", "tr", "zh", "zh_TW",
+        "en",
+    ] {
+        let mut catalogue = Catalogue::new(Locale::try_from(code).unwrap());
+        for i in 0..100 {
+            catalogue.insert("messages", &format!("message.{i}"), GREETING);
+        }
+
+        bag.insert(catalogue);
+    }
+
+    let translator = Translator::with_catalogue_bag(bag);
+
+    let mut group = c.benchmark_group("locales");
+    group.bench_function("last", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en",
+                "messages",
+                black_box("message.50"),
+                context!(name = "Saif", count = 5),
+            )
+        })
+    });
+    group.bench_function("variant", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en_GB",
+                "messages",
+                black_box("message.50"),
+                context!(name = "Saif", count = 5),
+            )
+        })
+    });
+    group.finish();
+}
+
+fn icu(c: &mut Criterion) {
+    let message = "{?, plural, =0 {There are no apples} one {There is one apple} other {There are # apples}}";
+    let formatter = IcuFormatter::new();
+    let locale = Locale::English(EnglishVariant::Default);
+    let translator = translator(formatter.into(), &[("apples", message)]);
+
+    let mut group = c.benchmark_group("icu");
+    group.bench_function("format", |b| {
+        let context = context!(? = 12);
+        b.iter(|| formatter.format(&locale, black_box(message), &context))
+    });
+    group.bench_function("trans", |b| {
+        b.iter(|| {
+            translator.trans(
+                "en",
+                "messages",
+                black_box("apples"),
+                context!(? = 12),
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, format, trans, locales, icu);
+criterion_main!(benches);
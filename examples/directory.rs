@@ -22,7 +22,7 @@ fn main() -> Result<(), Error> {
 
     for locale in locales {
         let greeting = translator.trans(
-            &locale,
+            locale,
             "messages",
             "greeting",
             context!(name = "Rust"),
@@ -31,7 +31,7 @@ fn main() -> Result<(), Error> {
 
         for i in [0, 1, 4, 10] {
             let translation = translator.trans(
-                &locale,
+                locale,
                 "messages",
                 "apple",
                 context!(? = i),
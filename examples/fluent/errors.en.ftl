@@ -0,0 +1,3 @@
+-brand = Tarjama
+
+not-found = { -brand } could not find this page.
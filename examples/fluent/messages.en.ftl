@@ -0,0 +1,17 @@
+## Shared terms
+
+-brand = Tarjama
+
+# Variables:
+#   $name (String) - The name of the user.
+welcome = Welcome to { -brand }, { $name }!
+
+emails = You have { $count ->
+        [0] no new emails
+        [one] one new email
+       *[other] { $count } new emails
+    }.
+
+login =
+    .placeholder = Your { -brand } email
+    .aria-label = Login input value
@@ -0,0 +1,15 @@
+## Termes partagés
+
+-brand = Tarjama
+
+welcome = Bienvenue sur { -brand }, { $name } !
+
+emails = Vous avez { $count ->
+        [0] aucun nouvel e-mail
+        [one] { $count } nouvel e-mail
+       *[other] { $count } nouveaux e-mails
+    }.
+
+login =
+    .placeholder = Votre e-mail { -brand }
+    .aria-label = Identifiant de connexion
@@ -0,0 +1,413 @@
+#!/usr/bin/env node
+// Generate `src/locale/data.rs` from the Unicode CLDR.
+//
+// The CLDR data is read through the `Intl` API of Node.js, which embeds it as part of ICU, so the
+// generated tables follow the CLDR release of the Node.js version used, see
+// `process.versions.cldr`:
+//
+// - language, region and script names come from `Intl.DisplayNames`, in every language with CLDR
+//   locale data,
+// - the pattern used to display a locale name, e.g. `{0} ({1})`, is derived from the names of a
+//   locale, its script and its region, through `Intl.DisplayNames`,
+// - the script of each language comes from its likely subtags, through `Intl.Locale#maximize`.
+//
+// Node.js must be built with full ICU data, which is the default, otherwise names are only
+// available in English.
+//
+// ISO 639 codes are not part of the `Intl` API, and are listed below, as published by the ISO 639-2
+// registration authority, along with the autonyms of the languages without CLDR locale data.
+//
+// Usage: `node scripts/generate-locale-data.mjs > src/locale/data.rs`
+
+// The languages with an ISO 639-1 code, along with their ISO 639-2 ( terminology ) code.
+const LANGUAGES = `
+aa aar ab abk ae ave af afr ak aka am amh an arg ar ara as asm av ava ay aym az aze ba bak be bel
+bg bul bh bih bi bis bm bam bn ben bo bod br bre bs bos ca cat ce che ch cha co cos cr cre cs ces
+cu chu cv chv cy cym da dan de deu dv div dz dzo ee ewe el ell en eng eo epo es spa et est eu eus
+fa fas ff ful fi fin fj fij fo fao fr fra fy fry ga gle gd gla gl glg gn grn gu guj gv glv ha hau
+he heb hi hin ho hmo hr hrv ht hat hu hun hy hye hz her ia ina id ind ie ile ig ibo ii iii ik ipk
+io ido is isl it ita iu iku ja jpn jv jav ka kat kg kon ki kik kj kua kk kaz kl kal km khm kn kan
+ko kor kr kau ks kas ku kur kv kom kw cor ky kir la lat lb ltz lg lug li lim ln lin lo lao lt lit
+lu lub lv lav mg mlg mh mah mi mri mk mkd ml mal mn mon mr mar ms msa mt mlt my mya na nau nb nob
+nd nde ne nep ng ndo nl nld nn nno no nor nr nbl nv nav ny nya oc oci oj oji om orm or ori os oss
+pa pan pi pli pl pol ps pus pt por qu que rm roh rn run ro ron ru rus rw kin sa san sc srd sd snd
+se sme sg sag si sin sk slk sl slv sm smo sn sna so som sq sqi sr srp ss ssw st sot su sun sv swe
+sw swa ta tam te tel tg tgk th tha ti tir tk tuk tl tgl tn tsn to ton tr tur ts tso tt tat tw twi
+ty tah ug uig uk ukr ur urd uz uzb ve ven vi vie vo vol wa wln wo wol xh xho yi yid yo yor za zha
+zh zho zu zul
+`;
+
+// The ISO 639-2 bibliographic codes that differ from the terminology codes.
+const BIBLIOGRAPHIC = {
+  bo: "tib", cs: "cze", cy: "wel", de: "ger", el: "gre", eu: "baq", fa: "per", fr: "fre",
+  hy: "arm", is: "ice", ka: "geo", mi: "mao", mk: "mac", ms: "may", my: "bur", nl: "dut",
+  ro: "rum", sk: "slo", sq: "alb", zh: "chi",
+};
+
+// The ISO 639-2 collective codes, which have no ISO 639-3 code.
+const COLLECTIVE = ["bh"];
+
+// The autonyms of the languages without CLDR locale data, as listed in the ISO 639-1 code
+// tables.
+const AUTONYMS = {
+  aa: "Qafaraf", ab: "аԥсуа бызшәа", ae: "avesta", an: "aragonés", av: "авар мацӀ",
+  ay: "aymar aru", bh: "भोजपुरी", bi: "Bislama", ch: "Chamoru", co: "corsu",
+  cr: "ᓀᐦᐃᔭᐍᐏᐣ", cu: "ѩзыкъ словѣньскъ", dv: "ދިވެހި", fj: "vosa Vakaviti",
+  gn: "Avañeʼẽ", ho: "Hiri Motu", ht: "kreyòl ayisyen", hz: "Otjiherero", ik: "Iñupiaq",
+  io: "Ido", iu: "ᐃᓄᒃᑎᑐᑦ", kg: "Kikongo", kj: "Oshikwanyama", kr: "Kanuri",
+  kv: "коми кыв", la: "Latina", li: "Limburgs", mh: "Kajin M̧ajeļ", na: "Dorerin Naoero",
+  ng: "Oshindonga", nr: "isiNdebele", nv: "Diné bizaad", ny: "chiCheŵa", oj: "ᐊᓂᔑᓈᐯᒧᐎᓐ",
+  pi: "पाऴि", sm: "Gagana faʻa Sāmoa", ss: "siSwati", ts: "Xitsonga", tw: "Twi",
+  ty: "reo Tahiti", ve: "Tshivenḓa", vo: "Volapük", wa: "walon",
+};
+
+// The separator of the names of a table, which must not be part of any name.
+const SEPARATOR = "\t";
+
+// The pattern of a locale name, as `[open, separator, close]`, e.g. `English (Latin, Canada)`.
+const PATTERN = [" (", ", ", ")"];
+
+const UPPER = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWER = UPPER.toLowerCase();
+
+const languages = [];
+const words = LANGUAGES.trim().split(/\s+/);
+for (let index = 0; index < words.length; index += 2) {
+  languages.push([words[index], words[index + 1]]);
+}
+
+// region codes: ISO 3166-1 alpha-2 codes, and UN M49 numeric codes.
+const regions = [];
+for (const first of UPPER) {
+  for (const second of UPPER) {
+    regions.push(first + second);
+  }
+}
+for (let number = 0; number < 1000; number++) {
+  regions.push(String(number).padStart(3, "0"));
+}
+
+// script codes: ISO 15924 codes.
+const scripts = [];
+for (const first of UPPER) {
+  for (const second of LOWER) {
+    for (const third of LOWER) {
+      for (const fourth of LOWER) {
+        scripts.push(first + second + third + fourth);
+      }
+    }
+  }
+}
+
+// Whether the code is kept as-is by CLDR, i.e. is neither deprecated nor an alias.
+const canonical = {
+  region: (code) => new Intl.Locale(`und-${code}`).region === code,
+  script: (code) => new Intl.Locale(`und-${code}`).script === code,
+};
+
+// The CLDR locale of a language, e.g. `fil` for `tl`, if it has locale data.
+function cldr(code) {
+  const [locale] = Intl.getCanonicalLocales(code);
+
+  return Intl.DisplayNames.supportedLocalesOf([locale]).length > 0 ? locale : undefined;
+}
+
+// The display languages of the name tables: every language with CLDR locale data.
+const displays = languages.map(([code]) => code).filter((code) => cldr(code) !== undefined);
+
+function names(display, type) {
+  return new Intl.DisplayNames([cldr(display)], {
+    type,
+    fallback: "none",
+    languageDisplay: "standard",
+  });
+}
+
+// The codes named in English, all other languages name a subset of them.
+const codes = {
+  language: languages.map(([code]) => code),
+  region: regions.sort().filter((code) => canonical.region(code)),
+  script: scripts.filter((code) => canonical.script(code)),
+};
+for (const type of ["region", "script"]) {
+  codes[type] = codes[type].filter((code) => names("en", type).of(code) !== undefined);
+}
+
+function endonym(code) {
+  return cldr(code) === undefined ? AUTONYMS[code] : names(code, "language").of(code);
+}
+
+// Returns the pattern of a locale name in the given display language, as `[open, separator,
+// close]`, by locating the names of a language, a script and a region within a locale name.
+function pattern(display) {
+  // names fall back to codes here, as ICU does when formatting a locale name.
+  const of = (type, code) =>
+    new Intl.DisplayNames([cldr(display)], { type, languageDisplay: "standard" }).of(code);
+  const [language, script, region] = [of("language", "de"), of("script", "Latn"), of("region", "AT")];
+  const name = of("language", "de-Latn-AT");
+  const rest = name.slice(language.length);
+  const start = rest.indexOf(script);
+  const end = rest.indexOf(region, start + script.length);
+  if (!name.startsWith(language) || start < 0 || end < 0) {
+    throw new Error(`unexpected locale name pattern for \`${display}\`: ${name}`);
+  }
+
+  return [
+    rest.slice(0, start),
+    rest.slice(start + script.length, end),
+    rest.slice(end + region.length),
+  ];
+}
+
+// Returns a Rust string literal, escaping invisible characters, e.g. the zero width spaces used
+// in Khmer and Lao names.
+function string(value) {
+  const escaped = value
+    .replace(/\\/g, "\\\\")
+    .replace(/"/g, '\\"')
+    .replace(/\t/g, "\\t")
+    .replace(/[\u00ad\u200b\u2060\ufeff]/g, (c) => `\\u{${c.codePointAt(0).toString(16)}}`);
+
+  return `"${escaped}"`;
+}
+
+function list(values) {
+  const lines = [];
+  let line = "   ";
+  for (const value of values.map(string)) {
+    if (line.length + value.length + 2 > 100) {
+      lines.push(line);
+      line = "   ";
+    }
+    line += ` ${value},`;
+  }
+  lines.push(line);
+
+  return lines.join("\n");
+}
+
+// Returns the names of the codes, joined using the separator, as a string literal, wrapped using
+// `\` line continuations.
+function joined(display, type) {
+  const values = codes[type].map((code) => {
+    const name = names(display, type).of(code) ?? "";
+    if (name.includes(SEPARATOR) || name.trim() !== name) {
+      throw new Error(`unexpected ${type} name for \`${code}\` in \`${display}\`: ${name}`);
+    }
+
+    return name;
+  });
+
+  const lines = [];
+  let line = "";
+  for (const [index, value] of values.entries()) {
+    const part = index + 1 < values.length ? value + SEPARATOR : value;
+    if (line.length > 0 && line.length + part.length > 80) {
+      lines.push(line);
+      line = "";
+    }
+    line += part;
+  }
+  lines.push(line);
+
+  return `"${lines.map((line) => string(line).slice(1, -1)).join("\\\n         ")}"`;
+}
+
+function table(type) {
+  return displays
+    .map((display) => `    (${string(display)}, ${joined(display, type)}),`)
+    .join("\n");
+}
+
+const patterns = displays
+  .map((display) => [display, pattern(display)])
+  .filter(([, parts]) => parts.join() !== PATTERN.join())
+  .map(([display, parts]) => `    (${string(display)}, [${parts.map(string).join(", ")}]),`);
+
+const rows = languages.map(([code, terminology]) => {
+  const bibliographic = BIBLIOGRAPHIC[code] ?? terminology;
+  const iso639_3 = COLLECTIVE.includes(code) ? "None" : `Some(${string(terminology)})`;
+  const script = new Intl.Locale(code).maximize().script;
+
+  return `    (${string(code)}, Language { endonym: ${string(endonym(code))}, iso639_2: ${string(terminology)}, iso639_2b: ${string(bibliographic)}, iso639_3: ${iso639_3}, script: ${string(script)} }),`;
+});
+
+process.stdout.write(`// @generated by \`scripts/generate-locale-data.mjs\`, from Unicode CLDR ${process.versions.cldr}.
+//
+// Locale names and scripts are derived from the Unicode CLDR, Copyright © Unicode, Inc., and
+// distributed under the Unicode License v3, see \`LICENSE-UNICODE\`.
+
+use crate::locale::Direction;
+
+/// Metadata of a language.
+pub(super) struct Language {
+    pub(super) endonym: &'static str,
+    pub(super) iso639_2: &'static str,
+    pub(super) iso639_2b: &'static str,
+    pub(super) iso639_3: Option<&'static str>,
+    pub(super) script: &'static str,
+}
+
+/// The metadata of unknown languages.
+static UNDETERMINED: Language = Language {
+    endonym: "",
+    iso639_2: "und",
+    iso639_2b: "und",
+    iso639_3: Some("und"),
+    script: "Zzzz",
+};
+
+/// Return the metadata of a language, given its ISO 639-1 code.
+pub(super) fn language(code: &str) -> &'static Language {
+    LANGUAGES
+        .binary_search_by_key(&code, |(code, _)| code)
+        .map_or(&UNDETERMINED, |index| &LANGUAGES[index].1)
+}
+
+/// Return the name of a language, given its ISO 639-1 code, in the given display language.
+pub(super) fn language_name(
+    display: &str,
+    code: &str,
+) -> Option<&'static str> {
+    name(LANGUAGE_CODES, LANGUAGE_NAMES, display, code)
+}
+
+/// Return the name of a region, given its ISO 3166-1 or UN M49 code, in the given display
+/// language.
+pub(super) fn region_name(display: &str, code: &str) -> Option<&'static str> {
+    name(REGION_CODES, REGION_NAMES, display, code)
+}
+
+/// Return the name of a script, given its ISO 15924 code, in the given display language.
+pub(super) fn script_name(display: &str, code: &str) -> Option<&'static str> {
+    name(SCRIPT_CODES, SCRIPT_NAMES, display, code)
+}
+
+/// Return \`true\` if names are available in the given display language.
+pub(super) fn has_names(display: &str) -> bool {
+    LANGUAGE_NAMES
+        .binary_search_by_key(&display, |(display, _)| display)
+        .is_ok()
+}
+
+/// Return the pattern of a locale name in the given display language, as \`(open, separator,
+/// close)\`, e.g. \`(" (", ", ", ")")\` for \`English (Latin, Canada)\`.
+pub(super) fn pattern(
+    display: &str,
+) -> (&'static str, &'static str, &'static str) {
+    match PATTERNS.binary_search_by_key(&display, |(display, _)| display) {
+        Ok(index) => {
+            let [open, separator, close] = PATTERNS[index].1;
+
+            (open, separator, close)
+        }
+        Err(_) => (${PATTERN.map(string).join(", ")}),
+    }
+}
+
+/// Return the writing direction of a script, given its ISO 15924 code.
+pub(super) fn direction(script: &str) -> Direction {
+    match script {
+        "Adlm" | "Arab" | "Hebr" | "Mand" | "Nkoo" | "Rohg" | "Samr"
+        | "Syrc" | "Thaa" => Direction::RightToLeft,
+        _ => Direction::LeftToRight,
+    }
+}
+
+fn name(
+    codes: &[&str],
+    table: &'static [(&str, &'static str)],
+    display: &str,
+    code: &str,
+) -> Option<&'static str> {
+    let index = codes.binary_search(&code).ok()?;
+    let names = table
+        .binary_search_by_key(&display, |(display, _)| display)
+        .map(|index| table[index].1)
+        .ok()?;
+
+    names.split('\\t').nth(index).filter(|name| !name.is_empty())
+}
+
+// languages, sorted by ISO 639-1 code.
+#[rustfmt::skip]
+static LANGUAGES: &[(&str, Language)] = &[
+${rows.join("\n")}
+];
+
+// ISO 639-1 codes of the language names.
+#[rustfmt::skip]
+static LANGUAGE_CODES: &[&str] = &[
+${list(codes.language)}
+];
+
+// ISO 3166-1 and UN M49 codes of the region names.
+#[rustfmt::skip]
+static REGION_CODES: &[&str] = &[
+${list(codes.region)}
+];
+
+// ISO 15924 codes of the script names.
+#[rustfmt::skip]
+static SCRIPT_CODES: &[&str] = &[
+${list(codes.script)}
+];
+
+// language names, by display language, in the order of \`LANGUAGE_CODES\`, joined using
+// tabs, or empty if unknown.
+#[rustfmt::skip]
+static LANGUAGE_NAMES: &[(&str, &str)] = &[
+${table("language")}
+];
+
+// region names, by display language, in the order of \`REGION_CODES\`, joined using
+// tabs, or empty if unknown.
+#[rustfmt::skip]
+static REGION_NAMES: &[(&str, &str)] = &[
+${table("region")}
+];
+
+// script names, by display language, in the order of \`SCRIPT_CODES\`, joined using
+// tabs, or empty if unknown.
+#[rustfmt::skip]
+static SCRIPT_NAMES: &[(&str, &str)] = &[
+${table("script")}
+];
+
+// locale name patterns, by display language, as \`[open, separator, close]\`, when different from
+// the default pattern.
+#[rustfmt::skip]
+static PATTERNS: &[(&str, [&str; 3])] = &[
+${patterns.join("\n")}
+];
+
+#[cfg(test)]
+mod test {
+    use crate::locale::data::LANGUAGES;
+    use crate::locale::data::LANGUAGE_CODES;
+    use crate::locale::data::LANGUAGE_NAMES;
+    use crate::locale::data::PATTERNS;
+    use crate::locale::data::REGION_CODES;
+    use crate::locale::data::REGION_NAMES;
+    use crate::locale::data::SCRIPT_CODES;
+    use crate::locale::data::SCRIPT_NAMES;
+
+    #[test]
+    fn sorted_tables() {
+        assert!(LANGUAGES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(PATTERNS.windows(2).all(|w| w[0].0 < w[1].0));
+
+        for (codes, table) in [
+            (LANGUAGE_CODES, LANGUAGE_NAMES),
+            (REGION_CODES, REGION_NAMES),
+            (SCRIPT_CODES, SCRIPT_NAMES),
+        ] {
+            assert!(codes.windows(2).all(|w| w[0] < w[1]));
+            assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+            for (_, names) in table {
+                assert_eq!(names.split('\\t').count(), codes.len());
+            }
+        }
+    }
+}
+`);
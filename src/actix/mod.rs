@@ -12,7 +12,8 @@ use std::{future::Future, pin::Pin};
 /// Middleware to inject the translator and locale into the request extensions.
 ///
 /// The middleware will look for the `Accept-Language` header in the request and
-/// set the locale to the first language that is supported by the translator.
+/// set the locale to the best available locale of the translator, according to the
+/// requested languages and their quality weights.
 ///
 /// If no supported language is found, the default locale will be used.
 ///
@@ -81,7 +82,7 @@ where
 
     fn new_transform(&self, service: S) -> Self::Future {
         let mut translator = self.translator.clone();
-        let default_locale = self.default_locale.clone();
+        let default_locale = self.default_locale;
         translator.set_fallback_locale(default_locale);
 
         ready(Ok(TranslatorMiddlewareService {
@@ -118,12 +119,17 @@ where
         let mut locale = self.default_locale;
         if let Some(header_value) = req.headers().get("Accept-Language") {
             if let Ok(header) = header_value.to_str() {
-                let langauges = accept_language::parse(header);
-                for language in langauges {
-                    if let Ok(accept_locale) = Locale::try_from(language) {
-                        locale = accept_locale;
-                        break;
-                    }
+                let languages = accept_language::parse_with_quality(header)
+                    .into_iter()
+                    .filter_map(|(language, quality)| {
+                        Locale::try_from(language)
+                            .ok()
+                            .map(|locale| (locale, quality))
+                    });
+
+                if let Some(negotiated) = self.translator.negotiate(languages)
+                {
+                    locale = negotiated;
                 }
             }
         }
@@ -165,7 +171,7 @@ impl FromRequest for Locale {
         _payload: &mut Payload,
     ) -> Self::Future {
         if let Some(locale) = req.extensions().get::<Locale>() {
-            ready(Ok(locale.clone()))
+            ready(Ok(*locale))
         } else {
             ready(Err(actix_web::error::ErrorInternalServerError(
                 "Locale not found",
@@ -1,18 +1,21 @@
 use crate::locale::Locale;
+use crate::negotiation::negotiate;
 
-use core::marker::Send;
-use core::marker::Sync;
+use std::any::Any;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::OnceLock;
 
+/// A collection of catalogues, indexed by locale.
+///
+/// When multiple catalogues share the same locale, they are kept in insertion order, and the
+/// catalogue inserted first takes precedence when looking up a message.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CatalogueBag {
-    catalogues: Vec<Catalogue>,
+    catalogues: HashMap<Locale, Vec<Catalogue>>,
 }
 
-unsafe impl Sync for CatalogueBag {}
-unsafe impl Send for CatalogueBag {}
-
 impl CatalogueBag {
     /// Creates a new empty `CatalogueBag`.
     ///
@@ -29,7 +32,7 @@ impl CatalogueBag {
     /// assert_eq!(catalogue.locale(), &Locale::Arabic(ArabicVariant::Tunisia));
     /// ```
     pub fn new() -> Self {
-        Self { catalogues: Vec::new() }
+        Self { catalogues: HashMap::new() }
     }
 
     /// Creates a `CatalogueBag` containing the given catalogues.
@@ -57,7 +60,12 @@ impl CatalogueBag {
     where
         T: Into<Vec<Catalogue>>,
     {
-        Self { catalogues: catalogues.into() }
+        let mut bag = Self::new();
+        for catalogue in catalogues.into() {
+            bag.insert(catalogue);
+        }
+
+        bag
     }
 
     /// Moves all the catalogues of `other` into `self`, leaving `other` empty.
@@ -93,7 +101,7 @@ impl CatalogueBag {
     ///
     /// assert_eq!(
     ///     bag.get(&Locale::English(EnglishVariant::Default)),
-    ///     vec![&Catalogue::with_messages(
+    ///     [Catalogue::with_messages(
     ///         Locale::English(EnglishVariant::Default),
     ///         HashMap::from([(
     ///             "messages".to_owned(),
@@ -105,7 +113,9 @@ impl CatalogueBag {
     /// assert!(other.is_empty());
     /// ```
     pub fn append(&mut self, other: &mut CatalogueBag) {
-        self.catalogues.append(&mut other.catalogues);
+        for (locale, mut catalogues) in other.catalogues.drain() {
+            self.catalogues.entry(locale).or_default().append(&mut catalogues);
+        }
     }
 
     /// Insert a catalogue into the bag.
@@ -126,23 +136,26 @@ impl CatalogueBag {
     ///
     /// assert_eq!(
     ///   bag.get(&Locale::English(EnglishVariant::Default)),
-    ///   vec![
-    ///     &Catalogue::new(Locale::English(EnglishVariant::Default))
+    ///   [
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default))
     ///   ]
     /// );
     ///
     /// assert_eq!(
     ///   bag.get(&Locale::French(FrenchVariant::Default)),
-    ///   vec![
-    ///     &Catalogue::new(Locale::French(FrenchVariant::Default))
+    ///   [
+    ///     Catalogue::new(Locale::French(FrenchVariant::Default))
     ///   ]
     /// );
     /// ```
     pub fn insert(&mut self, catalogue: Catalogue) {
-        self.catalogues.push(catalogue);
+        self.catalogues
+            .entry(*catalogue.locale())
+            .or_default()
+            .push(catalogue);
     }
 
-    /// Returns a reference to the catalogues corresponding to the locale.
+    /// Returns the catalogues corresponding to the locale, in insertion order.
     ///
     /// # Examples
     /// ```
@@ -160,8 +173,8 @@ impl CatalogueBag {
     ///
     /// assert_eq!(
     ///   bag.get(&Locale::English(EnglishVariant::Default)),
-    ///   vec![
-    ///     &Catalogue::new(Locale::English(EnglishVariant::Default)),
+    ///   [
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
     ///   ]
     /// );
     ///
@@ -173,24 +186,117 @@ impl CatalogueBag {
     ///
     /// assert_eq!(
     ///   bag.get(&Locale::English(EnglishVariant::Default)),
-    ///   vec![
-    ///     &Catalogue::new(Locale::English(EnglishVariant::Default)),
-    ///     &Catalogue::new(Locale::English(EnglishVariant::Default))
+    ///   [
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default))
     ///   ]
     /// );
     ///
     /// assert_eq!(
     ///   bag.get(&Locale::French(FrenchVariant::Default)),
-    ///   vec![
-    ///     &Catalogue::new(Locale::French(FrenchVariant::Default)),
+    ///   [
+    ///     Catalogue::new(Locale::French(FrenchVariant::Default)),
     ///   ]
     /// );
+    ///
+    /// assert!(bag.get(&Locale::Hungarian).is_empty());
     /// ```
-    pub fn get(&self, locale: &Locale) -> Vec<&Catalogue> {
-        self.catalogues
+    pub fn get(&self, locale: &Locale) -> &[Catalogue] {
+        match self.catalogues.get(locale) {
+            Some(catalogues) => catalogues,
+            None => &[],
+        }
+    }
+
+    /// Returns a reference to the message corresponding to the locale, domain, and message id,
+    /// looking it up in the catalogues of the locale in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    ///
+    /// let mut first = Catalogue::new(Locale::English(EnglishVariant::Default));
+    /// first.insert("messages", "greeting", "Hello, {name}!");
+    ///
+    /// let mut second = Catalogue::new(Locale::English(EnglishVariant::Default));
+    /// second.insert("messages", "greeting", "Hi, {name}!");
+    /// second.insert("messages", "farewell", "Goodbye, {name}!");
+    ///
+    /// let bag = CatalogueBag::with_catalogues(vec![first, second]);
+    ///
+    /// let locale = Locale::English(EnglishVariant::Default);
+    ///
+    /// assert_eq!(bag.get_message(&locale, "messages", "greeting").unwrap().as_str(), "Hello, {name}!");
+    /// assert_eq!(bag.get_message(&locale, "messages", "farewell").unwrap().as_str(), "Goodbye, {name}!");
+    /// assert!(bag.get_message(&locale, "messages", "foo").is_none());
+    /// assert!(bag.get_message(&Locale::Hungarian, "messages", "greeting").is_none());
+    /// ```
+    pub fn get_message(
+        &self,
+        locale: &Locale,
+        domain: &str,
+        id: &str,
+    ) -> Option<&Message> {
+        self.get(locale)
             .iter()
-            .filter(|c| c.locale() == locale)
-            .collect::<Vec<&Catalogue>>()
+            .find_map(|catalogue| catalogue.get_message(domain, id))
+    }
+
+    /// Returns an iterator over the locales of the catalogues in the bag, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// let bag = CatalogueBag::with_catalogues(vec![
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
+    /// ]);
+    ///
+    /// assert_eq!(bag.locales().collect::<Vec<_>>(), vec![&Locale::English(EnglishVariant::Default)]);
+    /// ```
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.catalogues.keys()
+    }
+
+    /// Returns the best available locale in the bag for the given requested locales, and their quality
+    /// weights.
+    ///
+    /// See [`crate::negotiation::negotiate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::SpanishVariant;
+    ///
+    /// let bag = CatalogueBag::with_catalogues(vec![
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
+    ///     Catalogue::new(Locale::Spanish(SpanishVariant::LatinAmerica)),
+    ///     Catalogue::new(Locale::Spanish(SpanishVariant::Default)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     bag.negotiate([(Locale::Spanish(SpanishVariant::Mexico), 1.0), (Locale::English(EnglishVariant::Default), 0.5)]),
+    ///     Some(Locale::Spanish(SpanishVariant::LatinAmerica))
+    /// );
+    /// ```
+    pub fn negotiate<I>(&self, requested: I) -> Option<Locale>
+    where
+        I: IntoIterator<Item = (Locale, f32)>,
+    {
+        negotiate(requested, self.locales())
     }
 
     /// Returns `true` if the bag contains no catalogues.
@@ -215,14 +321,93 @@ impl CatalogueBag {
     }
 }
 
+/// Serialize a `CatalogueBag` as a sequence of catalogues.
+///
+/// Catalogues are sorted by locale code, and catalogues of the same locale are kept in precedence
+/// order, so the output is stable.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::catalogue::Catalogue;
+/// use tarjama::catalogue::CatalogueBag;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+///
+/// let mut french = Catalogue::new(Locale::French(FrenchVariant::Default));
+/// french.insert("messages", "greeting", "Bonjour, {name} !");
+///
+/// let mut english = Catalogue::new(Locale::English(EnglishVariant::UnitedKingdom));
+/// english.insert("messages", "greeting", "Hello, {name}!");
+/// english.insert("messages", "farewell", "Goodbye!");
+///
+/// let bag = CatalogueBag::with_catalogues([french, english]);
+/// let json = serde_json::to_string(&bag).unwrap();
+///
+/// assert_eq!(
+///     json,
+///     concat!(
+///         r#"[{"locale":"en_GB","messages":{"messages":{"farewell":"Goodbye!","greeting":"Hello, {name}!"}}},"#,
+///         r#"{"locale":"fr","messages":{"messages":{"greeting":"Bonjour, {name} !"}}}]"#,
+///     )
+/// );
+///
+/// assert_eq!(serde_json::from_str::<CatalogueBag>(&json).unwrap(), bag);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for CatalogueBag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut catalogues: Vec<&Catalogue> =
+            self.catalogues.values().flatten().collect();
+        // a stable sort, keeping catalogues of the same locale in precedence order.
+        catalogues.sort_by_key(|catalogue| catalogue.locale.code());
+
+        serializer.collect_seq(catalogues)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CatalogueBag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::<Catalogue>::deserialize(deserializer).map(Self::with_catalogues)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Catalogue {
     locale: Locale,
-    messages: HashMap<String, HashMap<String, String>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "sorted"))]
+    messages: HashMap<String, HashMap<String, Message>>,
 }
 
-unsafe impl Sync for Catalogue {}
-unsafe impl Send for Catalogue {}
+/// Serialize the messages of a catalogue sorted by domain, then by id.
+#[cfg(feature = "serde")]
+fn sorted<S>(
+    messages: &HashMap<String, HashMap<String, Message>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use std::collections::BTreeMap;
+
+    serializer.collect_map(
+        messages
+            .iter()
+            .map(|(domain, messages)| {
+                (domain, messages.iter().collect::<BTreeMap<_, _>>())
+            })
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
 
 /// A message catalogue for a specific locale.
 ///
@@ -283,7 +468,21 @@ impl Catalogue {
         locale: Locale,
         messages: HashMap<String, HashMap<String, String>>,
     ) -> Self {
-        Catalogue { locale, messages }
+        Catalogue {
+            locale,
+            messages: messages
+                .into_iter()
+                .map(|(domain, messages)| {
+                    (
+                        domain,
+                        messages
+                            .into_iter()
+                            .map(|(id, message)| (id, message.into()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
     }
 
     /// Returns a reference to the locale of the catalogue.
@@ -327,8 +526,7 @@ impl Catalogue {
     /// assert_eq!(catalogue.domains(), vec![&"errors".to_string(), &"messages".to_string()]);
     /// ```
     pub fn domains(&self) -> Vec<&String> {
-        let mut domains: Vec<&String> =
-            self.messages.keys().into_iter().collect();
+        let mut domains: Vec<&String> = self.messages.keys().collect();
         domains.sort();
 
         domains
@@ -352,6 +550,27 @@ impl Catalogue {
     /// assert_eq!(catalogue.get("bar", "foo"), None);
     /// ```
     pub fn get(&self, domain: &str, id: &str) -> Option<&String> {
+        self.get_message(domain, id).map(|message| &message.source)
+    }
+
+    /// Returns a reference to the message corresponding to the message id and domain, along
+    /// with its compiled form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// let mut catalogue = Catalogue::new(Locale::English(EnglishVariant::Default));
+    ///
+    /// catalogue.insert("messages", "greeting", "Hello, {name}!");
+    ///
+    /// assert_eq!(catalogue.get_message("messages", "greeting").unwrap().as_str(), "Hello, {name}!");
+    /// assert!(catalogue.get_message("messages", "foo").is_none());
+    /// ```
+    pub fn get_message(&self, domain: &str, id: &str) -> Option<&Message> {
         match self.messages.get(domain) {
             Some(messages) => messages.get(id),
             None => None,
@@ -375,10 +594,10 @@ impl Catalogue {
     /// catalogue.insert("messages", "greeting", "Hello, {name}!");
     ///
     /// assert_eq!(catalogue.get_all("messages"), Some(&HashMap::from([
-    ///   ("greeting".to_string(), "Hello, {name}!".to_string())
+    ///   ("greeting".to_string(), "Hello, {name}!".into())
     /// ])));
     /// ```
-    pub fn get_all(&self, domain: &str) -> Option<&HashMap<String, String>> {
+    pub fn get_all(&self, domain: &str) -> Option<&HashMap<String, Message>> {
         self.messages.get(domain)
     }
 
@@ -410,11 +629,12 @@ impl Catalogue {
         message: &str,
     ) -> Option<String> {
         match self.messages.entry(domain.to_owned()) {
-            Entry::Occupied(mut o) => {
-                o.get_mut().insert(id.to_owned(), message.to_owned())
-            }
+            Entry::Occupied(mut o) => o
+                .get_mut()
+                .insert(id.to_owned(), message.into())
+                .map(|message| message.source),
             Entry::Vacant(v) => {
-                v.insert(HashMap::from([(id.to_owned(), message.to_owned())]));
+                v.insert(HashMap::from([(id.to_owned(), message.into())]));
 
                 None
             }
@@ -440,7 +660,9 @@ impl Catalogue {
     /// ```
     pub fn remove(&mut self, domain: &str, id: &str) -> Option<String> {
         match self.messages.entry(domain.to_owned()) {
-            Entry::Occupied(mut o) => o.get_mut().remove(id),
+            Entry::Occupied(mut o) => {
+                o.get_mut().remove(id).map(|message| message.source)
+            }
             Entry::Vacant(_) => None,
         }
     }
@@ -473,8 +695,119 @@ impl Catalogue {
         domain: &str,
     ) -> Option<HashMap<String, String>> {
         match self.messages.entry(domain.to_owned()) {
-            Entry::Occupied(o) => Some(o.remove()),
+            Entry::Occupied(o) => Some(
+                o.remove()
+                    .into_iter()
+                    .map(|(id, message)| (id, message.source))
+                    .collect(),
+            ),
             Entry::Vacant(_) => None,
         }
     }
 }
+
+/// A catalogue message.
+///
+/// Along with its source, a message holds the compiled form produced by the formatter the first
+/// time it is formatted, so that subsequent translations don't have to parse it again.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::catalogue::Message;
+///
+/// let message = Message::from("Hello, {name}!");
+///
+/// assert_eq!(message.as_str(), "Hello, {name}!");
+/// assert_eq!(message.compiled(|source| source.len()), Some(&14));
+///
+/// // the message is only compiled once.
+/// assert_eq!(message.compiled(|_| 0usize), Some(&14));
+///
+/// // compiled into a different type.
+/// assert_eq!(message.compiled(|source| source.to_string()), None);
+/// ```
+#[derive(Clone, Default)]
+pub struct Message {
+    source: String,
+    compiled: OnceLock<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Message {
+    /// Creates a new, not yet compiled, message.
+    pub fn new(source: String) -> Self {
+        Message { source, compiled: OnceLock::new() }
+    }
+
+    /// Returns the message source.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the compiled form of the message, compiling it using `compile` on first access.
+    ///
+    /// Returns `None` if the message has already been compiled into a different type, e.g. by
+    /// another formatter.
+    pub fn compiled<T, F>(&self, compile: F) -> Option<&T>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&str) -> T,
+    {
+        self.compiled
+            .get_or_init(|| Arc::new(compile(&self.source)))
+            .downcast_ref::<T>()
+    }
+}
+
+impl From<String> for Message {
+    fn from(source: String) -> Self {
+        Message::new(source)
+    }
+}
+
+impl From<&str> for Message {
+    fn from(source: &str) -> Self {
+        Message::new(source.to_string())
+    }
+}
+
+impl std::fmt::Debug for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Message").field(&self.source).finish()
+    }
+}
+
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Serialize a `Message` as its source.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Message {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Message::new)
+    }
+}
+
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for Message {}
@@ -1,24 +1,197 @@
+use crate::error::Error;
+use crate::plural::PluralOperands;
+
 use std::fmt::Display;
 
 #[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Context {
     pub values: Vec<(String, Value)>,
-    pub count: Option<i64>,
+    pub count: Option<Count>,
+    /// Select plural messages using ordinal rules ( 1st, 2nd, 3rd ), instead of cardinal rules.
+    pub ordinal: bool,
 }
 
 impl Context {
     pub fn new(values: Vec<(String, Value)>, count: Option<i64>) -> Context {
-        Context { values, count }
+        Context { values, count: count.map(Count::Integer), ordinal: false }
+    }
+
+    /// Set the count used to select a plural message, which can be a decimal number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::context::Context;
+    /// use tarjama::context::Count;
+    ///
+    /// let context = Context::new(vec![], None).with_count(1.5);
+    ///
+    /// assert_eq!(context.count, Some(Count::from(1.5)));
+    /// ```
+    pub fn with_count<C>(mut self, count: C) -> Context
+    where
+        C: Into<Count>,
+    {
+        self.count = Some(count.into());
+
+        self
+    }
+
+    /// Set whether the count should be treated as an ordinal number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::context::Context;
+    ///
+    /// let context = Context::new(vec![], Some(2)).with_ordinal(true);
+    ///
+    /// assert!(context.ordinal);
+    /// ```
+    pub fn with_ordinal(mut self, ordinal: bool) -> Context {
+        self.ordinal = ordinal;
+
+        self
     }
 }
 
 impl From<Option<i64>> for Context {
     fn from(value: Option<i64>) -> Self {
-        Context { values: vec![], count: value }
+        Context::new(vec![], value)
+    }
+}
+
+/// The count used to select a plural message.
+///
+/// Decimal counts keep their visible fraction digits, which CLDR plural rules depend on, e.g.
+/// `1.0` is `other` in English, while `1` is `one`. A count created from a float always has at
+/// least one fraction digit, use the `&str` conversion for an exact number of digits.
+///
+/// With the `serde` feature, counts are serialized as plain numbers.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::context::Count;
+///
+/// assert_eq!(Count::from(3).to_string(), "3");
+/// assert_eq!(Count::from(1.5).to_string(), "1.5");
+/// assert_eq!(Count::from(1.0).to_string(), "1.0");
+/// assert_eq!(Count::from(1.0).operands().v, 1);
+///
+/// let count = Count::try_from("1.50").unwrap();
+///
+/// assert_eq!(count.to_string(), "1.50");
+/// assert_eq!(count.operands().v, 2);
+/// assert_eq!(Count::try_from("3").unwrap(), Count::Integer(3));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Count {
+    Integer(i64),
+    Decimal { value: f64, operands: PluralOperands },
+}
+
+impl Count {
+    /// Return the CLDR plural operands of the count.
+    pub fn operands(&self) -> PluralOperands {
+        match self {
+            Count::Integer(i) => PluralOperands::from(*i),
+            Count::Decimal { operands, .. } => *operands,
+        }
+    }
+}
+
+/// Create a `Count` from the string representation of a number, keeping its fraction digits.
+impl TryFrom<&str> for Count {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let operands = PluralOperands::try_from(value)?;
+        if operands.v == 0 {
+            if let Ok(integer) = value.trim().parse::<i64>() {
+                return Ok(Count::Integer(integer));
+            }
+        }
+
+        let value = match value.trim().starts_with('-') {
+            true => -operands.n,
+            false => operands.n,
+        };
+
+        Ok(Count::Decimal { value, operands })
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Count::Integer(i) => write!(f, "{}", i),
+            Count::Decimal { value, operands } => {
+                write!(f, "{:.*}", operands.v, value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Count {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Count::Integer(i) => serializer.serialize_i64(*i),
+            Count::Decimal { value, .. } => serializer.serialize_f64(*value),
+        }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Count {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Number {
+            Integer(i64),
+            Decimal(f64),
+        }
+
+        Ok(match Number::deserialize(deserializer)? {
+            Number::Integer(i) => Count::Integer(i),
+            Number::Decimal(d) => Count::from(d),
+        })
+    }
+}
+
+/// A context value.
+///
+/// With the `serde` feature, values are serialized as plain strings and numbers.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use tarjama::context;
+/// use tarjama::context::Context;
+///
+/// let context = context!(name = "Saif", apples = 3, ? = 3);
+/// let json = serde_json::to_string(&context).unwrap();
+///
+/// assert_eq!(json, r#"{"values":[["name","Saif"],["apples",3]],"count":3,"ordinal":false}"#);
+/// assert_eq!(serde_json::from_str::<Context>(&json).unwrap(), context);
+/// # }
+/// ```
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum Value {
     String(String),
     Integer(i64),
@@ -53,9 +226,15 @@ macro_rules! implement_integer {
             }
         }
 
+        impl From<$t> for Count {
+            fn from(value: $t) -> Self {
+                Count::Integer(value.into())
+            }
+        }
+
         impl From<$t> for Context {
             fn from(value: $t) -> Self {
-                Context { values: vec![], count: Some(value.into()) }
+                Context::new(vec![], None).with_count(value)
             }
         }
     };
@@ -68,6 +247,31 @@ macro_rules! implement_double {
                 Value::Double(value.into())
             }
         }
+
+        impl From<$t> for Count {
+            fn from(value: $t) -> Self {
+                // parse the shortest representation, so `1.1f32` doesn't become `1.100000023841858`,
+                // and keep a fraction digit, so `1.0` isn't mistaken for the integer `1`.
+                let mut representation = value.to_string();
+                if !representation.contains('.') {
+                    representation.push_str(".0");
+                }
+
+                match Count::try_from(representation.as_str()) {
+                    Ok(count) => count,
+                    Err(_) => Count::Decimal {
+                        value: value.into(),
+                        operands: PluralOperands::from(f64::from(value)),
+                    },
+                }
+            }
+        }
+
+        impl From<$t> for Context {
+            fn from(value: $t) -> Self {
+                Context::new(vec![], None).with_count(value)
+            }
+        }
     };
 }
 
@@ -0,0 +1,216 @@
+use crate::locale::Locale;
+
+/// Returns the preferred locales of the user, in order, as found in the process environment.
+///
+/// See [`locales_from`].
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::environment;
+/// use tarjama::Translator;
+///
+/// let translator = Translator::default();
+///
+/// let locales = environment::locales();
+/// let chain = translator.preferred_fallback_chain(&locales);
+/// ```
+pub fn locales() -> Vec<Locale> {
+    locales_from(|name| std::env::var(name).ok())
+}
+
+/// Returns the preferred locales of the user, in order, using `lookup` to read environment
+/// variables.
+///
+/// The locale of messages is the first non-empty variable of `LC_ALL`, `LC_MESSAGES` and `LANG`,
+/// it is preceded by the colon-separated locales of `LANGUAGE`, if any.
+///
+/// Just like gettext, no locales are returned when the locale of messages is the `C` or `POSIX`
+/// locale, or when it is not set at all, `LANGUAGE` is ignored in that case.
+///
+/// Locales that are invalid, or not supported, are skipped, and duplicates are removed.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::environment::locales_from;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+///
+/// let locales = locales_from(|name| match name {
+///     "LANG" => Some("fr_CA.UTF-8".to_string()),
+///     "LANGUAGE" => Some("fr_BE:en_GB:en".to_string()),
+///     _ => None,
+/// });
+///
+/// assert_eq!(
+///     locales,
+///     vec![
+///         Locale::French(FrenchVariant::Belgium),
+///         Locale::English(EnglishVariant::UnitedKingdom),
+///         Locale::English(EnglishVariant::Default),
+///         Locale::French(FrenchVariant::Canada),
+///     ]
+/// );
+///
+/// let locales = locales_from(|name| match name {
+///     "LC_ALL" => Some("C.UTF-8".to_string()),
+///     "LANGUAGE" => Some("fr_BE:en_GB:en".to_string()),
+///     _ => None,
+/// });
+///
+/// assert!(locales.is_empty());
+/// ```
+pub fn locales_from<F>(lookup: F) -> Vec<Locale>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let variable = |name: &str| lookup(name).filter(|value| !value.is_empty());
+
+    let messages =
+        match ["LC_ALL", "LC_MESSAGES", "LANG"].into_iter().find_map(variable)
+        {
+            Some(messages) => messages,
+            None => return vec![],
+        };
+
+    if is_default(&messages) {
+        return vec![];
+    }
+
+    let language = variable("LANGUAGE").unwrap_or_default();
+
+    let mut locales = vec![];
+    for locale in
+        language.split(':').chain(messages.split(':')).filter_map(parse)
+    {
+        if !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+
+    locales
+}
+
+/// Parses a POSIX locale name, i.e. `language[_territory][.codeset][@modifier]`.
+///
+/// The codeset is ignored, and the `latin`, `cyrillic` and `valencia` modifiers are mapped to
+/// their script, and variant, subtags, other modifiers are ignored.
+///
+/// Returns `None` for the `C` and `POSIX` locales, and for invalid or unsupported locales.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::environment::parse;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// assert_eq!(parse("en_GB.UTF-8"), Some(Locale::English(EnglishVariant::UnitedKingdom)));
+/// assert_eq!(parse("fr_FR.ISO-8859-15@euro").unwrap().code(), "fr_FR");
+/// assert_eq!(parse("sr_RS@latin").unwrap().code(), "sr_Latn_RS");
+/// assert_eq!(parse("C.UTF-8"), None);
+/// ```
+pub fn parse(value: &str) -> Option<Locale> {
+    if is_default(value) {
+        return None;
+    }
+
+    let (name, modifier) = match value.split_once('@') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (value, None),
+    };
+    let name = name.split_once('.').map_or(name, |(name, _)| name);
+
+    let (language, territory) = match name.split_once('_') {
+        Some((language, territory)) => (language, Some(territory)),
+        None => (name, None),
+    };
+
+    let mut tag = language.to_string();
+    match modifier {
+        Some("latin") => tag.push_str("_Latn"),
+        Some("cyrillic") => tag.push_str("_Cyrl"),
+        _ => {}
+    }
+
+    if let Some(territory) = territory {
+        tag.push('_');
+        tag.push_str(territory);
+    }
+
+    if modifier == Some("valencia") {
+        tag.push_str("_valencia");
+    }
+
+    tag.parse().ok()
+}
+
+/// Returns `true` for the `C` and `POSIX` locales, in which messages are not translated.
+fn is_default(value: &str) -> bool {
+    let name = value.split(['.', '@']).next().unwrap_or_default();
+
+    matches!(name, "C" | "POSIX")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::environment::locales_from;
+    use crate::environment::parse;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+    use crate::locale::SpanishVariant;
+
+    #[test]
+    fn posix_locales() {
+        for (value, expected) in [
+            ("es_MX", Some("es_MX")),
+            ("es_MX.utf8", Some("es_MX")),
+            ("ca_ES.UTF-8@valencia", Some("ca_ES_valencia")),
+            ("uz_UZ@cyrillic", Some("uz_Cyrl_UZ")),
+            ("de_DE@euro", Some("de_DE")),
+            ("ja", Some("ja")),
+            ("POSIX", None),
+            ("C", None),
+            ("xx_XX.UTF-8", None),
+            ("", None),
+        ] {
+            assert_eq!(
+                parse(value).as_ref().map(Locale::code),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn variable_precedence() {
+        let locales = locales_from(|name| match name {
+            "LC_ALL" => Some(String::new()),
+            "LC_MESSAGES" => Some("es_MX.UTF-8".to_string()),
+            "LANG" => Some("en_US.UTF-8".to_string()),
+            "LANGUAGE" => Some("xx:es_MX::en_GB".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(
+            locales,
+            vec![
+                Locale::Spanish(SpanishVariant::Mexico),
+                Locale::English(EnglishVariant::UnitedKingdom),
+            ]
+        );
+
+        let locales = locales_from(|name| match name {
+            "LANG" => Some("en_US.UTF-8".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(
+            locales,
+            vec![Locale::English(EnglishVariant::UnitedStates)]
+        );
+        assert_eq!(locales_from(|_| None), vec![]);
+    }
+}
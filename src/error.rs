@@ -1,22 +1,22 @@
 use crate::loader::error::Error as LoadingError;
+use crate::locale::error::ParseError;
 use crate::locale::Locale;
 
 #[derive(Debug)]
 pub enum Error {
     MessageNotFound(Locale, String, String),
     InvalidLocale(String),
+    ParseError(ParseError),
     FormattingError(String),
     LoadingError(LoadingError),
 }
 
-unsafe impl Sync for Error {}
-unsafe impl Send for Error {}
-
 impl ::std::fmt::Display for Error {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
             Error::MessageNotFound(locale, domain, message) => write!(f, "message not found: message `'{message}'` could not be found in `'{domain}'` domain for `'{locale}'` locale."),
             Error::InvalidLocale(locale) => write!(f, "locale: invalid locale, expected a valid locale code but found `'{locale}'`."),
+            Error::ParseError(inner) => write!(f, "{inner}"),
             Error::FormattingError(inner) => write!(f, "{inner}"),
             Error::LoadingError(inner) => write!(f, "{inner}"),
         }
@@ -24,3 +24,9 @@ impl ::std::fmt::Display for Error {
 }
 
 impl ::std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::ParseError(error)
+    }
+}
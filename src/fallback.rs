@@ -0,0 +1,254 @@
+use crate::locale::Locale;
+
+use std::collections::HashMap;
+
+/// A `FallbackStrategy` trait.
+///
+/// A fallback strategy decides which locales are looked up, and in which order, when translating a
+/// message into a given locale.
+///
+/// The translator looks up the chain of the requested locale first, followed by the chain of its
+/// fallback locale, if any.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::fallback::FallbackStrategy;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// struct EnglishFallbackStrategy;
+///
+/// impl FallbackStrategy for EnglishFallbackStrategy {
+///     fn chain(&self, locale: &Locale) -> Vec<Locale> {
+///         vec![*locale, Locale::English(EnglishVariant::Default)]
+///     }
+/// }
+/// ```
+pub trait FallbackStrategy: Send + Sync {
+    /// Returns the locales to look up, in order, when translating a message into `locale`.
+    ///
+    /// The chain should start with `locale` itself.
+    fn chain(&self, locale: &Locale) -> Vec<Locale>;
+}
+
+/// The default fallback strategy.
+///
+/// Falls back from a locale variant through its parent locales, according to the CLDR parent
+/// locales data, see [`Locale::parent`].
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::fallback::DefaultFallbackStrategy;
+/// use tarjama::fallback::FallbackStrategy;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// let strategy = DefaultFallbackStrategy::new();
+///
+/// assert_eq!(
+///     strategy.chain(&Locale::English(EnglishVariant::UnitedKingdom)),
+///     vec![
+///         Locale::English(EnglishVariant::UnitedKingdom),
+///         Locale::English(EnglishVariant::World),
+///         Locale::English(EnglishVariant::Default),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     strategy.chain(&Locale::English(EnglishVariant::UnitedStates)),
+///     vec![
+///         Locale::English(EnglishVariant::UnitedStates),
+///         Locale::English(EnglishVariant::Default),
+///     ]
+/// );
+///
+/// assert_eq!(strategy.chain(&Locale::Hungarian), vec![Locale::Hungarian]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultFallbackStrategy;
+
+impl DefaultFallbackStrategy {
+    pub fn new() -> Self {
+        DefaultFallbackStrategy
+    }
+}
+
+impl FallbackStrategy for DefaultFallbackStrategy {
+    fn chain(&self, locale: &Locale) -> Vec<Locale> {
+        let mut chain = vec![*locale];
+        while let Some(parent) = chain[chain.len() - 1].parent() {
+            chain.push(parent);
+        }
+
+        chain
+    }
+}
+
+/// A fallback strategy using explicit, ordered, fallback chains per locale.
+///
+/// Locales without an explicit chain are resolved using the `DefaultFallbackStrategy`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::fallback::ChainFallbackStrategy;
+/// use tarjama::fallback::FallbackStrategy;
+/// use tarjama::locale::*;
+///
+/// let strategy = ChainFallbackStrategy::new()
+///     .with_chain(
+///         Locale::Portuguese(PortugueseVariant::Brazil),
+///         [
+///             Locale::Portuguese(PortugueseVariant::Default),
+///             Locale::Spanish(SpanishVariant::Default),
+///             Locale::English(EnglishVariant::Default),
+///         ],
+///     );
+///
+/// assert_eq!(
+///     strategy.chain(&Locale::Portuguese(PortugueseVariant::Brazil)),
+///     vec![
+///         Locale::Portuguese(PortugueseVariant::Brazil),
+///         Locale::Portuguese(PortugueseVariant::Default),
+///         Locale::Spanish(SpanishVariant::Default),
+///         Locale::English(EnglishVariant::Default),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     strategy.chain(&Locale::Spanish(SpanishVariant::Mexico)),
+///     vec![
+///         Locale::Spanish(SpanishVariant::Mexico),
+///         Locale::Spanish(SpanishVariant::LatinAmerica),
+///         Locale::Spanish(SpanishVariant::Default),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChainFallbackStrategy {
+    chains: HashMap<Locale, Vec<Locale>>,
+}
+
+impl ChainFallbackStrategy {
+    /// Creates a new `ChainFallbackStrategy`, without any chains.
+    pub fn new() -> Self {
+        ChainFallbackStrategy { chains: HashMap::new() }
+    }
+
+    /// Set the fallback chain of `locale`, replacing the previous chain if any.
+    ///
+    /// The chain lists the locales to fall back to, in order, not including `locale` itself.
+    pub fn with_chain<T>(mut self, locale: Locale, chain: T) -> Self
+    where
+        T: Into<Vec<Locale>>,
+    {
+        self.insert(locale, chain);
+
+        self
+    }
+
+    /// Set the fallback chain of `locale`, returning the previous chain if any.
+    ///
+    /// The chain lists the locales to fall back to, in order, not including `locale` itself.
+    pub fn insert<T>(
+        &mut self,
+        locale: Locale,
+        chain: T,
+    ) -> Option<Vec<Locale>>
+    where
+        T: Into<Vec<Locale>>,
+    {
+        self.chains.insert(locale, chain.into())
+    }
+}
+
+impl FallbackStrategy for ChainFallbackStrategy {
+    fn chain(&self, locale: &Locale) -> Vec<Locale> {
+        // `pt_BR_u_nu_latn` uses the chain of `pt_BR`.
+        let base = locale.without_extensions();
+
+        match self.chains.get(&base) {
+            Some(chain) => {
+                let mut result = Vec::with_capacity(chain.len() + 2);
+                result.push(*locale);
+                if base != *locale {
+                    result.push(base);
+                }
+                result.extend(chain);
+
+                result
+            }
+            None => DefaultFallbackStrategy.chain(locale),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fallback::ChainFallbackStrategy;
+    use crate::fallback::FallbackStrategy;
+    use crate::locale::ArabicVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::Locale;
+    use crate::locale::PortugueseVariant;
+
+    #[test]
+    fn chain_replacement() {
+        let mut strategy = ChainFallbackStrategy::new().with_chain(
+            Locale::Arabic(ArabicVariant::Tunisia),
+            [Locale::French(FrenchVariant::Default)],
+        );
+
+        assert_eq!(
+            strategy.insert(
+                Locale::Arabic(ArabicVariant::Tunisia),
+                [
+                    Locale::Arabic(ArabicVariant::Morocco),
+                    Locale::Arabic(ArabicVariant::Default),
+                    Locale::French(FrenchVariant::Default),
+                ],
+            ),
+            Some(vec![Locale::French(FrenchVariant::Default)])
+        );
+
+        assert_eq!(
+            strategy.chain(&Locale::Arabic(ArabicVariant::Tunisia)),
+            vec![
+                Locale::Arabic(ArabicVariant::Tunisia),
+                Locale::Arabic(ArabicVariant::Morocco),
+                Locale::Arabic(ArabicVariant::Default),
+                Locale::French(FrenchVariant::Default),
+            ]
+        );
+
+        assert_eq!(
+            strategy.chain(&Locale::Arabic(ArabicVariant::Morocco)),
+            vec![
+                Locale::Arabic(ArabicVariant::Morocco),
+                Locale::Arabic(ArabicVariant::Default),
+            ]
+        );
+    }
+
+    #[test]
+    fn chain_with_extensions() {
+        let portugal: Locale = "pt-PT".try_into().unwrap();
+        let strategy = ChainFallbackStrategy::new().with_chain(
+            Locale::Portuguese(PortugueseVariant::Brazil),
+            [portugal],
+        );
+
+        let locale: Locale = "pt-BR-u-nu-latn".try_into().unwrap();
+
+        assert_eq!(
+            strategy.chain(&locale),
+            vec![
+                locale,
+                Locale::Portuguese(PortugueseVariant::Brazil),
+                portugal,
+            ]
+        );
+    }
+}
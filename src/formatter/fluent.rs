@@ -0,0 +1,845 @@
+use crate::catalogue::Message;
+use crate::context::Context;
+use crate::context::Count;
+use crate::context::Value;
+use crate::error::Error;
+use crate::formatter::Formatter;
+use crate::locale::Locale;
+use crate::plural::cardinal;
+use crate::plural::ordinal;
+use crate::plural::PluralOperands;
+
+use fluent_syntax::ast;
+use fluent_syntax::parser;
+use fluent_syntax::unicode::unescape_unicode_to_string;
+use std::collections::HashMap;
+
+/// The maximum depth of nested message and term references, to guard against cycles.
+const MAXIMUM_DEPTH: usize = 32;
+
+/// A [Project Fluent](https://projectfluent.org) implementation for `Formatter`.
+///
+/// # Syntax
+///
+/// A message source is a Fluent resource, the first message of the resource is formatted, any
+/// other messages and terms of the resource can be referenced from it, e.g.:
+///
+/// ```ftl
+/// emails = { $name } has { $count ->
+///         [0] no new emails
+///         [one] one new email
+///        *[other] { $count } new emails
+///     } from { -brand }.
+///
+/// -brand = Tarjama
+/// ```
+///
+/// The [`crate::loader::fluent`] loader takes care of producing these sources from `.ftl` files,
+/// including the referenced messages and terms of each message.
+///
+/// Variables are looked up by name in the context values, `$count` also refers to the `count`
+/// value of the context when the context has no `count` value.
+///
+/// Selectors match numeric variant keys first, then the CLDR plural category of the locale, string
+/// values match variant keys by name, the default variant is used when no key matches.
+///
+/// The built-in functions are supported:
+///
+/// - `NUMBER($value, minimumFractionDigits: 2, maximumFractionDigits: 2, type: "ordinal")`
+/// - `DATETIME($value)`: formats a unix timestamp, in seconds, as an ISO 8601 UTC date-time,
+///   e.g. `2024-03-01T12:00:00Z`, other values are formatted as is.
+///
+/// Unlike other Fluent implementations, placeables are not isolated using unicode bidi marks, and
+/// errors, such as a missing variable, fail the whole message instead of being replaced inline.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::context;
+/// use tarjama::formatter::Formatter;
+/// use tarjama::formatter::fluent::FluentFormatter;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// let formatter = FluentFormatter::new();
+/// let locale = Locale::English(EnglishVariant::Default);
+/// let message = r#"
+/// apples = { $name } has { $count ->
+///         [0] no apples
+///         [one] one apple
+///        *[other] { $count } apples
+///     } in { -place(case: "locative") }.
+///
+/// -place = { $case ->
+///    *[nominative] The basket
+///     [locative] the basket
+/// }
+/// "#;
+///
+/// assert_eq!(
+///     formatter.format(&locale, message, &context!(name = "Saif", count = 1)).unwrap(),
+///     "Saif has one apple in the basket."
+/// );
+///
+/// assert_eq!(
+///     formatter.format(&locale, message, &context!(name = "Saif", count = 4)).unwrap(),
+///     "Saif has 4 apples in the basket."
+/// );
+/// ```
+///
+/// Use it with a `Translator` instead of the default syntax:
+///
+/// ```
+/// use tarjama::catalogue::Catalogue;
+/// use tarjama::catalogue::CatalogueBag;
+/// use tarjama::context;
+/// use tarjama::formatter::fluent::FluentFormatter;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::Translator;
+///
+/// let mut catalogue = Catalogue::new(Locale::English(EnglishVariant::Default));
+/// catalogue.insert(
+///     "messages",
+///     "apple",
+///     "apple = { $count ->\n    [one] an apple\n   *[other] { $count } apples\n}",
+/// );
+///
+/// let translator = Translator::new(
+///     FluentFormatter::new().into(),
+///     CatalogueBag::with_catalogues(vec![catalogue]),
+///     None,
+/// );
+///
+/// assert_eq!(translator.trans("en", "messages", "apple", context!(? = 3)).unwrap(), "3 apples");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Hash)]
+pub struct FluentFormatter;
+
+impl FluentFormatter {
+    pub fn new() -> FluentFormatter {
+        FluentFormatter
+    }
+}
+
+impl From<FluentFormatter> for Box<dyn Formatter> {
+    fn from(formatter: FluentFormatter) -> Self {
+        Box::new(formatter)
+    }
+}
+
+impl Formatter for FluentFormatter {
+    fn format(
+        &self,
+        locale: &Locale,
+        message: &str,
+        context: &Context,
+    ) -> Result<String, Error> {
+        let resource = parse(message).map_err(Error::FormattingError)?;
+
+        format_resource(&resource, locale, context)
+    }
+
+    fn format_message(
+        &self,
+        locale: &Locale,
+        message: &Message,
+        context: &Context,
+    ) -> Result<String, Error> {
+        match message.compiled(parse) {
+            Some(Ok(resource)) => format_resource(resource, locale, context),
+            Some(Err(error)) => Err(Error::FormattingError(error.clone())),
+            None => self.format(locale, message.as_str(), context),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn Formatter> {
+        Box::new(*self)
+    }
+}
+
+fn parse(source: &str) -> Result<ast::Resource<String>, String> {
+    parser::parse_runtime(source.to_string()).map_err(|(_, errors)| {
+        let reasons: Vec<String> = errors
+            .iter()
+            .map(|error| error.kind.to_string().to_lowercase())
+            .collect();
+
+        format!("invalid message format: {}.", reasons.join(", "))
+    })
+}
+
+fn format_resource(
+    resource: &ast::Resource<String>,
+    locale: &Locale,
+    context: &Context,
+) -> Result<String, Error> {
+    let message = resource
+        .body
+        .iter()
+        .find_map(|entry| match entry {
+            ast::Entry::Message(message) => Some(message),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            Error::FormattingError(
+                "invalid message format: expected a message.".to_string(),
+            )
+        })?;
+
+    let value = message.value.as_ref().ok_or_else(|| {
+        Error::FormattingError(format!(
+            "invalid message format: message `'{}'` has no value.",
+            message.id.name
+        ))
+    })?;
+
+    let scope = Scope { locale, context, resource, arguments: None, depth: 0 };
+
+    scope.pattern(value)
+}
+
+/// The options of a number, as set by the `NUMBER` function, or by a number literal.
+#[derive(Debug, Clone, Copy, Default)]
+struct NumberOptions {
+    minimum_fraction_digits: Option<usize>,
+    maximum_fraction_digits: Option<usize>,
+    ordinal: bool,
+}
+
+/// A resolved Fluent value.
+#[derive(Debug, Clone)]
+enum FluentValue {
+    String(String),
+    Number(f64, NumberOptions),
+}
+
+impl FluentValue {
+    fn number(value: f64) -> FluentValue {
+        FluentValue::Number(value, NumberOptions::default())
+    }
+}
+
+impl From<&Value> for FluentValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::String(s) => FluentValue::String(s.clone()),
+            Value::Integer(i) => FluentValue::number(*i as f64),
+            Value::Double(d) => FluentValue::number(*d),
+        }
+    }
+}
+
+impl std::fmt::Display for FluentValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FluentValue::String(s) => write!(f, "{s}"),
+            FluentValue::Number(value, options) => {
+                let minimum = options.minimum_fraction_digits.unwrap_or(0);
+                let maximum =
+                    options.maximum_fraction_digits.unwrap_or(3).max(minimum);
+
+                let mut number = format!("{value:.maximum$}");
+                if let Some(dot) = number.find('.') {
+                    while number.len() - dot - 1 > minimum
+                        && number.ends_with('0')
+                    {
+                        number.pop();
+                    }
+
+                    if number.ends_with('.') {
+                        number.pop();
+                    }
+                }
+
+                write!(f, "{number}")
+            }
+        }
+    }
+}
+
+struct Scope<'a> {
+    locale: &'a Locale,
+    context: &'a Context,
+    resource: &'a ast::Resource<String>,
+    /// The arguments of a term reference, terms can't access the context values.
+    arguments: Option<HashMap<&'a str, FluentValue>>,
+    depth: usize,
+}
+
+impl<'a> Scope<'a> {
+    fn pattern(
+        &self,
+        pattern: &'a ast::Pattern<String>,
+    ) -> Result<String, Error> {
+        let mut buffer = String::new();
+        for element in &pattern.elements {
+            match element {
+                ast::PatternElement::TextElement { value } => {
+                    buffer.push_str(value)
+                }
+                ast::PatternElement::Placeable { expression } => {
+                    buffer.push_str(&self.expression(expression)?.to_string())
+                }
+            }
+        }
+
+        Ok(buffer)
+    }
+
+    fn expression(
+        &self,
+        expression: &'a ast::Expression<String>,
+    ) -> Result<FluentValue, Error> {
+        match expression {
+            ast::Expression::Inline(expression) => self.inline(expression),
+            ast::Expression::Select { selector, variants } => {
+                let selector = self.inline(selector)?;
+                let variant = self.select(&selector, variants)?;
+
+                Ok(FluentValue::String(self.pattern(&variant.value)?))
+            }
+        }
+    }
+
+    fn select(
+        &self,
+        selector: &FluentValue,
+        variants: &'a [ast::Variant<String>],
+    ) -> Result<&'a ast::Variant<String>, Error> {
+        let matching =
+            variants.iter().find(|variant| match (selector, &variant.key) {
+                (
+                    FluentValue::Number(value, _),
+                    ast::VariantKey::NumberLiteral { value: key },
+                ) => key.parse::<f64>().is_ok_and(|key| key == *value),
+                (
+                    FluentValue::String(value),
+                    ast::VariantKey::Identifier { name },
+                ) => name == value,
+                _ => false,
+            });
+
+        let matching = matching.or_else(|| {
+            let FluentValue::Number(_, options) = selector else {
+                return None;
+            };
+
+            let operands = PluralOperands::try_from(
+                selector.to_string().as_str(),
+            )
+            .ok()?;
+            let category = if options.ordinal || self.context.ordinal {
+                ordinal(self.locale, &operands)
+            } else {
+                cardinal(self.locale, &operands)
+            }
+            .to_string();
+
+            variants.iter().find(|variant| {
+                matches!(&variant.key, ast::VariantKey::Identifier { name } if *name == category)
+            })
+        });
+
+        matching
+            .or_else(|| variants.iter().find(|variant| variant.default))
+            .ok_or_else(|| {
+                Error::FormattingError(
+                    "invalid message format: missing default variant."
+                        .to_string(),
+                )
+            })
+    }
+
+    fn inline(
+        &self,
+        expression: &'a ast::InlineExpression<String>,
+    ) -> Result<FluentValue, Error> {
+        match expression {
+            ast::InlineExpression::StringLiteral { value } => Ok(
+                FluentValue::String(unescape_unicode_to_string(value).into()),
+            ),
+            ast::InlineExpression::NumberLiteral { value } => {
+                number_literal(value)
+            }
+            ast::InlineExpression::VariableReference { id } => {
+                self.variable(&id.name)
+            }
+            ast::InlineExpression::Placeable { expression } => {
+                self.expression(expression)
+            }
+            ast::InlineExpression::MessageReference { id, attribute } => {
+                let message = self
+                    .resource
+                    .body
+                    .iter()
+                    .find_map(|entry| match entry {
+                        ast::Entry::Message(message)
+                            if message.id.name == id.name =>
+                        {
+                            Some(message)
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| {
+                        Error::FormattingError(format!(
+                            "cannot find message `'{}'` in this resource.",
+                            id.name
+                        ))
+                    })?;
+
+                let pattern = match attribute {
+                    Some(attribute) => Some(self.attribute(
+                        &message.attributes,
+                        &id.name,
+                        attribute,
+                    )?),
+                    None => message.value.as_ref(),
+                }
+                .ok_or_else(|| {
+                    Error::FormattingError(format!(
+                        "invalid message format: message `'{}'` has no value.",
+                        id.name
+                    ))
+                })?;
+
+                let scope = self.nested(self.arguments.clone())?;
+
+                Ok(FluentValue::String(scope.pattern(pattern)?))
+            }
+            ast::InlineExpression::TermReference {
+                id,
+                attribute,
+                arguments,
+            } => {
+                let term = self
+                    .resource
+                    .body
+                    .iter()
+                    .find_map(|entry| match entry {
+                        ast::Entry::Term(term) if term.id.name == id.name => {
+                            Some(term)
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| {
+                        Error::FormattingError(format!(
+                            "cannot find term `'-{}'` in this resource.",
+                            id.name
+                        ))
+                    })?;
+
+                let pattern = match attribute {
+                    Some(attribute) => self.attribute(
+                        &term.attributes,
+                        &format!("-{}", id.name),
+                        attribute,
+                    )?,
+                    None => &term.value,
+                };
+
+                let mut named = HashMap::new();
+                if let Some(arguments) = arguments {
+                    for argument in &arguments.named {
+                        named.insert(
+                            argument.name.name.as_str(),
+                            self.inline(&argument.value)?,
+                        );
+                    }
+                }
+
+                let scope = self.nested(Some(named))?;
+
+                Ok(FluentValue::String(scope.pattern(pattern)?))
+            }
+            ast::InlineExpression::FunctionReference { id, arguments } => {
+                self.function(&id.name, arguments)
+            }
+        }
+    }
+
+    fn attribute(
+        &self,
+        attributes: &'a [ast::Attribute<String>],
+        entry: &str,
+        attribute: &ast::Identifier<String>,
+    ) -> Result<&'a ast::Pattern<String>, Error> {
+        attributes
+            .iter()
+            .find(|candidate| candidate.id.name == attribute.name)
+            .map(|attribute| &attribute.value)
+            .ok_or_else(|| {
+                Error::FormattingError(format!(
+                    "cannot find attribute `'{entry}.{}'` in this resource.",
+                    attribute.name
+                ))
+            })
+    }
+
+    fn variable(&self, name: &str) -> Result<FluentValue, Error> {
+        let value = match &self.arguments {
+            Some(arguments) => arguments.get(name).cloned(),
+            None => self
+                .context
+                .values
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into())
+                .or_else(|| match (name, self.context.count) {
+                    ("count", Some(Count::Integer(i))) => {
+                        Some(FluentValue::number(i as f64))
+                    }
+                    ("count", Some(Count::Decimal { value, operands })) => {
+                        Some(FluentValue::Number(
+                            value,
+                            NumberOptions {
+                                minimum_fraction_digits: Some(operands.v),
+                                ..Default::default()
+                            },
+                        ))
+                    }
+                    _ => None,
+                }),
+        };
+
+        value.ok_or_else(|| {
+            Error::FormattingError(format!(
+                "cannot find value `'{name}'` in this context."
+            ))
+        })
+    }
+
+    fn function(
+        &self,
+        name: &str,
+        arguments: &'a ast::CallArguments<String>,
+    ) -> Result<FluentValue, Error> {
+        let value = match arguments.positional.first() {
+            Some(argument) => self.inline(argument)?,
+            None => {
+                return Err(Error::FormattingError(format!(
+                    "invalid message format: missing argument for function `'{name}'`."
+                )))
+            }
+        };
+
+        let mut options = HashMap::new();
+        for argument in &arguments.named {
+            options.insert(
+                argument.name.name.as_str(),
+                self.inline(&argument.value)?,
+            );
+        }
+
+        match name {
+            "NUMBER" => {
+                let (number, mut number_options) = match value {
+                    FluentValue::Number(number, options) => (number, options),
+                    FluentValue::String(s) => (
+                        s.trim().parse::<f64>().map_err(|_| {
+                            Error::FormattingError(format!(
+                                "invalid argument: expected a number for function `'NUMBER'`, found `'{s}'`."
+                            ))
+                        })?,
+                        NumberOptions::default(),
+                    ),
+                };
+
+                let digits = |option: &str| match options.get(option) {
+                    Some(FluentValue::Number(digits, _)) => {
+                        Some(*digits as usize)
+                    }
+                    _ => None,
+                };
+
+                if let Some(digits) = digits("minimumFractionDigits") {
+                    number_options.minimum_fraction_digits = Some(digits);
+                }
+
+                if let Some(digits) = digits("maximumFractionDigits") {
+                    number_options.maximum_fraction_digits = Some(digits);
+                }
+
+                if let Some(FluentValue::String(kind)) = options.get("type") {
+                    number_options.ordinal = kind == "ordinal";
+                }
+
+                Ok(FluentValue::Number(number, number_options))
+            }
+            "DATETIME" => Ok(match value {
+                FluentValue::Number(seconds, _) => {
+                    FluentValue::String(datetime(seconds as i64))
+                }
+                value => value,
+            }),
+            _ => Err(Error::FormattingError(format!(
+                "invalid message format: unknown function `'{name}'`."
+            ))),
+        }
+    }
+
+    fn nested(
+        &self,
+        arguments: Option<HashMap<&'a str, FluentValue>>,
+    ) -> Result<Scope<'a>, Error> {
+        if self.depth >= MAXIMUM_DEPTH {
+            return Err(Error::FormattingError(
+                "invalid message format: too many nested references."
+                    .to_string(),
+            ));
+        }
+
+        Ok(Scope {
+            locale: self.locale,
+            context: self.context,
+            resource: self.resource,
+            arguments,
+            depth: self.depth + 1,
+        })
+    }
+}
+
+/// Parse a number literal, keeping its fraction digits, e.g. `1.50` is formatted as `1.50`.
+fn number_literal(value: &str) -> Result<FluentValue, Error> {
+    let number = value.parse::<f64>().map_err(|_| {
+        Error::FormattingError(format!(
+            "invalid message format: invalid number `'{value}'`."
+        ))
+    })?;
+
+    let digits =
+        value.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+
+    Ok(FluentValue::Number(
+        number,
+        NumberOptions {
+            minimum_fraction_digits: Some(digits),
+            ..Default::default()
+        },
+    ))
+}
+
+/// Format a unix timestamp as an ISO 8601 UTC date-time.
+fn datetime(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86400);
+    let seconds = timestamp.rem_euclid(86400);
+
+    // days to civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+        - day_of_era / 146096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month =
+        if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::context;
+    use crate::formatter::fluent::FluentFormatter;
+    use crate::formatter::Formatter;
+    use crate::locale::ArabicVariant;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+
+    macro_rules! assert_ok {
+        ($locale:expr, $msg:expr, $context:expr, $expected:literal) => {{
+            let msg = FluentFormatter::new().format(&$locale, $msg, &$context);
+
+            assert_eq!(msg.unwrap(), $expected.to_string());
+        }};
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            assert_ok!(
+                Locale::English(EnglishVariant::Default),
+                $msg,
+                $context,
+                $expected
+            );
+        }};
+    }
+
+    macro_rules! assert_err {
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            let msg = FluentFormatter::new().format(
+                &Locale::English(EnglishVariant::Default),
+                $msg,
+                &$context,
+            );
+
+            assert_eq!(msg.unwrap_err().to_string(), $expected.to_string());
+        }};
+    }
+
+    #[test]
+    fn placeables() {
+        assert_ok!("hello = Hello, World!", context!(), "Hello, World!");
+        assert_ok!(
+            "hello = Hello, { $name }!",
+            context!(name = "Saif"),
+            "Hello, Saif!"
+        );
+        assert_ok!(
+            "quote = { \"{\" }escaped{ \"}\" } { \"\\u0041\" }",
+            context!(),
+            "{escaped} A"
+        );
+        assert_ok!(
+            "hello = { greeting }, { $name }!\ngreeting = Hello",
+            context!(name = "Saif"),
+            "Hello, Saif!"
+        );
+        assert_ok!(
+            "form = { login.placeholder }\nlogin = Login\n    .placeholder = Email",
+            context!(),
+            "Email"
+        );
+        assert_ok!(
+            "brand = Welcome to { -brand }!\n-brand = Tarjama",
+            context!(),
+            "Welcome to Tarjama!"
+        );
+        assert_ok!("count = { $count } items", context!(? = 3), "3 items");
+        assert_ok!(
+            "count = { $count } items",
+            context!(count = "many", ? = 3),
+            "many items"
+        );
+        assert_ok!(
+            "multiline =\n    first line\n    second line",
+            context!(),
+            "first line\nsecond line"
+        );
+    }
+
+    #[test]
+    fn selectors() {
+        let message = "emails = { $count ->\n    [0] no emails\n    [one] one email\n   *[other] { $count } emails\n}";
+
+        assert_ok!(message, context!(count = 0), "no emails");
+        assert_ok!(message, context!(count = 1), "one email");
+        assert_ok!(message, context!(count = 7), "7 emails");
+        assert_ok!(message, context!(count = 1.5), "1.5 emails");
+
+        let message = "apples = { $count ->\n    [zero] zero\n    [one] one\n    [two] two\n    [few] few\n    [many] many\n   *[other] other\n}";
+        let locale = Locale::Arabic(ArabicVariant::Default);
+        assert_ok!(locale, message, context!(count = 0), "zero");
+        assert_ok!(locale, message, context!(count = 2), "two");
+        assert_ok!(locale, message, context!(count = 105), "few");
+        assert_ok!(locale, message, context!(count = 11), "many");
+        assert_ok!(locale, message, context!(count = 100), "other");
+
+        let message = "invite = { $gender ->\n    [male] He\n    [female] She\n   *[other] They\n} invited you.";
+        assert_ok!(message, context!(gender = "female"), "She invited you.");
+        assert_ok!(message, context!(gender = "robot"), "They invited you.");
+
+        let message = "place = { NUMBER($place, type: \"ordinal\") ->\n    [one] { $place }st\n    [two] { $place }nd\n    [few] { $place }rd\n   *[other] { $place }th\n}";
+        assert_ok!(message, context!(place = 1), "1st");
+        assert_ok!(message, context!(place = 22), "22nd");
+        assert_ok!(message, context!(place = 13), "13th");
+
+        let message = "brand = { -brand(case: \"genitive\") }\n-brand = { $case ->\n   *[nominative] Tarjama\n    [genitive] Tarjama's\n}";
+        assert_ok!(message, context!(case = "nominative"), "Tarjama's");
+
+        let message = "title = { -brand.gender ->\n    [feminine] She\n   *[other] It\n}\n-brand = Tarjama\n    .gender = feminine";
+        assert_ok!(message, context!(), "She");
+    }
+
+    #[test]
+    fn functions() {
+        assert_ok!(
+            "price = { NUMBER($price, minimumFractionDigits: 2) }",
+            context!(price = 3),
+            "3.00"
+        );
+        assert_ok!(
+            "price = { NUMBER($price, maximumFractionDigits: 1) }",
+            context!(price = 1.23456),
+            "1.2"
+        );
+        assert_ok!("ratio = { $ratio }", context!(ratio = 1.23456), "1.235");
+        assert_ok!("literal = { 1.50 }", context!(), "1.50");
+        assert_ok!(
+            "date = { DATETIME($date) }",
+            context!(date = 1709294400),
+            "2024-03-01T12:00:00Z"
+        );
+        assert_ok!(
+            "date = { DATETIME($date) }",
+            context!(date = -86400),
+            "1969-12-31T00:00:00Z"
+        );
+        assert_ok!(
+            "date = { DATETIME($date) }",
+            context!(date = "yesterday"),
+            "yesterday"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_err!(
+            "hello = Hello, { $name }!",
+            context!(),
+            "cannot find value `'name'` in this context."
+        );
+        assert_err!(
+            "hello = { greeting }",
+            context!(),
+            "cannot find message `'greeting'` in this resource."
+        );
+        assert_err!(
+            "hello = { -brand }",
+            context!(),
+            "cannot find term `'-brand'` in this resource."
+        );
+        assert_err!(
+            "hello = { hello.title }\nhello = Hello",
+            context!(),
+            "cannot find attribute `'hello.title'` in this resource."
+        );
+        assert_err!(
+            "brand = { -brand }\n-brand = { $name }",
+            context!(name = "Saif"),
+            "cannot find value `'name'` in this context."
+        );
+        assert_err!(
+            "hello = { UPPER($name) }",
+            context!(name = "Saif"),
+            "invalid message format: unknown function `'UPPER'`."
+        );
+        assert_err!(
+            "a = { b }\nb = { a }",
+            context!(),
+            "invalid message format: too many nested references."
+        );
+        assert_err!(
+            "-brand = Tarjama",
+            context!(),
+            "invalid message format: expected a message."
+        );
+        assert_err!(
+            "hello =\n    .title = Hello",
+            context!(),
+            "invalid message format: message `'hello'` has no value."
+        );
+        assert_err!(
+            "hello = { $name",
+            context!(),
+            "invalid message format: expected a token starting with \"}\"."
+        );
+    }
+}
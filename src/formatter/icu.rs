@@ -0,0 +1,822 @@
+use crate::catalogue::Message;
+use crate::context::Context;
+use crate::context::Count;
+use crate::context::Value;
+use crate::error::Error;
+use crate::formatter::Formatter;
+use crate::locale::Locale;
+use crate::plural::cardinal;
+use crate::plural::ordinal;
+use crate::plural::PluralCategory;
+
+/// An ICU MessageFormat implementation for `Formatter`.
+///
+/// # Syntax
+///
+/// Arguments are referenced by name or position, e.g. `{name}` or `{0}`, the special `{?}` argument
+/// refers to the `count` value of the context.
+///
+/// Complex arguments choose a message depending on the value of an argument:
+///
+/// - `{count, plural, =0 {no items} one {# item} other {# items}}`
+/// - `{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}`
+/// - `{gender, select, male {he} female {she} other {they}}`
+///
+/// Plural arguments accept an `offset:n` before their branches, within a plural branch `#` is
+/// replaced by the value of the argument minus the offset.
+///
+/// An apostrophe starts quoted literal text when it is followed by `{`, `}`, `|`, or `#` within a
+/// plural branch, `''` is a literal apostrophe.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::context;
+/// use tarjama::formatter::Formatter;
+/// use tarjama::formatter::icu::IcuFormatter;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// let formatter = IcuFormatter::new();
+/// let locale = Locale::English(EnglishVariant::Default);
+/// let message = "{name} has {count, plural, =0 {no items} one {# item} other {# items}} in '{'their'}' cart.";
+///
+/// assert_eq!(
+///     formatter.format(&locale, message, &context!(name = "Saif", count = 1)).unwrap(),
+///     "Saif has 1 item in {their} cart."
+/// );
+///
+/// assert_eq!(
+///     formatter.format(&locale, message, &context!(name = "Saif", count = 4)).unwrap(),
+///     "Saif has 4 items in {their} cart."
+/// );
+/// ```
+///
+/// Use it with a `Translator` instead of the default syntax:
+///
+/// ```
+/// use tarjama::catalogue::Catalogue;
+/// use tarjama::catalogue::CatalogueBag;
+/// use tarjama::context;
+/// use tarjama::formatter::icu::IcuFormatter;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::Translator;
+///
+/// let mut catalogue = Catalogue::new(Locale::English(EnglishVariant::Default));
+/// catalogue.insert("messages", "apple", "{?, plural, one {# apple} other {# apples}}");
+///
+/// let translator = Translator::new(
+///     IcuFormatter::new().into(),
+///     CatalogueBag::with_catalogues(vec![catalogue]),
+///     None,
+/// );
+///
+/// assert_eq!(translator.trans("en", "messages", "apple", context!(? = 3)).unwrap(), "3 apples");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Hash)]
+pub struct IcuFormatter;
+
+impl IcuFormatter {
+    pub fn new() -> IcuFormatter {
+        IcuFormatter
+    }
+}
+
+impl From<IcuFormatter> for Box<dyn Formatter> {
+    fn from(formatter: IcuFormatter) -> Self {
+        Box::new(formatter)
+    }
+}
+
+impl Formatter for IcuFormatter {
+    fn format(
+        &self,
+        locale: &Locale,
+        message: &str,
+        context: &Context,
+    ) -> Result<String, Error> {
+        let nodes = Parser::new(message).parse()?;
+
+        let mut buffer = String::new();
+        write_nodes(&mut buffer, &nodes, locale, context, None)?;
+
+        Ok(buffer)
+    }
+
+    fn format_message(
+        &self,
+        locale: &Locale,
+        message: &Message,
+        context: &Context,
+    ) -> Result<String, Error> {
+        let compiled = message.compiled(|source| {
+            Parser::new(source).parse().map_err(|error| error.to_string())
+        });
+
+        match compiled {
+            Some(Ok(nodes)) => {
+                let mut buffer = String::new();
+                write_nodes(&mut buffer, nodes, locale, context, None)?;
+
+                Ok(buffer)
+            }
+            Some(Err(error)) => Err(Error::FormattingError(error.clone())),
+            None => self.format(locale, message.as_str(), context),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn Formatter> {
+        Box::new(*self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Argument {
+        name: String,
+        style: Style,
+    },
+    Pound,
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: f64,
+        branches: Vec<(Selector, Vec<Node>)>,
+    },
+    Select {
+        name: String,
+        branches: Vec<(String, Vec<Node>)>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Style {
+    None,
+    Number,
+    Integer,
+    Percent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+struct Parser<'a> {
+    message: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(message: &'a str) -> Self {
+        Parser { message, position: 0 }
+    }
+
+    fn parse(&mut self) -> Result<Vec<Node>, Error> {
+        self.parse_message(false, false)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.message[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(Error::FormattingError(format!(
+                "invalid message format: expected `'{expected}'`, found `'{c}'` at position {}.",
+                self.position - c.len_utf8()
+            ))),
+            None => Err(Error::FormattingError(format!(
+                "invalid message format: expected `'{expected}'` but string was terminated."
+            ))),
+        }
+    }
+
+    /// Read a word, up to a whitespace or one of the syntax characters.
+    fn word(&mut self) -> &'a str {
+        let start = self.position;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, '{' | '}' | ',' | ':') {
+                break;
+            }
+
+            self.bump();
+        }
+
+        &self.message[start..self.position]
+    }
+
+    fn parse_message(
+        &mut self,
+        in_plural: bool,
+        nested: bool,
+    ) -> Result<Vec<Node>, Error> {
+        let mut nodes = vec![];
+        let mut text = String::new();
+
+        loop {
+            match self.peek() {
+                None if nested => {
+                    return Err(Error::FormattingError(
+                        "invalid message format: expected `'}'` but string was terminated.".to_string(),
+                    ));
+                }
+                None => break,
+                Some('}') if nested => break,
+                Some('}') => {
+                    return Err(Error::FormattingError(format!(
+                        "invalid message format: unmatched `'}}'` at position {}.",
+                        self.position
+                    )));
+                }
+                Some('{') => {
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+
+                    nodes.push(self.parse_argument(in_plural)?);
+                }
+                Some('#') if in_plural => {
+                    self.bump();
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+
+                    nodes.push(Node::Pound);
+                }
+                Some('\'') => {
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        Some('{' | '}' | '|') => self.quoted(&mut text),
+                        Some('#') if in_plural => self.quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+
+        Ok(nodes)
+    }
+
+    /// Read quoted literal text, up to the closing apostrophe, or the end of the message.
+    fn quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.bump() {
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.bump();
+                    text.push('\'');
+                } else {
+                    return;
+                }
+            } else {
+                text.push(c);
+            }
+        }
+    }
+
+    fn parse_argument(&mut self, in_plural: bool) -> Result<Node, Error> {
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        let name = self.word().to_string();
+        if name.is_empty() {
+            return Err(Error::FormattingError(format!(
+                "invalid message format: expected an argument name at position {}.",
+                self.position
+            )));
+        }
+
+        self.skip_whitespace();
+        if self.peek() != Some(',') {
+            self.expect('}')?;
+
+            return Ok(Node::Argument { name, style: Style::None });
+        }
+
+        self.bump();
+        self.skip_whitespace();
+        let kind = self.word();
+        self.skip_whitespace();
+
+        let node = match kind {
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.parse_plural(name, kind == "selectordinal")?
+            }
+            "select" => {
+                self.expect(',')?;
+                self.parse_select(name, in_plural)?
+            }
+            "number" => {
+                let style = if self.peek() == Some(',') {
+                    self.bump();
+                    self.skip_whitespace();
+                    match self.word() {
+                        "integer" => Style::Integer,
+                        "percent" => Style::Percent,
+                        style => {
+                            return Err(Error::FormattingError(format!(
+                                "invalid message format: unsupported number style `'{style}'` for argument `'{name}'`."
+                            )));
+                        }
+                    }
+                } else {
+                    Style::Number
+                };
+
+                Node::Argument { name, style }
+            }
+            kind => {
+                return Err(Error::FormattingError(format!(
+                    "invalid message format: unsupported argument type `'{kind}'` for argument `'{name}'`."
+                )));
+            }
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+
+        Ok(node)
+    }
+
+    fn parse_plural(
+        &mut self,
+        name: String,
+        ordinal: bool,
+    ) -> Result<Node, Error> {
+        let mut offset = 0.0;
+        let mut branches = vec![];
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+
+            let keyword = self.word();
+            let selector = if keyword == "offset" && self.peek() == Some(':') {
+                self.bump();
+                self.skip_whitespace();
+                let value = self.word();
+                offset = value.parse::<f64>().map_err(|e| {
+                    Error::FormattingError(format!(
+                        "invalid message format: failed to parse offset `'{value}'` for argument `'{name}'`, {e}."
+                    ))
+                })?;
+
+                continue;
+            } else if let Some(value) = keyword.strip_prefix('=') {
+                Selector::Exact(value.parse::<f64>().map_err(|e| {
+                    Error::FormattingError(format!(
+                        "invalid message format: failed to parse selector `'{keyword}'` for argument `'{name}'`, {e}."
+                    ))
+                })?)
+            } else {
+                Selector::Category(PluralCategory::try_from(keyword)?)
+            };
+
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.parse_message(true, true)?;
+            self.expect('}')?;
+
+            branches.push((selector, message));
+        }
+
+        if !branches.iter().any(|(selector, _)| {
+            *selector == Selector::Category(PluralCategory::Other)
+        }) {
+            return Err(Error::FormattingError(format!(
+                "invalid message format: missing `'other'` branch for argument `'{name}'`."
+            )));
+        }
+
+        Ok(Node::Plural { name, ordinal, offset, branches })
+    }
+
+    fn parse_select(
+        &mut self,
+        name: String,
+        in_plural: bool,
+    ) -> Result<Node, Error> {
+        let mut branches = vec![];
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+
+            let keyword = self.word().to_string();
+            if keyword.is_empty() {
+                return Err(Error::FormattingError(format!(
+                    "invalid message format: expected a selector for argument `'{name}'` at position {}.",
+                    self.position
+                )));
+            }
+
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.parse_message(in_plural, true)?;
+            self.expect('}')?;
+
+            branches.push((keyword, message));
+        }
+
+        if !branches.iter().any(|(keyword, _)| keyword == "other") {
+            return Err(Error::FormattingError(format!(
+                "invalid message format: missing `'other'` branch for argument `'{name}'`."
+            )));
+        }
+
+        Ok(Node::Select { name, branches })
+    }
+}
+
+/// An argument value, borrowed from the context.
+enum Argument<'a> {
+    Value(&'a Value),
+    Count(Count),
+}
+
+impl<'a> Argument<'a> {
+    fn number(&self, name: &str) -> Result<f64, Error> {
+        match self {
+            Argument::Value(Value::Integer(i)) => Ok(*i as f64),
+            Argument::Value(Value::Double(d)) => Ok(*d),
+            Argument::Value(Value::String(s)) => s.trim().parse::<f64>().map_err(|_| {
+                Error::FormattingError(format!(
+                    "invalid argument: expected a number for `'{name}'`, found `'{s}'`."
+                ))
+            }),
+            Argument::Count(Count::Integer(i)) => Ok(*i as f64),
+            Argument::Count(Count::Decimal { value, .. }) => Ok(*value),
+        }
+    }
+
+    fn count(&self, name: &str, offset: f64) -> Result<Count, Error> {
+        let count = match self {
+            Argument::Value(Value::Integer(i)) => Count::Integer(*i),
+            Argument::Count(count) => *count,
+            Argument::Value(Value::String(s)) => {
+                Count::try_from(s.as_str())
+                    .or_else(|_| self.number(name).map(Count::from))?
+            }
+            _ => Count::from(self.number(name)?),
+        };
+
+        Ok(match count {
+            Count::Integer(i) if offset.fract() == 0.0 => {
+                Count::Integer(i - offset as i64)
+            }
+            Count::Integer(i) => Count::from(i as f64 - offset),
+            Count::Decimal { .. } if offset == 0.0 => count,
+            Count::Decimal { value, .. } => Count::from(value - offset),
+        })
+    }
+}
+
+impl<'a> std::fmt::Display for Argument<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Argument::Value(value) => write!(f, "{}", value),
+            Argument::Count(count) => write!(f, "{}", count),
+        }
+    }
+}
+
+fn argument<'a>(
+    context: &'a Context,
+    name: &str,
+) -> Result<Argument<'a>, Error> {
+    if name == "?" {
+        return match context.count {
+            Some(count) => Ok(Argument::Count(count)),
+            None => Err(Error::FormattingError(
+                "invalid reference to count ( {?} ) value.".to_string(),
+            )),
+        };
+    }
+
+    let value = if let Ok(index) = name.parse::<usize>() {
+        context.values.get(index).map(|(_, value)| value)
+    } else {
+        context.values.iter().find(|(k, _)| k == name).map(|(_, value)| value)
+    };
+
+    value.map(Argument::Value).ok_or_else(|| {
+        Error::FormattingError(format!(
+            "cannot find value `'{name}'` in this context."
+        ))
+    })
+}
+
+fn write_nodes(
+    buffer: &mut String,
+    nodes: &[Node],
+    locale: &Locale,
+    context: &Context,
+    pound: Option<&Count>,
+) -> Result<(), Error> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => buffer.push_str(text),
+            Node::Pound => {
+                if let Some(count) = pound {
+                    buffer.push_str(&count.to_string());
+                }
+            }
+            Node::Argument { name, style } => {
+                let argument = argument(context, name)?;
+                match style {
+                    Style::None | Style::Number => {
+                        buffer.push_str(&argument.to_string())
+                    }
+                    Style::Integer => buffer.push_str(
+                        &(argument.number(name)?.round()).to_string(),
+                    ),
+                    Style::Percent => {
+                        let percent = argument.number(name)? * 100.0;
+                        buffer.push_str(&percent.round().to_string());
+                        buffer.push('%');
+                    }
+                }
+            }
+            Node::Plural { name, ordinal: is_ordinal, offset, branches } => {
+                let argument = argument(context, name)?;
+                let value = argument.number(name)?;
+                let count = argument.count(name, *offset)?;
+                let category = if *is_ordinal {
+                    ordinal(locale, &count.operands())
+                } else {
+                    cardinal(locale, &count.operands())
+                };
+
+                let exact = branches.iter().find(|(selector, _)| {
+                    matches!(selector, Selector::Exact(v) if *v == value)
+                });
+                let branch = exact
+                    .or_else(|| {
+                        branches.iter().find(|(selector, _)| {
+                            *selector == Selector::Category(category)
+                        })
+                    })
+                    .or_else(|| {
+                        branches.iter().find(|(selector, _)| {
+                            *selector
+                                == Selector::Category(PluralCategory::Other)
+                        })
+                    });
+
+                if let Some((_, message)) = branch {
+                    write_nodes(
+                        buffer,
+                        message,
+                        locale,
+                        context,
+                        Some(&count),
+                    )?;
+                }
+            }
+            Node::Select { name, branches } => {
+                let key = argument(context, name)?.to_string();
+                let branch = branches
+                    .iter()
+                    .find(|(keyword, _)| *keyword == key)
+                    .or_else(|| {
+                        branches.iter().find(|(keyword, _)| keyword == "other")
+                    });
+
+                if let Some((_, message)) = branch {
+                    write_nodes(buffer, message, locale, context, pound)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::context;
+    use crate::formatter::icu::IcuFormatter;
+    use crate::formatter::Formatter;
+    use crate::locale::ArabicVariant;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::Locale;
+
+    macro_rules! assert_ok {
+        ($locale:expr, $msg:expr, $context:expr, $expected:literal) => {{
+            let msg = IcuFormatter::new().format(&$locale, $msg, &$context);
+
+            assert_eq!(msg.unwrap(), $expected.to_string());
+        }};
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            assert_ok!(
+                Locale::English(EnglishVariant::Default),
+                $msg,
+                $context,
+                $expected
+            );
+        }};
+    }
+
+    macro_rules! assert_err {
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            let msg = IcuFormatter::new().format(
+                &Locale::English(EnglishVariant::Default),
+                $msg,
+                &$context,
+            );
+
+            assert_eq!(msg.unwrap_err().to_string(), $expected.to_string());
+        }};
+    }
+
+    #[test]
+    fn arguments() {
+        assert_ok!(
+            "Hello, {name}! {0} {1, number} {?}",
+            context!(name = "Saif", b = 2.5, ? = 3),
+            "Hello, Saif! Saif 2.5 3"
+        );
+        assert_ok!(
+            "{a, number, integer} {b, number, percent}",
+            context!(a = 2.6, b = 0.25),
+            "3 25%"
+        );
+    }
+
+    #[test]
+    fn plural() {
+        let message =
+            "{count, plural, =0 {no apples} one {# apple} other {# apples}}";
+
+        assert_ok!(message, context!(count = 0), "no apples");
+        assert_ok!(message, context!(count = 1), "1 apple");
+        assert_ok!(message, context!(count = 7), "7 apples");
+        assert_ok!(message, context!(count = 1.5), "1.5 apples");
+
+        let message = "{?, plural, zero {لا تفاحات} one {تفاحة} two {تفاحتان} few {# تفاحات} many {# تفاحة} other {# تفاحة}}";
+        let arabic = Locale::Arabic(ArabicVariant::Default);
+
+        assert_ok!(arabic, message, context!(? = 0), "لا تفاحات");
+        assert_ok!(arabic, message, context!(? = 2), "تفاحتان");
+        assert_ok!(arabic, message, context!(? = 5), "5 تفاحات");
+        assert_ok!(arabic, message, context!(? = 11), "11 تفاحة");
+
+        let message = "{count, plural, one {# pomme} other {# pommes}}";
+        let french = Locale::French(FrenchVariant::Default);
+
+        assert_ok!(french, message, context!(count = 0), "0 pomme");
+        assert_ok!(french, message, context!(count = 2), "2 pommes");
+    }
+
+    #[test]
+    fn plural_offset() {
+        let message = "{count, plural, offset:1 =0 {nobody} =1 {{host}} one {{host} and # other} other {{host} and # others}}";
+
+        assert_ok!(message, context!(host = "Saif", count = 0), "nobody");
+        assert_ok!(message, context!(host = "Saif", count = 1), "Saif");
+        assert_ok!(
+            message,
+            context!(host = "Saif", count = 2),
+            "Saif and 1 other"
+        );
+        assert_ok!(
+            message,
+            context!(host = "Saif", count = 5),
+            "Saif and 4 others"
+        );
+    }
+
+    #[test]
+    fn selectordinal() {
+        let message = "{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+
+        assert_ok!(message, context!(place = 1), "1st");
+        assert_ok!(message, context!(place = 2), "2nd");
+        assert_ok!(message, context!(place = 23), "23rd");
+        assert_ok!(message, context!(place = 11), "11th");
+    }
+
+    #[test]
+    fn nested_select() {
+        let message = "{gender, select, male {He has {count, plural, one {# cat} other {# cats}}} female {She has {count, plural, one {# cat} other {# cats}}} other {They have {count, plural, one {# cat} other {# cats}}}}.";
+
+        assert_ok!(
+            message,
+            context!(gender = "male", count = 1),
+            "He has 1 cat."
+        );
+        assert_ok!(
+            message,
+            context!(gender = "female", count = 3),
+            "She has 3 cats."
+        );
+        assert_ok!(
+            message,
+            context!(gender = "robot", count = 2),
+            "They have 2 cats."
+        );
+
+        let message =
+            "{count, plural, other {{gender, select, other {# items}}}}";
+
+        assert_ok!(message, context!(count = 3, gender = "x"), "3 items");
+    }
+
+    #[test]
+    fn quoting() {
+        assert_ok!("It''s {name}", context!(name = "Saif"), "It's Saif");
+        assert_ok!("It's {name}", context!(name = "Saif"), "It's Saif");
+        assert_ok!("'{name}'", context!(name = "Saif"), "{name}");
+        assert_ok!("'{'{name}'}'", context!(name = "Saif"), "{Saif}");
+        assert_ok!("# '#'", context!(), "# '#'");
+        assert_ok!(
+            "{count, plural, other {'#' is #}}",
+            context!(count = 3),
+            "# is 3"
+        );
+        assert_ok!("'{unterminated", context!(), "{unterminated");
+    }
+
+    #[test]
+    fn errors() {
+        assert_err!(
+            "{name",
+            context!(name = "Saif"),
+            "invalid message format: expected `'}'` but string was terminated."
+        );
+        assert_err!(
+            "name}",
+            context!(),
+            "invalid message format: unmatched `'}'` at position 4."
+        );
+        assert_err!(
+            "{count, plural, one {# apple}}",
+            context!(count = 1),
+            "invalid message format: missing `'other'` branch for argument `'count'`."
+        );
+        assert_err!(
+            "{gender, select, male {he}}",
+            context!(gender = "male"),
+            "invalid message format: missing `'other'` branch for argument `'gender'`."
+        );
+        assert_err!(
+            "{count, plural, uno {# apple} other {# apples}}",
+            context!(count = 1),
+            "formatting: invalid plural category, expected one of `'zero'`, `'one'`, `'two'`, `'few'`, `'many'` or `'other'`, found `'uno'`."
+        );
+        assert_err!(
+            "{when, date}",
+            context!(when = 1),
+            "invalid message format: unsupported argument type `'date'` for argument `'when'`."
+        );
+        assert_err!(
+            "{missing}",
+            context!(),
+            "cannot find value `'missing'` in this context."
+        );
+        assert_err!(
+            "{count, plural, other {#}}",
+            context!(count = "many"),
+            "invalid argument: expected a number for `'count'`, found `'many'`."
+        );
+    }
+}
@@ -0,0 +1,1323 @@
+#[cfg(feature = "fluent")]
+pub mod fluent;
+pub mod icu;
+
+use crate::catalogue::Message;
+use crate::context::Context;
+use crate::context::Count;
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::plural::cardinal;
+use crate::plural::ordinal;
+use crate::plural::PluralCategory;
+
+/// A `Formatter` trait.
+///
+/// # Syntax.
+///
+/// Each `Formatter` implementation defines it's own syntax rules for the message format,
+/// this includes the syntax used for accessing context values, and plural message syntax.
+///
+/// The default message syntax is quite similar to rust's own fmt syntax.
+///
+/// To include a context value in your message, you can use own of the three following syntax options:
+///
+/// 1. name: `Hello, {name}! How is {other} doing?`.
+/// 2. position: `Hello, {0}! How is {1} doing?`.
+/// 3. index: `Hello, {}! How is {} doing?`.
+///
+/// The plural message syntax is defined using `|`, where each message has it own rule, followed by a
+/// default message.
+///
+/// The special `?` context variable can be used to access the `count` value.
+///
+/// When the context is ordinal ( e.g. `context!(# = 2)` ), categories are selected using the ordinal
+/// rules of the locale instead:
+///
+/// ```toml
+/// place = "{one} {?}st place | {two} {?}nd place | {few} {?}rd place | {other} {?}th place"
+/// ```
+///
+/// ```toml
+/// apple = "{0} There are no apples | {1} There is one apple | {2..4} There are few apples | There are {?} apples"
+/// ```
+///
+/// A rule can represent a list of numbers, such as:
+///
+/// - `{1}`
+/// - `{1, 2, 3}`
+///
+/// or a range:
+///
+/// - `{5..}`
+/// - `{..3}`
+/// - `{2..4}`
+///
+/// or a CLDR plural category, selected using the plural rules of the locale:
+///
+/// - `{zero}`
+/// - `{one}`
+/// - `{two}`
+/// - `{few}`
+/// - `{many}`
+///
+/// ```toml
+/// apple = "{zero} لا توجد تفاحات | {one} تفاحة واحدة | {two} تفاحتان | {few} {?} تفاحات | {many} {?} تفاحة | {other} {?} تفاحة"
+/// ```
+///
+/// Rules are checked in order, so a number rule placed before a category takes precedence over it.
+/// The default message may optionally be marked with `{other}`.
+///
+/// A select chooses a branch using the value of a named context value, falling back to the `other`
+/// branch when no key matches:
+///
+/// ```toml
+/// invite = "{gender, male: He invited you | female: She invited you | other: They invited you}"
+/// ```
+///
+/// Branches may contain placeholders, nested selects and the count, and can be combined with plural
+/// messages:
+///
+/// ```toml
+/// photos = "{one} {gender, male: He added a photo | other: {name} added a photo} | {gender, male: He added {?} photos | other: {name} added {?} photos}"
+/// ```
+pub trait Formatter: Send + Sync {
+    /// Format a message.
+    ///
+    /// The `locale` carries the unicode extension keywords of the requested locale, e.g. the `nu`
+    /// key of `ar_u_nu_latn`, see [`Locale::extension`].
+    fn format(
+        &self,
+        locale: &Locale,
+        message: &str,
+        context: &Context,
+    ) -> Result<String, Error>;
+
+    /// Format a catalogue message.
+    ///
+    /// Implementations may override this method to compile the message once, using
+    /// [`Message::compiled`], and reuse the compiled form on subsequent calls.
+    ///
+    /// By default, the message source is formatted using [`Formatter::format`].
+    fn format_message(
+        &self,
+        locale: &Locale,
+        message: &Message,
+        context: &Context,
+    ) -> Result<String, Error> {
+        self.format(locale, message.as_str(), context)
+    }
+
+    fn box_clone(&self) -> Box<dyn Formatter>;
+}
+
+/// Default implementation for `Formatter`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::context;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ArabicVariant;
+/// use tarjama::formatter::Formatter;
+///
+/// let formatter: Box<dyn Formatter> = Default::default();
+///
+/// let message = formatter.format(
+///     &Locale::Arabic(ArabicVariant::Tunisia),
+///     "!أهلا {name}",
+///     &context!(name = "سيف")
+/// ).unwrap();
+///
+/// assert_eq!(message, "!أهلا سيف".to_string());
+/// ```
+impl Default for Box<dyn Formatter> {
+    // Return a default implementation for `Formatter`.
+    fn default() -> Self {
+        DefaultFormatter::new().into()
+    }
+}
+
+impl Clone for Box<dyn Formatter> {
+    fn clone(&self) -> Box<dyn Formatter> {
+        self.box_clone()
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash)]
+struct DefaultFormatter;
+
+impl DefaultFormatter {
+    pub fn new() -> DefaultFormatter {
+        DefaultFormatter
+    }
+}
+
+impl From<DefaultFormatter> for Box<dyn Formatter> {
+    fn from(formatter: DefaultFormatter) -> Self {
+        Box::new(formatter)
+    }
+}
+
+impl Formatter for DefaultFormatter {
+    fn format(
+        &self,
+        locale: &Locale,
+        message: &str,
+        context: &Context,
+    ) -> Result<String, Error> {
+        if let Some(count) = context.count {
+            let plural_messages = parse_plural_messages(message)?;
+            let message = plural_messages
+                .matching(&count, category(locale, context, &count));
+
+            format_nodes(&parse_nodes(message)?, context)
+        } else {
+            format_nodes(&parse_nodes(message)?, context)
+        }
+    }
+
+    fn format_message(
+        &self,
+        locale: &Locale,
+        message: &Message,
+        context: &Context,
+    ) -> Result<String, Error> {
+        match message.compiled(CompiledMessage::new) {
+            Some(compiled) => compiled.format(locale, context),
+            None => self.format(locale, message.as_str(), context),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn Formatter> {
+        Box::new(*self)
+    }
+}
+
+impl Default for DefaultFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+enum Rule {
+    RangeTo { to: i64 },
+    RangeFrom { from: i64 },
+    Range { from: i64, to: i64 },
+    Match { values: Vec<i64> },
+    Category { category: PluralCategory },
+}
+
+impl Rule {
+    pub fn matches(&self, count: &Count, category: PluralCategory) -> bool {
+        let value = match count {
+            Count::Integer(value) => *value,
+            Count::Decimal { value, .. } => {
+                return match self {
+                    Rule::RangeTo { to } => *value <= *to as f64,
+                    Rule::RangeFrom { from } => *value >= *from as f64,
+                    Rule::Range { from, to } => {
+                        *value >= *from as f64 && *value <= *to as f64
+                    }
+                    Rule::Match { values } => {
+                        values.iter().any(|v| *v as f64 == *value)
+                    }
+                    Rule::Category { category: c } => *c == category,
+                };
+            }
+        };
+
+        match self {
+            Rule::RangeTo { to } => value <= *to,
+            Rule::RangeFrom { from } => value >= *from,
+            Rule::Range { from, to } => value >= *from && value <= *to,
+            Rule::Match { values } => values.contains(&value),
+            Rule::Category { category: c } => *c == category,
+        }
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rule::RangeTo { to } => write!(f, "{{..{}}}", to),
+            Rule::RangeFrom { from } => write!(f, "{{{}..}}", from),
+            Rule::Range { from, to } => write!(f, "{{{}..{}}}", from, to),
+            Rule::Match { values } => write!(
+                f,
+                "{{{}}}",
+                values
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Rule::Category { category } => write!(f, "{{{}}}", category),
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+struct PluralMessages<T = String> {
+    pub rules: Vec<(T, Rule)>,
+    pub default: T,
+}
+
+impl<T> PluralMessages<T> {
+    pub fn matching(&self, count: &Count, category: PluralCategory) -> &T {
+        for (message, rule) in &self.rules {
+            if rule.matches(count, category) {
+                return message;
+            }
+        }
+
+        &self.default
+    }
+}
+
+/// A node of a message compiled by the default formatter.
+#[doc(hidden)]
+#[derive(Debug)]
+enum Node {
+    /// Literal text, with brackets already unescaped.
+    Literal(String),
+    /// An indexed value, e.g. `{}`.
+    Next,
+    /// A positional value, e.g. `{0}`.
+    Position(usize),
+    /// A named value, e.g. `{name}`, or the count, `{?}`.
+    Named(String),
+    /// A select, e.g. `{gender, male: He | female: She | other: They}`.
+    Select { name: String, branches: Vec<(String, Vec<Node>)> },
+}
+
+/// A message compiled by the default formatter.
+///
+/// Parse errors are kept, and only reported when the erroneous part of the message is used.
+#[doc(hidden)]
+#[derive(Debug)]
+struct CompiledMessage {
+    /// The message nodes, used when the context has no count.
+    nodes: Result<Vec<Node>, String>,
+    /// The plural messages nodes, used when the context has a count.
+    plural: Result<PluralMessages<Result<Vec<Node>, String>>, String>,
+}
+
+impl CompiledMessage {
+    pub fn new(message: &str) -> Self {
+        let compile = |message: &str| {
+            parse_nodes(message).map_err(|error| error.to_string())
+        };
+
+        CompiledMessage {
+            nodes: compile(message),
+            plural: match parse_plural_messages(message) {
+                Ok(plural_messages) => Ok(PluralMessages {
+                    rules: plural_messages
+                        .rules
+                        .into_iter()
+                        .map(|(message, rule)| (compile(&message), rule))
+                        .collect(),
+                    default: compile(&plural_messages.default),
+                }),
+                Err(error) => Err(error.to_string()),
+            },
+        }
+    }
+
+    pub fn format(
+        &self,
+        locale: &Locale,
+        context: &Context,
+    ) -> Result<String, Error> {
+        let nodes = if let Some(count) = context.count {
+            let plural_messages = self
+                .plural
+                .as_ref()
+                .map_err(|error| Error::FormattingError(error.clone()))?;
+
+            plural_messages.matching(&count, category(locale, context, &count))
+        } else {
+            &self.nodes
+        };
+
+        match nodes {
+            Ok(nodes) => format_nodes(nodes, context),
+            Err(error) => Err(Error::FormattingError(error.clone())),
+        }
+    }
+}
+
+fn category(
+    locale: &Locale,
+    context: &Context,
+    count: &Count,
+) -> PluralCategory {
+    let operands = count.operands();
+    if context.ordinal {
+        ordinal(locale, &operands)
+    } else {
+        cardinal(locale, &operands)
+    }
+}
+
+fn format_nodes(nodes: &[Node], context: &Context) -> Result<String, Error> {
+    let mut buffer = String::new();
+
+    write_nodes(&mut buffer, nodes, context, &mut 0)?;
+
+    if context.count.is_some() && buffer.contains("||") {
+        Ok(buffer.replace("||", "|"))
+    } else {
+        Ok(buffer)
+    }
+}
+
+fn write_nodes(
+    buffer: &mut String,
+    nodes: &[Node],
+    context: &Context,
+    arg_idx: &mut usize,
+) -> Result<(), Error> {
+    for node in nodes {
+        let index = match node {
+            Node::Literal(literal) => {
+                buffer.push_str(literal);
+
+                continue;
+            }
+            Node::Select { name, branches } => {
+                let value =
+                    match context.values.iter().find(|(k, _)| k == name) {
+                        Some((_, value)) => value.to_string(),
+                        None => {
+                            return Err(Error::FormattingError(format!(
+                            "cannot find value `'{name}'` in this context."
+                        )));
+                        }
+                    };
+
+                let branch = branches
+                    .iter()
+                    .find(|(key, _)| *key == value)
+                    .or_else(|| {
+                        branches.iter().rev().find(|(key, _)| key == "other")
+                    });
+
+                match branch {
+                    Some((_, nodes)) => {
+                        write_nodes(buffer, nodes, context, arg_idx)?
+                    }
+                    None => {
+                        return Err(Error::FormattingError(format!(
+                            "invalid select format: no branch matches `'{value}'` for `'{name}'`, and no `'other'` branch was found."
+                        )));
+                    }
+                }
+
+                continue;
+            }
+            Node::Next => {
+                *arg_idx += 1;
+                if context.values.len() < *arg_idx {
+                    return Err(Error::FormattingError(format!(
+                        "invalid reference to indexed value `'{}'` (there is {} value).",
+                        *arg_idx - 1,
+                        context.values.len()
+                    )));
+                }
+
+                Some(*arg_idx - 1)
+            }
+            Node::Position(index) => Some(*index),
+            Node::Named(name) => {
+                if let Some(p) =
+                    context.values.iter().position(|x| &x.0 == name)
+                {
+                    Some(p)
+                } else if name == "?" {
+                    None
+                } else {
+                    return Err(Error::FormattingError(format!(
+                        "cannot find value `'{name}'` in this context."
+                    )));
+                }
+            }
+        };
+
+        if let Some(index) = index {
+            if let Some(a) = context.values.get(index) {
+                buffer.push_str(&a.1.to_string());
+            } else {
+                return Err(Error::FormattingError(format!(
+                    "invalid reference to positional value `'{}'` (there is {} value).",
+                    index,
+                    context.values.len()
+                )));
+            }
+        } else if let Some(count) = context.count {
+            buffer.push_str(&count.to_string());
+        } else {
+            return Err(Error::FormattingError(
+                "invalid reference to count ( {?} ) value.".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_nodes(message: &str) -> Result<Vec<Node>, Error> {
+    let mut nodes = vec![];
+    let mut literal = String::new();
+    let mut position = 0;
+    while let Some(mut current_position) = message[position..].find(['{', '}'])
+    {
+        current_position += position;
+
+        // Skip escaped }
+        if message.get(current_position..=current_position) == Some("}") {
+            literal.push_str(&message[position..=current_position]);
+
+            match message.get(current_position + 1..=current_position + 1) {
+                Some("}") => {
+                    position = current_position + 2;
+                }
+                Some(u) => {
+                    return Err(Error::FormattingError(format!(
+                        "invalid format string: expected `'}}'`, found `'{u}'`."
+                    )));
+                }
+                None => {
+                    return Err(Error::FormattingError(
+                        "invalid format string: expected `'}'` but string was terminated."
+                            .to_string(),
+                    ));
+                }
+            }
+
+            continue;
+        }
+
+        // Skip escaped {
+        if message.get(current_position + 1..=current_position + 1)
+            == Some("{")
+        {
+            literal.push_str(&message[position..=current_position]);
+            position = current_position + 2;
+
+            continue;
+        }
+
+        let left_curly_brackets_position = match closing_bracket_position(
+            message,
+            current_position,
+        ) {
+            Some(left_curly_brackets_position) => left_curly_brackets_position,
+            None => {
+                return Err(Error::FormattingError(
+                    "invalid format string: expected `'}'` but string was terminated.".to_string(),
+                ));
+            }
+        };
+
+        // push the part before the '{' as a literal
+        literal.push_str(&message[position..current_position]);
+        if !literal.is_empty() {
+            nodes.push(Node::Literal(std::mem::take(&mut literal)));
+        }
+
+        let argument_name =
+            message[current_position + 1..left_curly_brackets_position].trim();
+
+        nodes.push(
+            if let Some((name, branches)) = argument_name.split_once(',') {
+                parse_select(name.trim(), branches)?
+            } else if current_position == left_curly_brackets_position - 1 {
+                Node::Next
+            } else if let Ok(n) = argument_name.parse::<usize>() {
+                Node::Position(n)
+            } else {
+                Node::Named(argument_name.to_string())
+            },
+        );
+
+        position = left_curly_brackets_position + 1;
+    }
+
+    literal.push_str(&message[position..]);
+    if !literal.is_empty() {
+        nodes.push(Node::Literal(literal));
+    }
+
+    Ok(nodes)
+}
+
+/// Find the position of the `}` closing the `{` at `open`, taking nested brackets into account.
+fn closing_bracket_position(message: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (position, c) in message[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + position);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Parse the branches of a select, e.g. `{gender, male: He | female: She | other: They}`.
+fn parse_select(name: &str, branches: &str) -> Result<Node, Error> {
+    let mut parsed = vec![];
+    for branch in split_select_branches(branches) {
+        match branch.split_once(':') {
+            Some((key, message)) => parsed
+                .push((key.trim().to_string(), parse_nodes(message.trim())?)),
+            None => {
+                return Err(Error::FormattingError(format!(
+                    "invalid select format: expected `'key: message'` for `'{name}'`, found `'{}'`.",
+                    branch.trim()
+                )));
+            }
+        }
+    }
+
+    Ok(Node::Select { name: name.to_string(), branches: parsed })
+}
+
+/// Split select branches on `|`, ignoring nested brackets, `||` is a literal `|`.
+fn split_select_branches(branches: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut chars = branches.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                if chars.peek() == Some(&'|') {
+                    chars.next();
+                    current.push('|');
+                } else {
+                    result.push(std::mem::take(&mut current));
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        current.push(c);
+    }
+
+    result.push(current);
+
+    result
+}
+
+#[doc(hidden)]
+fn parse_plural_messages(message: &str) -> Result<PluralMessages, Error> {
+    let message = message.trim();
+    let mut messages: Vec<_> = vec![];
+
+    if !message.contains('|') {
+        if !message.is_empty() {
+            messages.push(message.to_string());
+        }
+    } else {
+        use unicode_segmentation::UnicodeSegmentation;
+        let graphemes = UnicodeSegmentation::graphemes(message, true)
+            .collect::<Vec<&str>>();
+
+        messages = match split_plural_messages(&graphemes, true) {
+            Some(messages) => messages,
+            // unbalanced brackets, let the rule parser report the error.
+            None => {
+                split_plural_messages(&graphemes, false).unwrap_or_default()
+            }
+        };
+    }
+
+    if let Some((last, messages)) = messages.split_last() {
+        let mut rules: Vec<(String, Rule)> = vec![];
+        for message in messages {
+            if message.starts_with('{') {
+                if let Some(ending_position) = message.find('}') {
+                    let rule_string = &message[1..ending_position];
+                    let target = &message[ending_position + 1..].trim();
+                    let rule = if let Ok(category) =
+                        PluralCategory::try_from(rule_string.trim())
+                    {
+                        Rule::Category { category }
+                    } else if let Some(sep_position) = rule_string.find("..") {
+                        if sep_position == 0 {
+                            Rule::RangeTo {
+                                to: match rule_string[2..].parse::<i64>() {
+                                    Ok(to) => to,
+                                    Err(e) => {
+                                        return Err(Error::FormattingError(format!(
+                                            "formatting: failed to parse `'to'` value in range-to rule for `'{message}'`, {e}.",
+                                        )));
+                                    }
+                                },
+                            }
+                        } else if sep_position == rule_string.len() - 2 {
+                            Rule::RangeFrom {
+                                from: match rule_string[..sep_position]
+                                    .parse::<i64>()
+                                {
+                                    Ok(from) => from,
+                                    Err(e) => {
+                                        return Err(Error::FormattingError(format!(
+                                            "formatting: failed to parse `'from'` value in range-from rule for `'{message}'`, {e}.",
+                                        )));
+                                    }
+                                },
+                            }
+                        } else {
+                            Rule::Range {
+                                from: match rule_string[..sep_position]
+                                    .parse::<i64>()
+                                {
+                                    Ok(from) => from,
+                                    Err(e) => {
+                                        return Err( Error::FormattingError(format!(
+                                            "formatting: failed to parse `'from'` value in range rule for `'{message}'`, {e}.",
+                                        )));
+                                    }
+                                },
+                                to: match rule_string[sep_position + 2..]
+                                    .parse::<i64>()
+                                {
+                                    Ok(to) => to,
+                                    Err(e) => {
+                                        return Err( Error::FormattingError(format!(
+                                            "formatting: failed to parse `'to'` value in range rule for `'{message}'`, {e}.",
+                                        )));
+                                    }
+                                },
+                            }
+                        }
+                    } else {
+                        let mut values = vec![];
+                        for value_str in
+                            rule_string.split(',').map(|s| s.trim())
+                        {
+                            values.push(match value_str.parse::<i64>() {
+                                Ok(value) => value,
+                                Err(e) => {
+                                    return Err( Error::FormattingError(format!(
+                                        "formatting: failed to parse value `'{value_str}'` in match rule for `'{message}'`, {e}.",
+                                    )));
+                                },
+                            });
+                        }
+
+                        Rule::Match { values }
+                    };
+
+                    rules.push((target.to_string(), rule));
+                } else {
+                    return Err(Error::FormattingError(format!("formatting: failed to parse rule for `'{message}'`, expected `'}}'` but string was terminated.")));
+                }
+            } else {
+                return Err(Error::FormattingError(format!("formatting: failed to parse rule for `'{message}'`, expected `'{{'` but string was terminated.")));
+            }
+        }
+
+        let default = match last.strip_prefix('{') {
+            Some(rest) => match rest.split_once('}') {
+                Some((rule, target)) if rule.trim() == "other" => {
+                    target.trim()
+                }
+                _ => last,
+            },
+            None => last,
+        };
+
+        Ok(PluralMessages { rules, default: default.to_string() })
+    } else {
+        Err(Error::FormattingError("formatting: failed to parse plural messages, expected at least a default message but string was terminated.".to_string()))
+    }
+}
+
+/// Split plural messages on `|`, `||` is a literal `|`.
+///
+/// When `nested` is set, `|` within brackets ( e.g. in a select ) is ignored, `None` is returned
+/// if the brackets are unbalanced.
+fn split_plural_messages(
+    graphemes: &[&str],
+    nested: bool,
+) -> Option<Vec<String>> {
+    let mut messages = vec![];
+    let mut i = 0usize;
+    let mut s = 0usize;
+    let mut start = 0usize;
+    let mut depth = 0usize;
+    while i < graphemes.len() {
+        let next = graphemes.get(i + 1).copied();
+        if depth == 0
+            && ((graphemes[i] == "{" && next == Some("{"))
+                || (graphemes[i] == "}" && next == Some("}")))
+        {
+            // Skip escaped {{ and }}
+            s = 0;
+            i += 2;
+
+            continue;
+        }
+
+        if nested && graphemes[i] == "{" {
+            depth += 1;
+        } else if nested && graphemes[i] == "}" {
+            depth = depth.saturating_sub(1);
+        }
+
+        // `|` within a select branch does not separate plural messages.
+        if graphemes[i] == "|" && depth == 0 {
+            s += 1;
+            // lookahead for ||
+            if i + 1 < graphemes.len() && graphemes[i + 1] != "|" && s % 2 == 1
+            {
+                messages.push(graphemes[start..i].concat().trim().to_string());
+                start = i + 1;
+            }
+        } else {
+            s = 0;
+        }
+        i += 1;
+    }
+    if start < graphemes.len() {
+        messages.push(graphemes[start..].concat().trim().to_string()); // Push the remaining graphemes
+    }
+
+    if depth == 0 {
+        Some(messages)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::catalogue::Message;
+    use crate::context;
+    use crate::context::Context;
+    use crate::context::Count;
+    use crate::formatter::Formatter;
+    use crate::locale::ArabicVariant;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::Locale;
+    use crate::locale::RussianVariant;
+
+    macro_rules! assert_ok {
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            let formatter: Box<dyn Formatter> = Default::default();
+            let msg = formatter.format(
+                &Locale::English(EnglishVariant::Default),
+                $msg,
+                &$context,
+            );
+
+            assert!(msg.is_ok());
+            assert_eq!(msg.unwrap(), $expected.to_string());
+        }};
+    }
+
+    macro_rules! assert_err {
+        ($msg:expr, $context:expr, $expected:literal) => {{
+            let formatter: Box<dyn Formatter> = Default::default();
+            let msg = formatter.format(
+                &Locale::English(EnglishVariant::Default),
+                $msg,
+                &$context,
+            );
+
+            assert!(msg.is_err());
+            assert_eq!(msg.unwrap_err().to_string(), $expected.to_string());
+        }};
+    }
+
+    #[test]
+    fn format_syntax() {
+        assert_ok!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}",
+            context!(a = 1, b = 2, c = 3, ? = 5),
+            "a = 1, b = 2, a = 1, b = 2, c = 3, c = 3, c = 3, count = 5"
+        );
+    }
+
+    #[test]
+    fn access_undefined() {
+        assert_err!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}, d = {d}",
+            context!(a = 1, b = 2, c = 3, ? = 5),
+            "cannot find value `'d'` in this context."
+        );
+
+        assert_err!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}, d = {d}",
+            context!(a = 1, b = 2, c = 3, ? = 5),
+            "cannot find value `'d'` in this context."
+        );
+    }
+
+    #[test]
+    fn access_undefined_positioned_context() {
+        assert_err!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}, d = {3}",
+            context!(a = 1, b = 2, c = 3, ? = 5),
+            "invalid reference to positional value `'3'` (there is 3 value)."
+        );
+    }
+
+    #[test]
+    fn access_undefined_indexed_context() {
+        assert_err!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}, d = {}",
+            context!(a = 1, b = 2, c = 3, ? = 5),
+            "invalid reference to indexed value `'3'` (there is 3 value)."
+        );
+    }
+
+    #[test]
+    fn access_undefined_count_context() {
+        assert_err!(
+            "a = {a}, b = {1}, a = {}, b = {}, c = {}, c = {2}, c = {c}, count = {?}",
+            context!(a = 1, b = 2, c = 3),
+            "invalid reference to count ( {?} ) value."
+        );
+    }
+
+    #[test]
+    fn escape_brackets() {
+        assert_ok!("a = {{{a}}}", context!(a = 1), "a = {1}");
+        assert_ok!("{{", context!(), "{");
+        assert_ok!("}}", context!(), "}");
+    }
+
+    #[test]
+    fn rule_matching() {
+        let message =
+            "{0} foo | {1, 2} bar | {..5} baz | {10..} qux | fizz || bizz";
+
+        assert_ok!(message, context!(? = 0), "foo");
+        assert_ok!(message, context!(? = 1), "bar");
+        assert_ok!(message, context!(? = 2), "bar");
+        assert_ok!(message, context!(? = 3), "baz");
+        assert_ok!(message, context!(? = 4), "baz");
+        assert_ok!(message, context!(? = 5), "baz");
+        assert_ok!(message, context!(? = 6), "fizz | bizz");
+        assert_ok!(message, context!(? = 7), "fizz | bizz");
+        assert_ok!(message, context!(? = 8), "fizz | bizz");
+        assert_ok!(message, context!(? = 9), "fizz | bizz");
+        assert_ok!(message, context!(? = 10), "qux");
+        assert_ok!(message, context!(? = 100), "qux");
+    }
+
+    #[test]
+    fn category_matching() {
+        let message = "{0} none | {one} one | {other} {?} many";
+
+        assert_ok!(message, context!(? = 0), "none");
+        assert_ok!(message, context!(? = 1), "one");
+        assert_ok!(message, context!(? = 2), "2 many");
+        assert_ok!(message, context!(? = 21), "21 many");
+
+        let formatter: Box<dyn Formatter> = Default::default();
+        let message = "{zero} zero | {one} one | {two} two | {few} few | {many} many | other";
+        let locale = Locale::Arabic(ArabicVariant::Default);
+
+        for (count, expected) in [
+            (0, "zero"),
+            (1, "one"),
+            (2, "two"),
+            (3, "few"),
+            (110, "few"),
+            (11, "many"),
+            (100, "other"),
+        ] {
+            assert_eq!(
+                formatter
+                    .format(&locale, message, &context!(? = count))
+                    .unwrap(),
+                expected
+            );
+        }
+
+        let message = "{one} one | {few} few | {many} many | other";
+        let locale = Locale::Russian(RussianVariant::Default);
+
+        for (count, expected) in
+            [(1, "one"), (21, "one"), (3, "few"), (11, "many"), (25, "many")]
+        {
+            assert_eq!(
+                formatter
+                    .format(&locale, message, &context!(? = count))
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_matching() {
+        let message = "{0} none | {one} {?} kilometre | {?} kilometres";
+
+        assert_ok!(message, context!(? = 0.0), "none");
+        assert_ok!(message, context!(? = 1), "1 kilometre");
+        assert_ok!(message, context!(? = 1.5), "1.5 kilometres");
+        assert_ok!(message, context!(? = 0.1f32), "0.1 kilometres");
+
+        let formatter: Box<dyn Formatter> = Default::default();
+        let locale = Locale::French(FrenchVariant::Default);
+
+        assert_eq!(
+            formatter.format(&locale, message, &context!(? = 1.5)).unwrap(),
+            "1.5 kilometre"
+        );
+
+        // `1.0` has a visible fraction digit, and is `other`, not `one`.
+        assert_ok!(message, context!(? = 1.0), "1.0 kilometres");
+        assert_ok!(
+            message,
+            Context::new(vec![], None)
+                .with_count(Count::try_from("1.50").unwrap()),
+            "1.50 kilometres"
+        );
+
+        let locale = Locale::Russian(RussianVariant::Default);
+        let message = "{one} {?} километр | {few} {?} километра | {many} {?} километров | {other} {?} километра";
+
+        for (count, expected) in [
+            (context!(? = 1), "1 километр"),
+            (context!(? = 1.0), "1.0 километра"),
+            (context!(? = 21), "21 километр"),
+            (context!(? = 2.5), "2.5 километра"),
+        ] {
+            assert_eq!(
+                formatter.format(&locale, message, &count).unwrap(),
+                expected
+            );
+        }
+
+        let message = "{..1} short | {1..2} medium | long";
+
+        assert_ok!(message, context!(? = 0.5), "short");
+        assert_ok!(message, context!(? = 1.5), "medium");
+        assert_ok!(message, context!(? = 2.5), "long");
+    }
+
+    #[test]
+    fn ordinal_matching() {
+        let message =
+            "{one} {?}st | {two} {?}nd | {few} {?}rd | {other} {?}th";
+
+        assert_ok!(message, context!(# = 1), "1st");
+        assert_ok!(message, context!(# = 2), "2nd");
+        assert_ok!(message, context!(# = 3), "3rd");
+        assert_ok!(message, context!(# = 4), "4th");
+        assert_ok!(message, context!(# = 11), "11th");
+        assert_ok!(message, context!(# = 22), "22nd");
+        assert_ok!(message, context!(? = 2), "2th");
+        assert_ok!(message, context!(? = 1), "1st");
+        assert_ok!(message, context!(? = 3), "3th");
+
+        let formatter: Box<dyn Formatter> = Default::default();
+        let message = "{one} {?}er | {?}e";
+        let locale = Locale::French(FrenchVariant::Default);
+
+        for (count, expected) in [(1, "1er"), (2, "2e"), (21, "21e")] {
+            assert_eq!(
+                formatter
+                    .format(&locale, message, &context!(# = count))
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn message_parse_errors() {
+        assert_err!(
+            "foo {bar",
+            context!(),
+            "invalid format string: expected `'}'` but string was terminated."
+        );
+        assert_err!(
+            "foo {",
+            context!(),
+            "invalid format string: expected `'}'` but string was terminated."
+        );
+        assert_err!(
+            "}{",
+            context!(),
+            "invalid format string: expected `'}'`, found `'{'`."
+        );
+        assert_err!(
+            "}{",
+            context!(),
+            "invalid format string: expected `'}'`, found `'{'`."
+        );
+        assert_err!(
+            "{",
+            context!(),
+            "invalid format string: expected `'}'` but string was terminated."
+        );
+    }
+
+    #[test]
+    fn select_matching() {
+        let message =
+            "{gender, male: He | female: She | other: They} invited you.";
+
+        assert_ok!(message, context!(gender = "male"), "He invited you.");
+        assert_ok!(message, context!(gender = "female"), "She invited you.");
+        assert_ok!(message, context!(gender = "robot"), "They invited you.");
+
+        assert_ok!(
+            "{n, 1: one | 2: two | other: {n}}",
+            context!(n = 2),
+            "two"
+        );
+        assert_ok!("{n, 1: one | 2: two | other: {n}}", context!(n = 5), "5");
+        assert_ok!(
+            "{kind, pipe: a || b | other: {{c}}}",
+            context!(kind = "pipe"),
+            "a | b"
+        );
+        assert_ok!(
+            "{kind, pipe: a || b | other: {{c}}}",
+            context!(kind = "x"),
+            "{c}"
+        );
+        assert_ok!(
+            "{a, x: {b, y: xy | other: x?} | other: other}",
+            context!(a = "x", b = "z"),
+            "x?"
+        );
+    }
+
+    #[test]
+    fn select_plural_matching() {
+        let message = "{0} {name} has no photos | {one} {gender, male: He added one photo | other: {name} added one photo} | {gender, male: He added {?} photos | other: {name} added {?} photos}";
+
+        assert_ok!(
+            message,
+            context!(name = "Saif", gender = "male", ? = 0),
+            "Saif has no photos"
+        );
+        assert_ok!(
+            message,
+            context!(name = "Saif", gender = "male", ? = 1),
+            "He added one photo"
+        );
+        assert_ok!(
+            message,
+            context!(name = "Sara", gender = "female", ? = 1),
+            "Sara added one photo"
+        );
+        assert_ok!(
+            message,
+            context!(name = "Sara", gender = "female", ? = 3),
+            "Sara added 3 photos"
+        );
+    }
+
+    #[test]
+    fn select_errors() {
+        assert_err!(
+            "{gender, male: He | other: They}",
+            context!(),
+            "cannot find value `'gender'` in this context."
+        );
+        assert_err!(
+            "{gender, male: He | female: She}",
+            context!(gender = "robot"),
+            "invalid select format: no branch matches `'robot'` for `'gender'`, and no `'other'` branch was found."
+        );
+        assert_err!(
+            "{gender, male: He | They}",
+            context!(gender = "robot"),
+            "invalid select format: expected `'key: message'` for `'gender'`, found `'They'`."
+        );
+        assert_err!(
+            "{gender, male: {name}",
+            context!(gender = "male"),
+            "invalid format string: expected `'}'` but string was terminated."
+        );
+    }
+
+    #[test]
+    fn compiled_messages() {
+        let formatter: Box<dyn Formatter> = Default::default();
+        let locale = Locale::English(EnglishVariant::Default);
+
+        for (message, context) in [
+            ("Hello, {name}!", context!(name = "Saif")),
+            ("{} {1} {0} {{escaped}}", context!(a = 1, b = 2)),
+            ("{0} none | {one} one | {?} many", context!(? = 0)),
+            ("{0} none | {one} one | {?} many", context!(? = 1)),
+            ("{0} none | {one} one | {?} many", context!(? = 4)),
+            ("{one} {?}st | {other} {?}th", context!(# = 1)),
+            ("fizz || {?} | buzz", context!(? = 1)),
+            ("fizz | buzz", context!()),
+            (
+                "{g, male: He | other: {n}} | {g, other: {?}}",
+                context!(g = "male", n = "x", ? = 1),
+            ),
+            (
+                "{g, male: He | other: {n}} | {g, other: {?}}",
+                context!(g = "male", n = "x", ? = 2),
+            ),
+            ("{foo}", context!()),
+            ("{?}", context!()),
+            ("{} {}", context!(a = 1)),
+            ("foo {bar", context!()),
+            ("{g, male: He}", context!(g = "female")),
+            ("{1 bar | {2} foo", context!(? = 1)),
+        ] {
+            let message = Message::from(message);
+            let expected =
+                formatter.format(&locale, message.as_str(), &context);
+            let actual = formatter.format_message(&locale, &message, &context);
+
+            match (expected, actual) {
+                (Ok(expected), Ok(actual)) => assert_eq!(expected, actual),
+                (Err(expected), Err(actual)) => {
+                    assert_eq!(expected.to_string(), actual.to_string())
+                }
+                (expected, actual) => panic!(
+                    "`{}`: expected {expected:?}, got {actual:?}.",
+                    message.as_str()
+                ),
+            }
+
+            // the message is compiled once, and reused.
+            assert!(message.compiled(|_| 0usize).is_none());
+        }
+    }
+
+    #[test]
+    fn plural_messages_parse_errors() {
+        assert_err!(
+            "{0} foo | {1 bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'{1 bar'`, expected `'}'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | 1} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'1} bar'`, expected `'{'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {uno} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse value `'uno'` in match rule for `'{uno} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | {1, two bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'{1, two bar'`, expected `'}'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | 1, two} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'1, two} bar'`, expected `'{'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {1, two} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse value `'two'` in match rule for `'{1, two} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | ..two} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'..two} bar'`, expected `'{'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {..two bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'{..two bar'`, expected `'}'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {..two} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse `'to'` value in range-to rule for `'{..two} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | two..} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'two..} bar'`, expected `'{'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {two.. bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'{two.. bar'`, expected `'}'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {two..} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse `'from'` value in range-from rule for `'{two..} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | {one..5} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse `'from'` value in range rule for `'{one..5} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | 2..5} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'2..5} bar'`, expected `'{'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {2..5 bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse rule for `'{2..5 bar'`, expected `'}'` but string was terminated."
+        );
+
+        assert_err!(
+            "{0} foo | {2....5} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse `'to'` value in range rule for `'{2....5} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "{0} foo | {2.5} bar | baz",
+            context!(? = 2),
+            "formatting: failed to parse value `'2.5'` in match rule for `'{2.5} bar'`, invalid digit found in string."
+        );
+
+        assert_err!(
+            "",
+            context!(? = 2),
+            "formatting: failed to parse plural messages, expected at least a default message but string was terminated."
+        );
+    }
+}
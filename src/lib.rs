@@ -2,17 +2,26 @@
 pub mod actix;
 pub mod catalogue;
 pub mod context;
+pub mod environment;
 pub mod error;
+pub mod fallback;
 pub mod formatter;
 pub mod loader;
 pub mod locale;
 pub mod macros;
+pub mod negotiation;
+pub mod plural;
+pub mod reload;
+pub mod tag;
 
 use std::fmt::Display;
+use std::sync::Arc;
 
 use crate::catalogue::CatalogueBag;
 use crate::context::Context;
 use crate::error::Error;
+use crate::fallback::DefaultFallbackStrategy;
+use crate::fallback::FallbackStrategy;
 use crate::formatter::Formatter;
 use crate::locale::Locale;
 
@@ -43,27 +52,45 @@ use crate::locale::Locale;
 ///
 /// assert_eq!(message, "Hello, World!".to_string());
 /// ```
+///
+/// The formatter and the catalogues are shared between clones of a translator, making it cheap to
+/// clone, e.g. for every request of a web server.
 #[derive(Clone)]
 pub struct Translator {
-    formatter: Box<dyn Formatter>,
-    bag: CatalogueBag,
+    formatter: Arc<dyn Formatter>,
+    bag: Arc<CatalogueBag>,
     fallback_locale: Option<Locale>,
+    fallback_strategy: Arc<dyn FallbackStrategy>,
 }
 
-unsafe impl Send for Translator {}
-unsafe impl Sync for Translator {}
-
 impl Translator {
     pub fn new(
         formatter: Box<dyn Formatter>,
         bag: CatalogueBag,
         fallback_locale: Option<Locale>,
     ) -> Self {
-        Self { formatter, bag, fallback_locale }
+        Self {
+            formatter: Arc::from(formatter),
+            bag: Arc::new(bag),
+            fallback_locale,
+            fallback_strategy: Arc::new(DefaultFallbackStrategy::new()),
+        }
     }
 
     pub fn with_catalogue_bag(bag: CatalogueBag) -> Self {
-        Self { formatter: Default::default(), bag, fallback_locale: None }
+        Self::new(Default::default(), bag, None)
+    }
+
+    /// Returns a reference to the catalogue bag of the translator.
+    pub fn catalogue_bag(&self) -> &CatalogueBag {
+        &self.bag
+    }
+
+    /// Replace the catalogue bag of the translator.
+    ///
+    /// Clones of the translator made before the replacement keep using the previous catalogue bag.
+    pub fn set_catalogue_bag(&mut self, bag: CatalogueBag) {
+        self.bag = Arc::new(bag);
     }
 
     /// Set the fallback locale.
@@ -114,14 +141,171 @@ impl Translator {
         self.fallback_locale = fallback_locale.into();
     }
 
+    /// Set the fallback strategy.
+    ///
+    /// The fallback strategy decides which locales are looked up, and in which order, when a message is
+    /// not found using the given locale, see [`Translator::fallback_chain`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tarjama::fallback::ChainFallbackStrategy;
+    /// use tarjama::locale::*;
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    /// use tarjama::Translator;
+    /// use tarjama::context;
+    ///
+    /// let mut catalogue = Catalogue::new(Locale::French(FrenchVariant::Default));
+    /// catalogue.insert("messages", "greeting", "Bonjour, {name}!");
+    ///
+    /// let mut translator = Translator::with_catalogue_bag(CatalogueBag::with_catalogues(vec![catalogue]));
+    ///
+    /// translator.set_fallback_strategy(ChainFallbackStrategy::new().with_chain(
+    ///     Locale::Arabic(ArabicVariant::Tunisia),
+    ///     [
+    ///         Locale::Arabic(ArabicVariant::Morocco),
+    ///         Locale::Arabic(ArabicVariant::Default),
+    ///         Locale::French(FrenchVariant::Default),
+    ///     ],
+    /// ));
+    ///
+    /// let message = translator.trans("ar_TN", "messages", "greeting", context!(name = "Saif"));
+    /// assert_eq!(message.unwrap(), "Bonjour, Saif!");
+    /// ```
+    pub fn set_fallback_strategy<T>(&mut self, fallback_strategy: T)
+    where
+        T: FallbackStrategy + 'static,
+    {
+        self.fallback_strategy = Arc::new(fallback_strategy);
+    }
+
+    /// Returns the locales looked up, in order, when translating a message into `locale`.
+    ///
+    /// The chain is built from the chain of `locale` given by the fallback strategy, followed by the
+    /// chain of the fallback locale, if set, without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::FrenchVariant;
+    /// use tarjama::Translator;
+    ///
+    /// let mut translator = Translator::default();
+    /// translator.set_fallback_locale(Locale::French(FrenchVariant::Canada));
+    ///
+    /// assert_eq!(
+    ///     translator.fallback_chain(&Locale::English(EnglishVariant::UnitedKingdom)),
+    ///     vec![
+    ///         Locale::English(EnglishVariant::UnitedKingdom),
+    ///         Locale::English(EnglishVariant::World),
+    ///         Locale::English(EnglishVariant::Default),
+    ///         Locale::French(FrenchVariant::Canada),
+    ///         Locale::French(FrenchVariant::Default),
+    ///     ]
+    /// );
+    /// ```
+    pub fn fallback_chain(&self, locale: &Locale) -> Vec<Locale> {
+        self.preferred_fallback_chain(std::slice::from_ref(locale))
+    }
+
+    /// Returns the locales looked up, in order, when translating a message into the first
+    /// available of the given preferred locales.
+    ///
+    /// The chain is built from the chains of each of the preferred locales, in order, followed by the chain
+    /// of the fallback locale, if set, without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::FrenchVariant;
+    /// use tarjama::Translator;
+    ///
+    /// let mut translator = Translator::default();
+    /// translator.set_fallback_locale(Locale::English(EnglishVariant::Default));
+    ///
+    /// assert_eq!(
+    ///     translator.preferred_fallback_chain(&[
+    ///         Locale::French(FrenchVariant::Canada),
+    ///         Locale::Arabic(ArabicVariant::Tunisia),
+    ///     ]),
+    ///     vec![
+    ///         Locale::French(FrenchVariant::Canada),
+    ///         Locale::French(FrenchVariant::Default),
+    ///         Locale::Arabic(ArabicVariant::Tunisia),
+    ///         Locale::Arabic(ArabicVariant::Default),
+    ///         Locale::English(EnglishVariant::Default),
+    ///     ]
+    /// );
+    /// ```
+    pub fn preferred_fallback_chain(&self, locales: &[Locale]) -> Vec<Locale> {
+        let mut chain = vec![];
+        for locale in locales.iter().chain(&self.fallback_locale) {
+            for locale in self.fallback_strategy.chain(locale) {
+                if !chain.contains(&locale) {
+                    chain.push(locale);
+                }
+            }
+        }
+
+        chain
+    }
+
+    /// Returns the best available locale for the given requested locales, and their quality weights, or
+    /// the fallback locale if none of the requested locales is available.
+    ///
+    /// See [`crate::negotiation::negotiate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::FrenchVariant;
+    /// use tarjama::locale::GermanVariant;
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    /// use tarjama::Translator;
+    ///
+    /// let mut translator = Translator::with_catalogue_bag(CatalogueBag::with_catalogues(vec![
+    ///     Catalogue::new(Locale::English(EnglishVariant::Default)),
+    ///     Catalogue::new(Locale::French(FrenchVariant::Default)),
+    /// ]));
+    ///
+    /// assert_eq!(
+    ///     translator.negotiate([(Locale::French(FrenchVariant::Canada), 1.0)]),
+    ///     Some(Locale::French(FrenchVariant::Default))
+    /// );
+    ///
+    /// assert_eq!(translator.negotiate([(Locale::German(GermanVariant::Default), 1.0)]), None);
+    ///
+    /// translator.set_fallback_locale(Locale::English(EnglishVariant::Default));
+    ///
+    /// assert_eq!(
+    ///     translator.negotiate([(Locale::German(GermanVariant::Default), 1.0)]),
+    ///     Some(Locale::English(EnglishVariant::Default))
+    /// );
+    /// ```
+    pub fn negotiate<I>(&self, requested: I) -> Option<Locale>
+    where
+        I: IntoIterator<Item = (Locale, f32)>,
+    {
+        self.bag.negotiate(requested).or(self.fallback_locale)
+    }
+
     /// Translate a message.
     ///
-    /// When the `count` field of `Context` is `Some(i)`, the message is parsed for plural forms, and
-    /// a translation is chosen according to `i`.
+    /// When the `count` field of `Context` is `Some(count)`, the message is parsed for plural forms, and
+    /// a translation is chosen according to `count`, which can be an integer or a decimal number.
     ///
-    /// If the request message is not found in the given locale, the translator will first fallback to the
-    /// default variant of the locale if it exists, and is different from the given locale, and then to the
-    /// fallback locale if it is set.
+    /// If the request message is not found in the given locale, the translator will fallback to the locales
+    /// of the fallback chain, see [`Translator::fallback_chain`], by default, the CLDR parent locales of the
+    /// locale ( e.g. `es_MX → es_419 → es` ), and then to the fallback locale if it is set.
     ///
     /// # Examples
     ///
@@ -172,47 +356,136 @@ impl Translator {
         let locale = locale
             .try_into()
             .map_err(|_| Error::InvalidLocale(locale_string))?;
-        let catalogues = self.bag.get(&locale);
-        let mut message = None;
-        for catalogue in catalogues.iter() {
-            if let Some(msg) = catalogue.get(domain, id) {
-                message = Some(msg);
 
-                break;
+        self.trans_chain(&[locale], domain, id, &context)
+            .map(|(message, _)| message)
+    }
+
+    /// Translate a message into the first of the given preferred locales, in order, in which the
+    /// message is available, e.g. the locales of an `Accept-Language` header.
+    ///
+    /// Returns the translated message, along with the locale that produced it.
+    ///
+    /// Each preferred locale is looked up along with its fallback chain ( e.g. `fr_CA → fr` ) before moving to
+    /// the next one, the fallback locale is only used if the message is not available in any of the preferred
+    /// locales, see [`Translator::preferred_fallback_chain`].
+    ///
+    /// Invalid locales are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::FrenchVariant;
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::catalogue::CatalogueBag;
+    /// use tarjama::Translator;
+    /// use tarjama::context;
+    ///
+    /// let mut english = Catalogue::new(Locale::English(EnglishVariant::Default));
+    /// english.insert("messages", "greeting", "Hello, {name}!");
+    /// english.insert("messages", "farewell", "Goodbye, {name}!");
+    ///
+    /// let mut french = Catalogue::new(Locale::French(FrenchVariant::Default));
+    /// french.insert("messages", "greeting", "Bonjour, {name}!");
+    ///
+    /// let mut translator = Translator::with_catalogue_bag(CatalogueBag::with_catalogues(vec![english, french]));
+    /// translator.set_fallback_locale(Locale::English(EnglishVariant::Default));
+    ///
+    /// let (message, locale) = translator
+    ///     .trans_preferred(["xx", "ar", "fr_CA"], "messages", "greeting", context!(name = "Saif"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(message, "Bonjour, Saif!");
+    /// assert_eq!(locale, Locale::French(FrenchVariant::Default));
+    ///
+    /// let (message, locale) = translator
+    ///     .trans_preferred(["ar", "fr_CA"], "messages", "farewell", context!(name = "Saif"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(message, "Goodbye, Saif!");
+    /// assert_eq!(locale, Locale::English(EnglishVariant::Default));
+    /// ```
+    pub fn trans_preferred<I, T, C>(
+        &self,
+        locales: I,
+        domain: &str,
+        id: &str,
+        context: C,
+    ) -> Result<(String, Locale), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: TryInto<Locale> + Display,
+        C: Into<Context>,
+    {
+        let context = context.into();
+        let mut invalid = vec![];
+        let mut preferred = vec![];
+        for locale in locales {
+            let locale_string = locale.to_string();
+            match locale.try_into() {
+                Ok(locale) => preferred.push(locale),
+                Err(_) => invalid.push(locale_string),
             }
         }
 
-        if let Some(message) = message {
-            return self.formatter.format(&locale, message, &context);
+        if preferred.is_empty() && self.fallback_locale.is_none() {
+            return Err(Error::InvalidLocale(invalid.join(", ")));
         }
 
-        if locale.has_variant() {
-            return self.trans(
-                locale.with_default_variant(),
-                domain,
-                id,
-                context,
-            );
-        }
+        self.trans_chain(&preferred, domain, id, &context)
+    }
+
+    fn trans_chain(
+        &self,
+        locales: &[Locale],
+        domain: &str,
+        id: &str,
+        context: &Context,
+    ) -> Result<(String, Locale), Error> {
+        for candidate in self.preferred_fallback_chain(locales) {
+            if let Some(message) = self.bag.get_message(&candidate, domain, id)
+            {
+                // pass the unicode extension keywords of the requested locale, e.g. `ar_u_nu_latn`,
+                // on to the formatter.
+                let locale = locales
+                    .iter()
+                    .find(|locale| {
+                        locale.with_default_variant()
+                            == candidate.with_default_variant()
+                    })
+                    .map_or(candidate, |locale| {
+                        locale.extensions().fold(
+                            candidate,
+                            |l, (key, value)| {
+                                l.with_extension(key, value).unwrap_or(l)
+                            },
+                        )
+                    });
 
-        // if locale is different that self.fallback_locale:
-        if let Some(fallback) = &self.fallback_locale {
-            if fallback != &locale {
-                return self.trans(fallback, domain, id, context);
+                return self
+                    .formatter
+                    .format_message(&locale, message, context)
+                    .map(|message| (message, candidate));
             }
         }
 
-        Err(Error::MessageNotFound(locale, domain.to_string(), id.to_string()))
+        // report the first preferred locale, or the fallback locale if there is none.
+        match locales.first().or(self.fallback_locale.as_ref()) {
+            Some(locale) => Err(Error::MessageNotFound(
+                *locale,
+                domain.to_string(),
+                id.to_string(),
+            )),
+            None => Err(Error::InvalidLocale(String::new())),
+        }
     }
 }
 
 impl Default for Translator {
     fn default() -> Self {
-        Self {
-            formatter: Default::default(),
-            bag: Default::default(),
-            fallback_locale: None,
-        }
+        Self::with_catalogue_bag(CatalogueBag::new())
     }
 }
 
@@ -498,4 +771,450 @@ mod test {
             "locale: invalid locale, expected a valid locale code but found `'foo'`."
         );
     }
+
+    #[test]
+    fn catalogue_precedence() {
+        let bag = CatalogueBag::with_catalogues(vec![
+            Catalogue::with_messages(
+                Locale::English(EnglishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Hello, {name}!".to_owned(),
+                    )]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::French(FrenchVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Bonjour, {name}!".to_owned(),
+                    )]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::English(EnglishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([
+                        ("greeting".to_owned(), "Hi, {name}!".to_owned()),
+                        ("love".to_owned(), "I love rust!".to_owned()),
+                    ]),
+                )]),
+            ),
+        ]);
+
+        let translator = Translator::with_catalogue_bag(bag);
+
+        assert_ok!(
+            translator.trans(
+                "en",
+                "messages",
+                "greeting",
+                context!(name = "Saif")
+            ),
+            "Hello, Saif!"
+        );
+        assert_ok!(
+            translator.trans("en_US", "messages", "love", context!()),
+            "I love rust!"
+        );
+    }
+
+    #[test]
+    fn variant_fallback_locale() {
+        let bag =
+            CatalogueBag::with_catalogues(vec![Catalogue::with_messages(
+                Locale::French(FrenchVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Bonjour, {name}!".to_owned(),
+                    )]),
+                )]),
+            )]);
+
+        let mut translator = Translator::with_catalogue_bag(bag);
+        translator.set_fallback_locale(Locale::French(FrenchVariant::Canada));
+
+        assert_ok!(
+            translator.trans(
+                "en",
+                "messages",
+                "greeting",
+                context!(name = "Saif")
+            ),
+            "Bonjour, Saif!"
+        );
+        assert_err!(
+            translator.trans("en", "messages", "foo", context!()),
+            "message not found: message `'foo'` could not be found in `'messages'` domain for `'en'` locale."
+        );
+    }
+
+    #[test]
+    fn shared_translator() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Translator>();
+
+        let translator = Translator::with_catalogue_bag(
+            CatalogueBag::with_catalogues(vec![Catalogue::with_messages(
+                Locale::English(EnglishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Hello, {name}!".to_owned(),
+                    )]),
+                )]),
+            )]),
+        );
+
+        let mut clone = translator.clone();
+        clone.set_fallback_locale(Locale::English(EnglishVariant::Default));
+
+        assert!(std::ptr::eq(
+            translator.catalogue_bag(),
+            clone.catalogue_bag()
+        ));
+
+        let handle = std::thread::spawn(move || {
+            clone.trans("fr", "messages", "greeting", context!(name = "Saif"))
+        });
+
+        let message = handle.join().unwrap();
+
+        assert_ok!(message, "Hello, Saif!");
+        assert!(translator
+            .trans("fr", "messages", "greeting", context!(name = "Saif"))
+            .is_err());
+    }
+
+    #[test]
+    fn fallback_strategy() {
+        use crate::fallback::ChainFallbackStrategy;
+        use crate::locale::PortugueseVariant;
+        use crate::locale::SpanishVariant;
+
+        let bag = CatalogueBag::with_catalogues(vec![
+            Catalogue::with_messages(
+                Locale::English(EnglishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([
+                        ("greeting".to_owned(), "Hello, {name}!".to_owned()),
+                        ("love".to_owned(), "I love rust!".to_owned()),
+                        ("bye".to_owned(), "Bye!".to_owned()),
+                    ]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::Spanish(SpanishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([
+                        ("greeting".to_owned(), "¡Hola, {name}!".to_owned()),
+                        ("love".to_owned(), "¡Me encanta rust!".to_owned()),
+                    ]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::Portuguese(PortugueseVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Olá, {name}!".to_owned(),
+                    )]),
+                )]),
+            ),
+        ]);
+
+        let mut translator = Translator::with_catalogue_bag(bag);
+        translator.set_fallback_locale(Locale::French(FrenchVariant::Default));
+        translator.set_fallback_strategy(
+            ChainFallbackStrategy::new().with_chain(
+                Locale::Portuguese(PortugueseVariant::Brazil),
+                [
+                    Locale::Portuguese(PortugueseVariant::Default),
+                    Locale::Spanish(SpanishVariant::Default),
+                    Locale::English(EnglishVariant::Default),
+                ],
+            ),
+        );
+
+        assert_eq!(
+            translator.fallback_chain(&Locale::Portuguese(
+                PortugueseVariant::Brazil
+            )),
+            vec![
+                Locale::Portuguese(PortugueseVariant::Brazil),
+                Locale::Portuguese(PortugueseVariant::Default),
+                Locale::Spanish(SpanishVariant::Default),
+                Locale::English(EnglishVariant::Default),
+                Locale::French(FrenchVariant::Default),
+            ]
+        );
+
+        assert_ok!(
+            translator.trans(
+                "pt_BR",
+                "messages",
+                "greeting",
+                context!(name = "Saif")
+            ),
+            "Olá, Saif!"
+        );
+        assert_ok!(
+            translator.trans("pt_BR", "messages", "love", None),
+            "¡Me encanta rust!"
+        );
+        assert_ok!(translator.trans("pt_BR", "messages", "bye", None), "Bye!");
+        assert_err!(
+            translator.trans("pt", "messages", "bye", None),
+            "message not found: message `'bye'` could not be found in `'messages'` domain for `'pt'` locale."
+        );
+    }
+
+    #[test]
+    fn parent_locale_fallback() {
+        use crate::locale::SpanishVariant;
+
+        let bag = CatalogueBag::with_catalogues(vec![
+            Catalogue::with_messages(
+                Locale::Spanish(SpanishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([
+                        ("car".to_owned(), "coche".to_owned()),
+                        ("computer".to_owned(), "ordenador".to_owned()),
+                    ]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::Spanish(SpanishVariant::LatinAmerica),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "computer".to_owned(),
+                        "computadora".to_owned(),
+                    )]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::Spanish(SpanishVariant::Argentina),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([("car".to_owned(), "auto".to_owned())]),
+                )]),
+            ),
+        ]);
+
+        let translator = Translator::with_catalogue_bag(bag);
+
+        assert_ok!(translator.trans("es_AR", "messages", "car", None), "auto");
+        assert_ok!(
+            translator.trans("es_AR", "messages", "computer", None),
+            "computadora"
+        );
+        assert_ok!(
+            translator.trans("es_MX", "messages", "car", None),
+            "coche"
+        );
+        assert_ok!(
+            translator.trans("es-MX", "messages", "computer", None),
+            "computadora"
+        );
+        assert_ok!(
+            translator.trans("es", "messages", "computer", None),
+            "ordenador"
+        );
+    }
+
+    #[test]
+    fn preferred_translation() {
+        let bag = CatalogueBag::with_catalogues(vec![
+            Catalogue::with_messages(
+                Locale::English(EnglishVariant::Default),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([
+                        ("greeting".to_owned(), "Hello, {name}!".to_owned()),
+                        ("love".to_owned(), "I love rust!".to_owned()),
+                    ]),
+                )]),
+            ),
+            Catalogue::with_messages(
+                Locale::French(FrenchVariant::Canada),
+                HashMap::from([(
+                    "messages".to_owned(),
+                    HashMap::from([(
+                        "greeting".to_owned(),
+                        "Bonjour, {name}!".to_owned(),
+                    )]),
+                )]),
+            ),
+        ]);
+
+        let mut translator = Translator::with_catalogue_bag(bag);
+
+        assert_eq!(
+            translator
+                .trans_preferred(
+                    ["de", "fr_CA", "en"],
+                    "messages",
+                    "greeting",
+                    context!(name = "Saif")
+                )
+                .unwrap(),
+            (
+                "Bonjour, Saif!".to_string(),
+                Locale::French(FrenchVariant::Canada)
+            )
+        );
+        assert_eq!(
+            translator
+                .trans_preferred(["fr_CA", "en_GB"], "messages", "love", None)
+                .unwrap(),
+            (
+                "I love rust!".to_string(),
+                Locale::English(EnglishVariant::Default)
+            )
+        );
+        assert_err!(
+            translator.trans_preferred(["fr", "de"], "messages", "greeting", None),
+            "message not found: message `'greeting'` could not be found in `'messages'` domain for `'fr'` locale."
+        );
+        assert_err!(
+            translator.trans_preferred(["foo", "bar"], "messages", "greeting", None),
+            "locale: invalid locale, expected a valid locale code but found `'foo, bar'`."
+        );
+
+        translator
+            .set_fallback_locale(Locale::English(EnglishVariant::Default));
+
+        assert_eq!(
+            translator
+                .trans_preferred(
+                    ["foo", "bar"],
+                    "messages",
+                    "greeting",
+                    context!(name = "Saif")
+                )
+                .unwrap(),
+            (
+                "Hello, Saif!".to_string(),
+                Locale::English(EnglishVariant::Default)
+            )
+        );
+    }
+
+    #[test]
+    fn language_tag_locales() {
+        use crate::locale::ChineseVariant;
+
+        let mut japanese = Catalogue::new(Locale::try_from("ja-JP").unwrap());
+        japanese.insert("messages", "hello", "こんにちは");
+
+        let mut traditional =
+            Catalogue::new(Locale::try_from("zh_Hant").unwrap());
+        traditional.insert("messages", "language", "繁體中文");
+
+        let mut chinese =
+            Catalogue::new(Locale::Chinese(ChineseVariant::Default));
+        chinese.insert("messages", "language", "简体中文");
+        chinese.insert("messages", "hello", "你好");
+
+        let translator =
+            Translator::with_catalogue_bag(CatalogueBag::with_catalogues(
+                vec![japanese, traditional, chinese],
+            ));
+
+        assert_ok!(
+            translator.trans("ja_jp", "messages", "hello", None),
+            "こんにちは"
+        );
+        assert_ok!(
+            translator.trans("zh-Hant-TW", "messages", "language", None),
+            "繁體中文"
+        );
+        // traditional chinese does not fall back to simplified chinese.
+        assert_err!(
+            translator.trans("zh-Hant-TW", "messages", "hello", None),
+            "message not found: message `'hello'` could not be found in `'messages'` domain for `'zh_Hant_TW'` locale."
+        );
+        assert_ok!(
+            translator.trans("zh-CN", "messages", "language", None),
+            "简体中文"
+        );
+        assert_err!(
+            translator.trans("ja", "messages", "hello", None),
+            "message not found: message `'hello'` could not be found in `'messages'` domain for `'ja'` locale."
+        );
+    }
+
+    #[test]
+    fn locale_extensions() {
+        use crate::context::Context;
+        use crate::error::Error;
+        use crate::formatter::Formatter;
+        use crate::locale::ArabicVariant;
+
+        struct NumberingFormatter;
+
+        impl Formatter for NumberingFormatter {
+            fn format(
+                &self,
+                locale: &Locale,
+                message: &str,
+                _context: &Context,
+            ) -> Result<String, Error> {
+                Ok(format!(
+                    "{message} ({locale}, {})",
+                    locale.extension("nu").unwrap_or("default")
+                ))
+            }
+
+            fn box_clone(&self) -> Box<dyn Formatter> {
+                Box::new(NumberingFormatter)
+            }
+        }
+
+        let mut catalogue =
+            Catalogue::new(Locale::Arabic(ArabicVariant::Default));
+        catalogue.insert("messages", "hello", "مرحبا");
+
+        let translator = Translator::new(
+            Box::new(NumberingFormatter),
+            CatalogueBag::with_catalogues(vec![catalogue]),
+            None,
+        );
+
+        assert_ok!(
+            translator.trans("ar-EG-u-nu-latn", "messages", "hello", None),
+            "مرحبا (ar_u_nu_latn, latn)"
+        );
+        assert_ok!(
+            translator.trans("ar_EG", "messages", "hello", None),
+            "مرحبا (ar, default)"
+        );
+
+        let result = translator.trans_preferred(
+            ["ar-u-nu-arab"],
+            "messages",
+            "hello",
+            None,
+        );
+        assert_eq!(
+            result.unwrap(),
+            (
+                "مرحبا (ar_u_nu_arab, arab)".to_string(),
+                Locale::Arabic(ArabicVariant::Default)
+            )
+        );
+    }
 }
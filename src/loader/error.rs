@@ -9,15 +9,24 @@ pub enum Error {
     #[cfg(feature = "file")]
     InvalidFilenameFormat(String),
 
-    #[cfg(feature = "toml")]
-    FailedToParseFile(::toml::de::Error),
+    #[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
+    FailedToParseFile(Box<dyn ::std::error::Error + Send + Sync>),
+
+    #[cfg(feature = "po")]
+    FailedToParseGettextFile(String, String),
+
+    #[cfg(feature = "fluent")]
+    FailedToParseFluentFile(String, String),
+
+    #[cfg(feature = "xliff")]
+    FailedToParseXliffFile(String, String),
+
+    #[cfg(feature = "watch")]
+    FailedToWatchDirectory(::notify::Error),
 
     Custom(String),
 }
 
-unsafe impl Sync for Error {}
-unsafe impl Send for Error {}
-
 impl ::std::fmt::Display for Error {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
@@ -33,10 +42,28 @@ impl ::std::fmt::Display for Error {
                 "unreadable node: file `{node}`, {}.",
                 inner.to_string().to_lowercase(),
             ),
-            #[cfg(feature = "file")]
+            #[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
             Error::FailedToParseFile(inner) => write!(f, "{inner}."),
-            #[cfg(feature = "toml")]
+            #[cfg(feature = "file")]
             Error::InvalidFilenameFormat(inner) => write!(f, "{inner}"),
+            #[cfg(feature = "po")]
+            Error::FailedToParseGettextFile(node, reason) => {
+                write!(f, "unparsable node: file `{node}`, {reason}.")
+            }
+            #[cfg(feature = "fluent")]
+            Error::FailedToParseFluentFile(node, reason) => {
+                write!(f, "unparsable node: file `{node}`, {reason}.")
+            }
+            #[cfg(feature = "xliff")]
+            Error::FailedToParseXliffFile(node, reason) => {
+                write!(f, "unparsable node: file `{node}`, {reason}.")
+            }
+            #[cfg(feature = "watch")]
+            Error::FailedToWatchDirectory(inner) => write!(
+                f,
+                "unwatchable node: directory, {}.",
+                inner.to_string().to_lowercase()
+            ),
             Error::Custom(inner) => write!(f, "{inner}"),
         }
     }
@@ -4,6 +4,97 @@ use crate::locale::Locale;
 
 use std::{collections::HashMap, path::Path};
 
+/// The layout of translation files within a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Layout {
+    /// `{domain}.{locale}.{ext}` files, e.g. `messages.en.toml`.
+    #[default]
+    Flat,
+    /// `{locale}/{domain}.{ext}` files, e.g. `en/messages.toml`.
+    LocaleDirectory,
+    /// `{locale}.{ext}` files, e.g. `en.toml`, containing the messages of all domains.
+    ///
+    /// Loaders supporting this layout expect a top-level table per domain.
+    LocaleFile,
+}
+
+/// The configuration of a file loader.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::file::Layout;
+///
+/// let configuration = Configuration::new()
+///     .with_layout(Layout::LocaleDirectory)
+///     .with_recursion(true);
+///
+/// assert_eq!(configuration.layout(), Layout::LocaleDirectory);
+/// assert!(configuration.is_recursive());
+///
+/// let configuration = Configuration::default();
+///
+/// assert_eq!(configuration.layout(), Layout::Flat);
+/// assert!(!configuration.is_recursive());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Configuration {
+    layout: Layout,
+    recursive: bool,
+}
+
+impl Configuration {
+    /// Creates a configuration using the flat layout, without recursion.
+    pub fn new() -> Self {
+        Configuration::default()
+    }
+
+    /// Returns the configuration, using the given layout.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+
+        self
+    }
+
+    /// Returns the configuration, looking for files within subdirectories if `recursive` is
+    /// `true`, e.g. `{domain}.{locale}.{ext}` files are also found in `admin/` when using the flat
+    /// layout, and `{locale}/{domain}.{ext}` files in `en/admin/` when using the locale
+    /// directory layout.
+    ///
+    /// The domain of a file found within a subdirectory is prefixed with the path of that
+    /// subdirectory, joined using `.`, e.g. the domain of both `admin/users.en.toml` and
+    /// `en/admin/users.toml` is `admin.users`.
+    pub fn with_recursion(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+
+        self
+    }
+
+    /// Returns the layout of translation files.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Returns `true` if subdirectories are searched for translation files.
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Returns `true` if any subdirectory of the loaded directory may be searched.
+    #[cfg(feature = "watch")]
+    pub(crate) fn has_subdirectories(&self) -> bool {
+        self.recursive || self.layout == Layout::LocaleDirectory
+    }
+
+    /// Returns `true` if the given directory, relative to the loaded directory, should be searched.
+    fn descends(&self, directory: &Path) -> bool {
+        self.recursive
+            || (self.layout == Layout::LocaleDirectory
+                && directory.components().count() == 1)
+    }
+}
+
 /// A helper function for loading catalogues from directory.
 ///
 /// You won't need this function unless you are creating your own file loader.
@@ -64,64 +155,92 @@ pub async fn iterate<T>(
 where
     T: AsRef<Path> + 'static,
 {
-    use tokio::fs;
+    iterate_with(path, extensions, Configuration::default()).await
+}
 
-    let path: &Path = path.as_ref();
-    let mut entries = fs::read_dir(path).await.map_err(|e| {
-        Error::LoadingError(LoadingError::FailedToReadDirectory(e))
-    })?;
+/// Iterate over the translation files of a directory, using the given configuration.
+///
+/// With the [`Layout::LocaleFile`] layout, the domains are found within the files, so files are
+/// listed under an empty domain, or under the path of their subdirectory, e.g. `admin` for
+/// `admin/en.toml`, see [`Configuration::with_recursion`].
+///
+/// The files of each domain are sorted by path, loaders insert their messages in that order, so
+/// that when a message is defined by several files of a domain, e.g. `messages.fr.mo` and
+/// `messages.fr.po`, the last file wins.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::loader::file;
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::file::Layout;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// # async fn doc() {
+/// let configuration = Configuration::new().with_layout(Layout::LocaleDirectory);
+/// let result = file::iterate_with("examples/layouts/directory", &["toml".to_string()], configuration)
+///     .await
+///     .unwrap();
+///
+/// assert_eq!(
+///     result[&Locale::English(EnglishVariant::Default)]["messages"],
+///     vec!["examples/layouts/directory/en/messages.toml".to_string()]
+/// );
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn iterate_with<T>(
+    path: T,
+    extensions: &[String],
+    configuration: Configuration,
+) -> Result<HashMap<Locale, HashMap<String, Vec<String>>>, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use tokio::fs;
 
+    let root: &Path = path.as_ref();
     let mut result: HashMap<Locale, HashMap<String, Vec<String>>> =
         HashMap::new();
 
-    while let Some(entry) = entries.next_entry().await.map_err(|e| {
-        Error::LoadingError(LoadingError::FailedToReadDirectory(e))
-    })? {
-        let entry_path_buf = entry.path();
-        if !entry_path_buf.is_file() {
-            continue;
-        }
-        if let Some(ext) = entry_path_buf.extension().and_then(|x| x.to_str())
-        {
-            if !extensions.contains(&ext.to_string()) {
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let mut entries = fs::read_dir(&directory).await.map_err(|e| {
+            Error::LoadingError(LoadingError::FailedToReadDirectory(e))
+        })?;
+
+        while let Some(entry) = entries.next_entry().await.map_err(|e| {
+            Error::LoadingError(LoadingError::FailedToReadDirectory(e))
+        })? {
+            let entry_path_buf = entry.path();
+            if entry_path_buf.is_dir() {
+                if configuration.descends(relative(root, &entry_path_buf)) {
+                    directories.push(entry_path_buf);
+                }
+
                 continue;
             }
 
-            if let Some(stem) = entry_path_buf.file_stem().unwrap().to_str() {
-                if let Some(pos) = stem.rfind('.') {
-                    let domain = stem[..pos].to_string();
-                    let locale_name = &stem[pos + 1..];
-                    let locale: Locale = locale_name.try_into().map_err(|_| {
-                        Error::LoadingError(LoadingError::InvalidFilenameFormat(format!(
-                            "invalid filename: locale, expected valid locale code, found `{locale_name}` in `{}.{}`.",
-                            stem,
-                            ext
-                        )))
-                    })?;
-
-                    let map = if !result.contains_key(&locale) {
-                        result.insert(locale.clone(), HashMap::new());
-
-                        result.get_mut(&locale).unwrap()
-                    } else {
-                        result.get_mut(&locale).unwrap()
-                    };
-                    if !map.contains_key(&domain) {
-                        map.insert(domain.clone(), vec![]);
-                    }
-
-                    map.get_mut(&domain)
-                        .unwrap()
-                        .push(entry_path_buf.to_str().unwrap().to_string());
-                } else {
-                    return Err(Error::LoadingError(
-                        LoadingError::InvalidFilenameFormat(format!("invalid filename: format, expected `{{domain}}.{{locale}}.{{ext}}` for `{}.{}`.", stem, ext))
-                    ));
-                }
+            if !entry_path_buf.is_file() {
+                continue;
+            }
+
+            if let Some((locale, domain)) =
+                classify(root, &entry_path_buf, extensions, configuration)?
+            {
+                result
+                    .entry(locale)
+                    .or_default()
+                    .entry(domain)
+                    .or_default()
+                    .push(entry_path_buf.to_str().unwrap().to_string());
             }
         }
     }
 
+    sort(&mut result);
+
     Ok(result)
 }
 
@@ -132,73 +251,177 @@ pub fn iterate_sync<T>(
 where
     T: AsRef<Path>,
 {
-    use std::fs;
+    iterate_with_sync(path, extensions, Configuration::default())
+}
 
-    let path: &Path = path.as_ref();
-    // Use std::fs to read the directory synchronously
-    let entries = fs::read_dir(path).map_err(|e| {
-        Error::LoadingError(LoadingError::FailedToReadDirectory(e))
-    })?;
+/// Synchronously iterate over the translation files of a directory, using the given
+/// configuration.
+///
+/// See [`iterate_with`].
+pub fn iterate_with_sync<T>(
+    path: T,
+    extensions: &[String],
+    configuration: Configuration,
+) -> Result<HashMap<Locale, HashMap<String, Vec<String>>>, Error>
+where
+    T: AsRef<Path>,
+{
+    use std::fs;
 
+    let root: &Path = path.as_ref();
     let mut result: HashMap<Locale, HashMap<String, Vec<String>>> =
         HashMap::new();
 
-    for entry in entries {
-        let entry = entry.map_err(|e| {
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let entries = fs::read_dir(&directory).map_err(|e| {
             Error::LoadingError(LoadingError::FailedToReadDirectory(e))
         })?;
 
-        let entry_path_buf = entry.path();
-        if !entry_path_buf.is_file() {
-            continue;
-        }
-        if let Some(ext) = entry_path_buf.extension().and_then(|x| x.to_str())
-        {
-            if !extensions.contains(&ext.to_string()) {
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                Error::LoadingError(LoadingError::FailedToReadDirectory(e))
+            })?;
+
+            let entry_path_buf = entry.path();
+            if entry_path_buf.is_dir() {
+                if configuration.descends(relative(root, &entry_path_buf)) {
+                    directories.push(entry_path_buf);
+                }
+
                 continue;
             }
 
-            if let Some(stem) = entry_path_buf.file_stem().unwrap().to_str() {
-                if let Some(pos) = stem.rfind('.') {
-                    let domain = stem[..pos].to_string();
-                    let locale_name = &stem[pos + 1..];
-                    let locale: Locale = locale_name.try_into().map_err(|_| {
-                        Error::LoadingError(LoadingError::InvalidFilenameFormat(format!(
-                            "invalid filename: locale, expected valid locale code, found `{locale_name}` in `{}.{}`.",
-                            stem,
-                            ext
-                        )))
-                    })?;
-
-                    let map = if !result.contains_key(&locale) {
-                        result.insert(locale.clone(), HashMap::new());
-
-                        result.get_mut(&locale).unwrap()
-                    } else {
-                        result.get_mut(&locale).unwrap()
-                    };
-                    if !map.contains_key(&domain) {
-                        map.insert(domain.clone(), vec![]);
-                    }
-
-                    map.get_mut(&domain)
-                        .unwrap()
-                        .push(entry_path_buf.to_str().unwrap().to_string());
-                } else {
-                    return Err(Error::LoadingError(
-                        LoadingError::InvalidFilenameFormat(format!("invalid filename: format, expected `{{domain}}.{{locale}}.{{ext}}` but got `{}.{}`.", stem, ext))
-                    ));
-                }
+            if !entry_path_buf.is_file() {
+                continue;
+            }
+
+            if let Some((locale, domain)) =
+                classify(root, &entry_path_buf, extensions, configuration)?
+            {
+                result
+                    .entry(locale)
+                    .or_default()
+                    .entry(domain)
+                    .or_default()
+                    .push(entry_path_buf.to_str().unwrap().to_string());
             }
         }
     }
 
+    sort(&mut result);
+
     Ok(result)
 }
 
+/// Sort the files of each domain, so they are loaded in a deterministic order.
+fn sort(result: &mut HashMap<Locale, HashMap<String, Vec<String>>>) {
+    result
+        .values_mut()
+        .flat_map(|domains| domains.values_mut())
+        .for_each(|paths| paths.sort());
+}
+
+fn relative<'a>(root: &Path, path: &'a Path) -> &'a Path {
+    path.strip_prefix(root).unwrap_or(path)
+}
+
+/// Returns the locale and domain of a translation file, or `None` if the file should be ignored.
+fn classify(
+    root: &Path,
+    path: &Path,
+    extensions: &[String],
+    configuration: Configuration,
+) -> Result<Option<(Locale, String)>, Error> {
+    let Some(ext) = path.extension().and_then(|x| x.to_str()) else {
+        return Ok(None);
+    };
+
+    if !extensions.contains(&ext.to_string()) {
+        return Ok(None);
+    }
+
+    let Some(stem) = path.file_stem().unwrap().to_str() else {
+        return Ok(None);
+    };
+
+    let directories =
+        relative(root, path).parent().unwrap_or_else(|| Path::new(""));
+
+    let locale = |locale_name: &str, node: &str| -> Result<Locale, Error> {
+        locale_name.try_into().map_err(|_| {
+            Error::LoadingError(LoadingError::InvalidFilenameFormat(format!(
+                "invalid filename: locale, expected valid locale code, found `{locale_name}` in `{node}`."
+            )))
+        })
+    };
+
+    match configuration.layout {
+        Layout::Flat => match stem.rfind('.') {
+            Some(pos) => Ok(Some((
+                locale(&stem[pos + 1..], &format!("{stem}.{ext}"))?,
+                domain(directories, &stem[..pos]),
+            ))),
+            None => Err(Error::LoadingError(
+                LoadingError::InvalidFilenameFormat(format!("invalid filename: format, expected `{{domain}}.{{locale}}.{{ext}}` for `{stem}.{ext}`."))
+            )),
+        },
+        Layout::LocaleDirectory => {
+            let mut components = directories.components();
+            match components.next() {
+                Some(directory) => {
+                    let directory = directory.as_os_str().to_string_lossy();
+                    let node = relative(root, path)
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+
+                    Ok(Some((
+                        locale(&directory, &node)?,
+                        domain(components.as_path(), stem),
+                    )))
+                }
+                _ => Err(Error::LoadingError(
+                    LoadingError::InvalidFilenameFormat(format!("invalid filename: format, expected `{{locale}}/{{domain}}.{{ext}}` for `{stem}.{ext}`."))
+                )),
+            }
+        }
+        Layout::LocaleFile => Ok(Some((
+            locale(stem, &format!("{stem}.{ext}"))?,
+            domain(directories, ""),
+        ))),
+    }
+}
+
+/// Returns the domain of a file, prefixed with the subdirectories it was found in, relative to the
+/// loaded directory, e.g. `admin.users` for `admin/users.en.toml`.
+fn domain(directories: &Path, domain: &str) -> String {
+    directories
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .chain(std::iter::once(domain.into()))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Returns the domain within a subdirectory, e.g. `admin.users` for the `users` domain of an
+/// `admin/en.toml` file, using the [`Layout::LocaleFile`] layout.
+#[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
+pub(crate) fn qualify(directory: &str, domain: String) -> String {
+    if directory.is_empty() {
+        domain
+    } else {
+        format!("{directory}.{domain}")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::loader::file;
+    use crate::loader::file::Configuration;
+    use crate::loader::file::Layout;
     use crate::locale::ArabicVariant;
     use crate::locale::ChineseVariant;
     use crate::locale::EnglishVariant;
@@ -333,4 +556,144 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn layouts() {
+        let extensions = ["toml".to_string()];
+        let english = Locale::English(EnglishVariant::Default);
+
+        let result = file::iterate_with_sync(
+            "examples/layouts/directory",
+            &extensions,
+            Configuration::new().with_layout(Layout::LocaleDirectory),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            HashMap::from([
+                (
+                    english,
+                    HashMap::from([(
+                        "messages".to_string(),
+                        vec!["examples/layouts/directory/en/messages.toml"
+                            .to_string()]
+                    )])
+                ),
+                (
+                    Locale::French(FrenchVariant::Default),
+                    HashMap::from([(
+                        "messages".to_string(),
+                        vec!["examples/layouts/directory/fr/messages.toml"
+                            .to_string()]
+                    )])
+                ),
+            ])
+        );
+
+        let result = file::iterate_with_sync(
+            "examples/layouts/directory",
+            &extensions,
+            Configuration::new()
+                .with_layout(Layout::LocaleDirectory)
+                .with_recursion(true),
+        )
+        .unwrap();
+        assert_eq!(
+            result[&english]["admin.users"],
+            vec!["examples/layouts/directory/en/admin/users.toml".to_string()]
+        );
+
+        let result = file::iterate_with_sync(
+            "examples/layouts/file",
+            &extensions,
+            Configuration::new().with_layout(Layout::LocaleFile),
+        )
+        .unwrap();
+        assert_eq!(
+            result[&english],
+            HashMap::from([(
+                String::new(),
+                vec!["examples/layouts/file/en.toml".to_string()]
+            )])
+        );
+
+        let result = file::iterate_with_sync(
+            "examples/layouts/recursive",
+            &extensions,
+            Configuration::new().with_recursion(true),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            HashMap::from([(
+                english,
+                HashMap::from([
+                    (
+                        "messages".to_string(),
+                        vec!["examples/layouts/recursive/messages.en.toml"
+                            .to_string()]
+                    ),
+                    (
+                        "admin.messages".to_string(),
+                        vec!["examples/layouts/recursive/admin/messages.en.toml"
+                            .to_string()]
+                    ),
+                    (
+                        "admin.users".to_string(),
+                        vec!["examples/layouts/recursive/admin/users.en.toml"
+                            .to_string()]
+                    ),
+                ])
+            )])
+        );
+
+        let extensions = ["po".to_string(), "mo".to_string()];
+        let result =
+            file::iterate_sync("examples/layouts/gettext", &extensions)
+                .unwrap();
+        assert_eq!(
+            result[&Locale::French(FrenchVariant::Default)]["messages"],
+            vec![
+                "examples/layouts/gettext/messages.fr.mo".to_string(),
+                "examples/layouts/gettext/messages.fr.po".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn layout_errors() {
+        let extensions = ["toml".to_string()];
+
+        let error = file::iterate_with_sync(
+            "examples/layouts/recursive",
+            &extensions,
+            Configuration::new().with_layout(Layout::LocaleDirectory),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid filename: format, expected `{locale}/{domain}.{ext}` for `messages.en.toml`."
+        );
+
+        let error = file::iterate_with_sync(
+            "examples/layouts/recursive",
+            &extensions,
+            Configuration::new().with_layout(Layout::LocaleFile),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid filename: locale, expected valid locale code, found `messages.en` in `messages.en.toml`."
+        );
+
+        let error = file::iterate_with_sync(
+            "examples/layouts/directory",
+            &extensions,
+            Configuration::new().with_recursion(true),
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with(
+            "invalid filename: format, expected `{domain}.{locale}.{ext}` for"
+        ));
+    }
 }
@@ -0,0 +1,455 @@
+use crate::catalogue::Catalogue;
+use crate::catalogue::CatalogueBag;
+use crate::error::Error;
+use crate::loader::error::Error as LoadingError;
+use crate::loader::file::Configuration;
+
+use fluent_syntax::ast;
+use fluent_syntax::parser;
+use fluent_syntax::serializer::serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Load a catalogue bag from a directory containing Fluent `.ftl` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.ftl`.
+///
+/// Each message is inserted using its identifier as id, and each attribute of a message using
+/// `{message}.{attribute}` as id, e.g. `login-input.placeholder`.
+///
+/// Messages are meant to be formatted using the
+/// [`FluentFormatter`](crate::formatter::fluent::FluentFormatter), the source of each message is a
+/// Fluent resource containing the message, followed by the messages and terms it references from
+/// any file of the same domain and locale, comments are removed.
+///
+/// See [`load_with`] to load files using a different layout.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::fluent::load;
+///
+/// let catalogue_bag = load("examples/fluent").await.expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    load_with(directory, Configuration::default()).await
+}
+
+/// Load a catalogue bag from a directory containing Fluent `.ftl` files, using the given
+/// configuration.
+///
+/// Fluent files do not define domains, so with the
+/// [`Layout::LocaleFile`](crate::loader::file::Layout::LocaleFile) layout, files should be named
+/// `{locale}.ftl`, and their messages are inserted into the domain of their subdirectory, i.e. the
+/// empty domain at the top-level.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::fluent::load_with;
+///
+/// let configuration = Configuration::new().with_recursion(true);
+/// let catalogue_bag = load_with("examples/fluent", configuration)
+///     .await
+///     .expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use crate::loader::file::iterate_with;
+    use futures_util::future::join_all;
+    use tokio::fs;
+
+    let data =
+        iterate_with(directory, &["ftl".to_string()], configuration).await?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            let files =
+                join_all(message_files.into_iter().map(|path| async {
+                    match fs::read_to_string(&path).await {
+                        Ok(content) => Ok((path, content)),
+                        Err(e) => Err(Error::LoadingError(
+                            LoadingError::FailedToReadFile(path, e),
+                        )),
+                    }
+                }))
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            for (id, message) in messages(files)? {
+                catalogue.insert(&domain, &id, &message);
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Synchronously load a catalogue bag from a directory containing Fluent `.ftl` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.ftl`.
+pub fn load_sync<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    load_sync_with(directory, Configuration::default())
+}
+
+/// Synchronously load a catalogue bag from a directory containing Fluent `.ftl` files, using the
+/// given configuration.
+///
+/// See [`load_with`].
+pub fn load_sync_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    use crate::loader::file::iterate_with_sync;
+    use std::fs;
+
+    let data =
+        iterate_with_sync(directory, &["ftl".to_string()], configuration)?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            let mut files = vec![];
+            for path in message_files {
+                let content = fs::read_to_string(&path).map_err(|e| {
+                    Error::LoadingError(LoadingError::FailedToReadFile(
+                        path.clone(),
+                        e,
+                    ))
+                })?;
+
+                files.push((path, content));
+            }
+
+            for (id, message) in messages(files)? {
+                catalogue.insert(&domain, &id, &message);
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Returns the messages of a domain, as `(id, message)` pairs, from its `(path, content)` files.
+fn messages(
+    files: Vec<(String, String)>,
+) -> Result<Vec<(String, String)>, Error> {
+    let mut ids = vec![];
+    let mut entries = HashMap::new();
+    for (path, content) in files {
+        let resource = parser::parse(content).map_err(|(_, errors)| {
+            let reasons: Vec<String> = errors
+                .iter()
+                .map(|error| error.kind.to_string().to_lowercase())
+                .collect();
+
+            Error::LoadingError(LoadingError::FailedToParseFluentFile(
+                path.clone(),
+                reasons.join(", "),
+            ))
+        })?;
+
+        for entry in resource.body {
+            match entry {
+                ast::Entry::Message(mut message) => {
+                    message.comment = None;
+                    if !entries.contains_key(&message.id.name) {
+                        ids.push(message.id.name.clone());
+                    }
+
+                    entries.insert(
+                        message.id.name.clone(),
+                        ast::Entry::Message(message),
+                    );
+                }
+                ast::Entry::Term(mut term) => {
+                    term.comment = None;
+                    entries.insert(
+                        format!("-{}", term.id.name),
+                        ast::Entry::Term(term),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut messages = vec![];
+    for id in ids {
+        let Some(ast::Entry::Message(message)) = entries.get(&id) else {
+            continue;
+        };
+
+        let dependencies = dependencies(&id, &entries);
+        if message.value.is_some() {
+            let body =
+                dependencies.iter().map(|&entry| entry.clone()).collect();
+
+            messages.push((id.clone(), serialize(&ast::Resource { body })));
+        }
+
+        for attribute in &message.attributes {
+            // a message can only be formatted through its value, so the attribute is referenced
+            // from a synthetic message placed first.
+            let reference = ast::Entry::Message(ast::Message {
+                id: ast::Identifier {
+                    name: format!("{id}--{}", attribute.id.name),
+                },
+                value: Some(ast::Pattern {
+                    elements: vec![ast::PatternElement::Placeable {
+                        expression: ast::Expression::Inline(
+                            ast::InlineExpression::MessageReference {
+                                id: message.id.clone(),
+                                attribute: Some(attribute.id.clone()),
+                            },
+                        ),
+                    }],
+                }),
+                attributes: vec![],
+                comment: None,
+            });
+
+            let body = std::iter::once(reference)
+                .chain(dependencies.iter().map(|&entry| entry.clone()))
+                .collect();
+
+            messages.push((
+                format!("{id}.{}", attribute.id.name),
+                serialize(&ast::Resource { body }),
+            ));
+        }
+    }
+
+    Ok(messages)
+}
+
+/// Returns the entry of `id`, followed by all the entries it references, directly or not.
+fn dependencies<'a>(
+    id: &str,
+    entries: &'a HashMap<String, ast::Entry<String>>,
+) -> Vec<&'a ast::Entry<String>> {
+    let mut ids = vec![id.to_string()];
+    let mut dependencies = vec![];
+    let mut index = 0;
+    while let Some(id) = ids.get(index) {
+        index += 1;
+
+        let Some(entry) = entries.get(id) else {
+            continue;
+        };
+
+        let (value, attributes) = match entry {
+            ast::Entry::Message(message) => {
+                (message.value.as_ref(), &message.attributes)
+            }
+            ast::Entry::Term(term) => (Some(&term.value), &term.attributes),
+            _ => continue,
+        };
+
+        let mut references = vec![];
+        value
+            .into_iter()
+            .chain(attributes.iter().map(|attribute| &attribute.value))
+            .for_each(|pattern| pattern_references(pattern, &mut references));
+
+        dependencies.push(entry);
+        for reference in references {
+            if !ids.contains(&reference) {
+                ids.push(reference);
+            }
+        }
+    }
+
+    dependencies
+}
+
+fn pattern_references(
+    pattern: &ast::Pattern<String>,
+    references: &mut Vec<String>,
+) {
+    for element in &pattern.elements {
+        if let ast::PatternElement::Placeable { expression } = element {
+            expression_references(expression, references);
+        }
+    }
+}
+
+fn expression_references(
+    expression: &ast::Expression<String>,
+    references: &mut Vec<String>,
+) {
+    match expression {
+        ast::Expression::Inline(expression) => {
+            inline_references(expression, references)
+        }
+        ast::Expression::Select { selector, variants } => {
+            inline_references(selector, references);
+            for variant in variants {
+                pattern_references(&variant.value, references);
+            }
+        }
+    }
+}
+
+fn inline_references(
+    expression: &ast::InlineExpression<String>,
+    references: &mut Vec<String>,
+) {
+    let arguments = match expression {
+        ast::InlineExpression::MessageReference { id, .. } => {
+            references.push(id.name.clone());
+
+            None
+        }
+        ast::InlineExpression::TermReference { id, arguments, .. } => {
+            references.push(format!("-{}", id.name));
+
+            arguments.as_ref()
+        }
+        ast::InlineExpression::FunctionReference { arguments, .. } => {
+            Some(arguments)
+        }
+        ast::InlineExpression::Placeable { expression } => {
+            expression_references(expression, references);
+
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(arguments) = arguments {
+        for argument in arguments
+            .positional
+            .iter()
+            .chain(arguments.named.iter().map(|argument| &argument.value))
+        {
+            inline_references(argument, references);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::context;
+    use crate::formatter::fluent::FluentFormatter;
+    use crate::loader::file::Configuration;
+    use crate::loader::fluent::load_sync;
+    use crate::loader::fluent::load_sync_with;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+    use crate::Translator;
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn load_test() {
+        use crate::loader::fluent::load;
+
+        let bag = load("examples/fluent").await.unwrap();
+
+        assert_eq!(bag, load_sync("examples/fluent").unwrap());
+    }
+
+    #[test]
+    fn load_sync_with_test() {
+        let bag = load_sync_with(
+            "examples",
+            Configuration::new().with_recursion(true),
+        )
+        .unwrap();
+
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("fluent.messages", "welcome").unwrap(),
+            "welcome = Welcome to { -brand }, { $name }!\n-brand = Tarjama\n"
+        );
+        assert_eq!(catalogue.get("messages", "welcome"), None);
+    }
+
+    #[test]
+    fn load_sync_test() {
+        let bag = load_sync("examples/fluent").unwrap();
+
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
+        let message = catalogue.get("messages", "welcome").unwrap();
+        assert_eq!(
+            message,
+            "welcome = Welcome to { -brand }, { $name }!\n-brand = Tarjama\n"
+        );
+
+        let translator =
+            Translator::new(FluentFormatter::new().into(), bag, None);
+
+        assert_eq!(
+            translator
+                .trans("en", "messages", "welcome", context!(name = "Saif"))
+                .unwrap(),
+            "Welcome to Tarjama, Saif!"
+        );
+        assert_eq!(
+            translator
+                .trans("fr", "messages", "welcome", context!(name = "Saif"))
+                .unwrap(),
+            "Bienvenue sur Tarjama, Saif !"
+        );
+        assert_eq!(
+            translator
+                .trans("en", "messages", "emails", context!(? = 1))
+                .unwrap(),
+            "You have one new email."
+        );
+        assert_eq!(
+            translator
+                .trans("fr", "messages", "emails", context!(? = 1))
+                .unwrap(),
+            "Vous avez 1 nouvel e-mail."
+        );
+        assert_eq!(
+            translator
+                .trans("en", "messages", "login.placeholder", context!())
+                .unwrap(),
+            "Your Tarjama email"
+        );
+        assert_eq!(
+            translator
+                .trans("fr", "messages", "login.placeholder", context!())
+                .unwrap(),
+            "Votre e-mail Tarjama"
+        );
+        assert_eq!(
+            translator.trans("en", "errors", "not-found", context!()).unwrap(),
+            "Tarjama could not find this page."
+        );
+        assert!(translator
+            .trans("en", "messages", "login", context!())
+            .is_err());
+    }
+}
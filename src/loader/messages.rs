@@ -0,0 +1,362 @@
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+
+/// The CLDR plural categories, in the order they are listed in plural messages.
+const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// The messages of a file, as `(id, message)` pairs.
+///
+/// Messages can either be listed flat, or grouped into nested maps, in which case the keys of the
+/// enclosing maps are joined using `.` to form the id, e.g. the following files are equivalent:
+///
+/// ```json
+/// { "user.greeting": "Hello, {name}!" }
+/// ```
+///
+/// ```json
+/// { "user": { "greeting": "Hello, {name}!" } }
+/// ```
+///
+/// Plural messages can be written as a list of rules, joined using ` | `, or as a map of CLDR
+/// plural categories, and exact numbers, containing the `other` category and at least one other
+/// category or number, e.g. the following files are equivalent:
+///
+/// ```json
+/// { "apple": "{0} No apples | {one} One apple | {other} {?} apples" }
+/// ```
+///
+/// ```json
+/// { "apple": ["{0} No apples", "{one} One apple", "{other} {?} apples"] }
+/// ```
+///
+/// ```json
+/// { "apple": { "0": "No apples", "one": "One apple", "other": "{?} apples" } }
+/// ```
+///
+/// Within a map of plural categories, `|` is a literal character, and is escaped as `||`.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Messages(pub(crate) Vec<(String, String)>);
+
+impl<'de> Deserialize<'de> for Messages {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut messages = vec![];
+        for (id, node) in deserializer.deserialize_any(RootVisitor)? {
+            flatten(id, node, &mut messages);
+        }
+
+        Ok(Messages(messages))
+    }
+}
+
+/// The messages of a file, grouped by domain, as `(domain, messages)` pairs, i.e. a map of domains
+/// at the top-level of the file, each containing a map of messages.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Domains(pub(crate) Vec<(String, Messages)>);
+
+impl<'de> Deserialize<'de> for Domains {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DomainsVisitor)
+    }
+}
+
+struct DomainsVisitor;
+
+impl<'de> Visitor<'de> for DomainsVisitor {
+    type Value = Domains;
+
+    fn expecting(
+        &self,
+        formatter: &mut ::std::fmt::Formatter,
+    ) -> ::std::fmt::Result {
+        write!(formatter, "a map of domains")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // an empty document.
+        Ok(Domains::default())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut domains = vec![];
+        while let Some(entry) = map.next_entry::<String, Messages>()? {
+            domains.push(entry);
+        }
+
+        Ok(Domains(domains))
+    }
+}
+
+/// A node of a messages file.
+enum Node {
+    Message(String),
+    Plural(Vec<String>),
+    Map(Vec<(String, Node)>),
+}
+
+struct RootVisitor;
+
+impl<'de> Visitor<'de> for RootVisitor {
+    type Value = Vec<(String, Node)>;
+
+    fn expecting(
+        &self,
+        formatter: &mut ::std::fmt::Formatter,
+    ) -> ::std::fmt::Result {
+        write!(formatter, "a map of messages")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // an empty document.
+        Ok(vec![])
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        entries(map)
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(
+        &self,
+        formatter: &mut ::std::fmt::Formatter,
+    ) -> ::std::fmt::Result {
+        write!(
+            formatter,
+            "a message, a list of messages, or a map of messages"
+        )
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Node::Message(value.to_string()))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut messages = vec![];
+        while let Some(message) = seq.next_element::<String>()? {
+            messages.push(message);
+        }
+
+        Ok(Node::Plural(messages))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Ok(Node::Map(entries(map)?))
+    }
+}
+
+fn entries<'de, A>(mut map: A) -> Result<Vec<(String, Node)>, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let mut entries = vec![];
+    while let Some(entry) = map.next_entry::<String, Node>()? {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn flatten(id: String, node: Node, messages: &mut Vec<(String, String)>) {
+    match node {
+        Node::Message(message) => messages.push((id, message)),
+        Node::Plural(rules) => messages.push((id, rules.join(" | "))),
+        Node::Map(entries) => match plural(&entries) {
+            Some(message) => messages.push((id, message)),
+            None => {
+                for (key, node) in entries {
+                    flatten(format!("{id}.{key}"), node, messages);
+                }
+            }
+        },
+    }
+}
+
+/// Returns the plural message of a map of plural categories, and exact numbers, if it is one.
+///
+/// A plural map has an `other` entry, and at least one other category or exact number.
+fn plural(entries: &[(String, Node)]) -> Option<String> {
+    let mut numbers = vec![];
+    let mut categories = [None; CATEGORIES.len()];
+    for (key, node) in entries {
+        let Node::Message(message) = node else {
+            return None;
+        };
+
+        if key.parse::<u64>().is_ok() {
+            numbers.push(format!("{{{key}}} {}", escape(message)));
+        } else {
+            let index = CATEGORIES.iter().position(|c| c == key)?;
+
+            categories[index] = Some(message);
+        }
+    }
+
+    // a map with only `other` is a nested message, e.g. `status.other`.
+    let (other, categories) = categories.split_last()?;
+    let other = (*other)?;
+    if numbers.is_empty() && categories.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let rules: Vec<String> = numbers
+        .into_iter()
+        .chain(categories.iter().zip(CATEGORIES).filter_map(
+            |(message, category)| {
+                message.map(|message| {
+                    format!("{{{category}}} {}", escape(message))
+                })
+            },
+        ))
+        .chain(std::iter::once(format!("{{other}} {}", escape(other))))
+        .collect();
+
+    Some(rules.join(" | "))
+}
+
+/// Escape the `|` of a plural branch, so it is not mistaken for a separator.
+fn escape(message: &str) -> String {
+    message.replace('|', "||")
+}
+
+#[cfg(all(test, feature = "json"))]
+mod test {
+    use crate::loader::messages::Messages;
+
+    #[test]
+    fn flattening() {
+        let flat: Messages = serde_json::from_str(
+            r#"{ "greeting": "Hello", "user.greeting": "Hello, {name}!" }"#,
+        )
+        .unwrap();
+        let nested: Messages = serde_json::from_str(
+            r#"{ "greeting": "Hello", "user": { "greeting": "Hello, {name}!" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(flat, nested);
+        assert_eq!(
+            nested.0,
+            vec![
+                ("greeting".to_string(), "Hello".to_string()),
+                ("user.greeting".to_string(), "Hello, {name}!".to_string()),
+            ]
+        );
+
+        let error = serde_json::from_str::<Messages>(r#"{ "count": 3 }"#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "invalid type: integer `3`, expected a message, a list of messages, or a map of messages at line 1 column 12"
+        );
+
+        let error = serde_json::from_str::<Messages>(r#""greeting""#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "invalid type: string \"greeting\", expected a map of messages at line 1 column 10"
+        );
+    }
+
+    #[test]
+    fn plurals() {
+        let messages: Messages = serde_json::from_str(
+            r#"{
+                "list": ["{0} No apples", "{one} One apple", "{other} {?} apples"],
+                "categories": { "other": "{?} apples", "one": "One apple", "0": "No apples" },
+                "other": { "other": "Apples" },
+                "group": { "one": "One", "two": "Two" },
+                "nested": { "apple": { "one": "One apple", "other": "{?} apples" } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages.0,
+            vec![
+                (
+                    "list".to_string(),
+                    "{0} No apples | {one} One apple | {other} {?} apples"
+                        .to_string()
+                ),
+                (
+                    "categories".to_string(),
+                    "{0} No apples | {one} One apple | {other} {?} apples"
+                        .to_string()
+                ),
+                ("other.other".to_string(), "Apples".to_string()),
+                ("group.one".to_string(), "One".to_string()),
+                ("group.two".to_string(), "Two".to_string()),
+                (
+                    "nested.apple".to_string(),
+                    "{one} One apple | {other} {?} apples".to_string()
+                ),
+            ]
+        );
+
+        let messages: Messages = serde_json::from_str(
+            r#"{ "pipe": { "one": "a | b", "other": "{?} c | d" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages.0,
+            vec![(
+                "pipe".to_string(),
+                "{one} a || b | {other} {?} c || d".to_string()
+            )]
+        );
+
+        let error = serde_json::from_str::<Messages>(r#"{ "apple": [1] }"#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "invalid type: integer `1`, expected a string at line 1 column 13"
+        );
+    }
+}
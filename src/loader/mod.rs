@@ -3,5 +3,23 @@ pub mod error;
 #[cfg(feature = "file")]
 pub mod file;
 
+#[cfg(feature = "fluent")]
+pub mod fluent;
+
+#[cfg(feature = "json")]
+pub mod json;
+
+#[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
+mod messages;
+
+#[cfg(feature = "po")]
+pub mod po;
+
 #[cfg(feature = "toml")]
 pub mod toml;
+
+#[cfg(feature = "xliff")]
+pub mod xliff;
+
+#[cfg(feature = "yaml")]
+pub mod yaml;
@@ -0,0 +1,1216 @@
+use crate::catalogue::Catalogue;
+use crate::catalogue::CatalogueBag;
+use crate::error::Error;
+use crate::loader::error::Error as LoadingError;
+use crate::loader::file::Configuration;
+use crate::locale::Locale;
+use crate::plural::cardinal;
+use crate::plural::PluralCategory;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The separator placed between the context ( `msgctxt` ) and the id ( `msgid` ) of a message,
+/// as used by gettext.
+///
+/// A message with a context is inserted into the catalogue using `{msgctxt}\u{4}{msgid}` as its
+/// id.
+pub const CONTEXT_SEPARATOR: char = '\u{4}';
+
+/// The counts used to map gettext plural forms to CLDR plural categories, `0..=SAMPLES`.
+///
+/// Plural rules, in CLDR and in the `Plural-Forms` of gettext, only depend on `n`, `n % 10`,
+/// `n % 100` and `n % 1000` for small counts, so the samples cover a full period of each, and
+/// larger counts are assumed to follow the same pattern.
+const SAMPLES: u64 = 1000;
+
+/// Counts above [`SAMPLES`] with a category of their own, e.g. the `many` category of millions
+/// in French or Spanish.
+const LARGE_SAMPLES: [u64; 2] = [1_000_000, 2_000_000];
+
+/// The maximum nesting depth of a plural expression, to guard against stack overflows.
+const MAXIMUM_DEPTH: usize = 64;
+
+/// Load a catalogue bag from a directory containing gettext `.po` and `.mo` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.po`,
+/// or `{domain}.{locale}.mo`.
+///
+/// Each translated message is inserted using its `msgid` as id, prefixed with its `msgctxt` and
+/// [`CONTEXT_SEPARATOR`] if any, fuzzy and untranslated messages are skipped.
+///
+/// Plural messages ( `msgid_plural` ) are converted into the plural syntax of the default
+/// formatter, using the `Plural-Forms` header of the file: each plural form is selected using the
+/// CLDR plural category of the locale it matches, and counts for which the gettext expression
+/// disagrees with the CLDR rules are listed explicitly, e.g. `nplurals=2; plural=(n != 1);` in
+/// an english file gives `{one} msgstr[0] | {other} msgstr[1]`.
+///
+/// See [`load_with`] to load files using a different layout.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::po::load;
+///
+/// let catalogue_bag = load("examples/gettext").await.expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    load_with(directory, Configuration::default()).await
+}
+
+/// Load a catalogue bag from a directory containing gettext `.po` and `.mo` files, using the given
+/// configuration.
+///
+/// gettext files do not define domains, so with the
+/// [`Layout::LocaleFile`](crate::loader::file::Layout::LocaleFile) layout, files should be named
+/// `{locale}.po` or `{locale}.mo`, and their messages are inserted into the domain of their
+/// subdirectory, i.e. the empty domain at the top-level.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::po::load_with;
+///
+/// let configuration = Configuration::new().with_recursion(true);
+/// let catalogue_bag = load_with("examples/gettext", configuration)
+///     .await
+///     .expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use crate::loader::file::iterate_with;
+    use futures_util::future::join_all;
+    use tokio::fs;
+
+    let data = iterate_with(
+        directory,
+        &["po".to_string(), "mo".to_string()],
+        configuration,
+    )
+    .await?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            let message_groups =
+                join_all(message_files.iter().map(|path| async move {
+                    fs::read(path)
+                        .await
+                        .map_err(|e| -> Error {
+                            Error::LoadingError(
+                                LoadingError::FailedToReadFile(
+                                    path.to_string(),
+                                    e,
+                                ),
+                            )
+                        })
+                        .and_then(|content| messages(&locale, path, &content))
+                }))
+                .await;
+
+            for messages in message_groups {
+                for (id, message) in messages? {
+                    catalogue.insert(&domain, &id, &message);
+                }
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Synchronously load a catalogue bag from a directory containing gettext `.po` and `.mo` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.po`,
+/// or `{domain}.{locale}.mo`.
+pub fn load_sync<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    load_sync_with(directory, Configuration::default())
+}
+
+/// Synchronously load a catalogue bag from a directory containing gettext `.po` and `.mo` files,
+/// using the given configuration.
+///
+/// See [`load_with`].
+pub fn load_sync_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    use crate::loader::file::iterate_with_sync;
+    use std::fs;
+
+    let data = iterate_with_sync(
+        directory,
+        &["po".to_string(), "mo".to_string()],
+        configuration,
+    )?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            for path in message_files {
+                let content = fs::read(&path).map_err(|e| {
+                    Error::LoadingError(LoadingError::FailedToReadFile(
+                        path.clone(),
+                        e,
+                    ))
+                })?;
+
+                for (id, message) in messages(&locale, &path, &content)? {
+                    catalogue.insert(&domain, &id, &message);
+                }
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// A gettext message, as found in a `.po` or `.mo` file.
+#[derive(Debug, Default)]
+struct Entry {
+    context: Option<String>,
+    id: String,
+    plural: bool,
+    translations: Vec<String>,
+    fuzzy: bool,
+}
+
+/// Returns the translated messages of a `.po` or `.mo` file, as `(id, message)` pairs.
+fn messages(
+    locale: &Locale,
+    path: &str,
+    content: &[u8],
+) -> Result<Vec<(String, String)>, Error> {
+    let error = |reason: String| {
+        Error::LoadingError(LoadingError::FailedToParseGettextFile(
+            path.to_string(),
+            reason,
+        ))
+    };
+
+    let entries = if path.ends_with(".mo") {
+        parse_mo(content)
+    } else {
+        std::str::from_utf8(content)
+            .map_err(|e| format!("invalid utf-8, {e}"))
+            .and_then(parse_po)
+    }
+    .map_err(error)?;
+
+    let plural_forms = entries
+        .iter()
+        .find(|entry| entry.context.is_none() && entry.id.is_empty())
+        .and_then(|header| header.translations.first())
+        .and_then(|header| {
+            header.lines().find_map(|line| {
+                line.strip_prefix("Plural-Forms:").map(str::trim)
+            })
+        })
+        .map(PluralForms::parse)
+        .transpose()
+        .map_err(error)?
+        .unwrap_or_default();
+
+    let mut messages = vec![];
+    for entry in entries {
+        if entry.id.is_empty()
+            || entry.fuzzy
+            || entry.translations.iter().all(String::is_empty)
+        {
+            continue;
+        }
+
+        let id = match entry.context {
+            Some(context) => {
+                format!("{context}{CONTEXT_SEPARATOR}{}", entry.id)
+            }
+            None => entry.id,
+        };
+
+        let message = if entry.plural {
+            plural_forms.message(locale, &entry.translations)
+        } else {
+            entry.translations.into_iter().next().unwrap_or_default()
+        };
+
+        messages.push((id, message));
+    }
+
+    Ok(messages)
+}
+
+/// The field of an entry that continuation strings are appended to.
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    Plural,
+    Translation(usize),
+}
+
+/// Parse the entries of a `.po` file.
+fn parse_po(content: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = vec![];
+    let mut entry = Entry::default();
+    let mut field: Option<Field> = None;
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let error = |reason: &str| format!("{reason} on line {}", number + 1);
+
+        let (keyword, value) = if line.starts_with('"') {
+            ("", line)
+        } else if line.starts_with('#') {
+            ("#", line)
+        } else {
+            line.split_once(char::is_whitespace)
+                .map(|(keyword, value)| (keyword, value.trim_start()))
+                .ok_or_else(|| error("expected a string"))?
+        };
+
+        // a comment, or a new message, completes the current entry.
+        if matches!(keyword, "#" | "msgctxt" | "msgid")
+            && !entry.translations.is_empty()
+        {
+            entries.push(std::mem::take(&mut entry));
+            field = None;
+        }
+
+        let target = match keyword {
+            // obsolete messages are commented out, and thus ignored.
+            "#" => {
+                if let Some(flags) = value.strip_prefix("#,") {
+                    entry.fuzzy |=
+                        flags.split(',').any(|flag| flag.trim() == "fuzzy");
+                }
+
+                continue;
+            }
+            "" => field.ok_or_else(|| error("unexpected string"))?,
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgid_plural" => Field::Plural,
+            "msgstr" => Field::Translation(0),
+            _ => keyword
+                .strip_prefix("msgstr[")
+                .and_then(|index| index.strip_suffix(']'))
+                .and_then(|index| index.parse().ok())
+                .map(Field::Translation)
+                .ok_or_else(|| {
+                    error(&format!("unexpected keyword `{keyword}`"))
+                })?,
+        };
+
+        let value = unquote(value).map_err(|reason| error(&reason))?;
+        match target {
+            Field::Context => {
+                entry.context.get_or_insert_with(String::new).push_str(&value)
+            }
+            Field::Id => entry.id.push_str(&value),
+            Field::Plural => entry.plural = true,
+            Field::Translation(index) => {
+                if entry.translations.len() <= index {
+                    entry.translations.resize(index + 1, String::new());
+                }
+
+                entry.translations[index].push_str(&value);
+            }
+        }
+
+        field = Some(target);
+    }
+
+    if !entry.translations.is_empty() {
+        entries.push(entry);
+    } else if !entry.id.is_empty() {
+        return Err("missing `msgstr` at the end of the file".to_string());
+    }
+
+    Ok(entries)
+}
+
+/// Unquote and unescape a `.po` string.
+fn unquote(value: &str) -> Result<String, String> {
+    let inner = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| format!("invalid string `{value}`"))?;
+
+    let mut result = String::with_capacity(inner.len());
+    let mut characters = inner.chars();
+    while let Some(character) = characters.next() {
+        if character == '"' {
+            return Err(format!("unescaped quote in `{value}`"));
+        }
+
+        if character != '\\' {
+            result.push(character);
+
+            continue;
+        }
+
+        result.push(match characters.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('a') => '\u{7}',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('v') => '\u{b}',
+            Some(c @ ('"' | '\\' | '\'' | '?')) => c,
+            Some(c) => return Err(format!("invalid escape `\\{c}`")),
+            None => return Err(format!("invalid string `{value}`")),
+        });
+    }
+
+    Ok(result)
+}
+
+/// Parse the entries of a `.mo` file.
+fn parse_mo(content: &[u8]) -> Result<Vec<Entry>, String> {
+    let word = |offset: usize, big_endian: bool| -> Result<usize, String> {
+        let bytes: [u8; 4] = content
+            .get(offset..offset + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| "unexpected end of file".to_string())?;
+
+        Ok(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        } as usize)
+    };
+
+    let big_endian = match word(0, false)? {
+        0x950412de => false,
+        0xde120495 => true,
+        _ => return Err("invalid magic number".to_string()),
+    };
+
+    if word(4, big_endian)? >> 16 > 1 {
+        return Err("unsupported revision".to_string());
+    }
+
+    let count = word(8, big_endian)?;
+    let originals = word(12, big_endian)?;
+    let translations = word(16, big_endian)?;
+
+    // both string tables must fit within the file before trusting `count`.
+    for table in [originals, translations] {
+        count
+            .checked_mul(8)
+            .and_then(|size| table.checked_add(size))
+            .filter(|end| *end <= content.len())
+            .ok_or_else(|| "unexpected end of file".to_string())?;
+    }
+
+    let string = |table: usize, index: usize| -> Result<&str, String> {
+        let length = word(table + index * 8, big_endian)?;
+        let offset = word(table + index * 8 + 4, big_endian)?;
+
+        let bytes = offset
+            .checked_add(length)
+            .and_then(|end| content.get(offset..end))
+            .ok_or_else(|| "unexpected end of file".to_string())?;
+
+        std::str::from_utf8(bytes).map_err(|e| format!("invalid utf-8, {e}"))
+    };
+
+    let mut entries = Vec::with_capacity(count);
+    for index in 0..count {
+        let original = string(originals, index)?;
+        let (context, original) = match original.split_once(CONTEXT_SEPARATOR)
+        {
+            Some((context, original)) => (Some(context.to_string()), original),
+            None => (None, original),
+        };
+
+        let (id, plural) = match original.split_once('\0') {
+            Some((id, _)) => (id, true),
+            None => (original, false),
+        };
+
+        entries.push(Entry {
+            context,
+            id: id.to_string(),
+            plural,
+            translations: string(translations, index)?
+                .split('\0')
+                .map(str::to_string)
+                .collect(),
+            fuzzy: false,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// The `Plural-Forms` header of a gettext file, e.g. `nplurals=2; plural=(n != 1);`.
+struct PluralForms {
+    count: usize,
+    expression: Expression,
+}
+
+impl Default for PluralForms {
+    /// The germanic plural forms, used by gettext when the header is missing.
+    fn default() -> Self {
+        PluralForms {
+            count: 2,
+            expression: Expression::Binary(
+                Operator::NotEqual,
+                Box::new(Expression::N),
+                Box::new(Expression::Number(1)),
+            ),
+        }
+    }
+}
+
+impl PluralForms {
+    fn parse(header: &str) -> Result<PluralForms, String> {
+        let mut count = None;
+        let mut expression = None;
+        for assignment in header.split(';') {
+            match assignment.split_once('=') {
+                Some((key, value)) if key.trim() == "nplurals" => {
+                    count = value.trim().parse::<usize>().ok();
+                }
+                Some((key, value)) if key.trim() == "plural" => {
+                    expression = Some(Expression::parse(value)?);
+                }
+                _ => {}
+            }
+        }
+
+        match (count, expression) {
+            (Some(count), Some(expression)) if count > 0 => {
+                Ok(PluralForms { count, expression })
+            }
+            _ => Err(format!("invalid plural forms `{header}`")),
+        }
+    }
+
+    /// Returns the index of the plural form used for `n`.
+    fn index(&self, n: u64, forms: usize) -> usize {
+        (self.expression.evaluate(n) as usize).min(self.count.min(forms) - 1)
+    }
+
+    /// Convert the given plural forms into a plural message.
+    fn message(&self, locale: &Locale, forms: &[String]) -> String {
+        // a literal `|` would otherwise separate the plural messages.
+        let forms: Vec<String> =
+            forms.iter().map(|form| form.replace('|', "||")).collect();
+
+        let mut indices: BTreeMap<PluralCategory, Vec<usize>> =
+            BTreeMap::new();
+        let mut samples =
+            Vec::with_capacity(SAMPLES as usize + 1 + LARGE_SAMPLES.len());
+        for n in (0..=SAMPLES).chain(LARGE_SAMPLES) {
+            let category = cardinal(locale, &(n as i64).into());
+            let index = self.index(n, forms.len());
+
+            indices.entry(category).or_default().push(index);
+            samples.push((n, category, index));
+        }
+
+        // each category uses the plural form it most often matches.
+        let categories: BTreeMap<PluralCategory, usize> = indices
+            .into_iter()
+            .map(|(category, indices)| {
+                let mut counts = vec![0; forms.len()];
+                for index in indices {
+                    counts[index] += 1;
+                }
+
+                let index = (0..forms.len())
+                    .max_by(|a, b| counts[*a].cmp(&counts[*b]).then(b.cmp(a)))
+                    .unwrap_or_default();
+
+                (category, index)
+            })
+            .collect();
+
+        let default = categories
+            .get(&PluralCategory::Other)
+            .copied()
+            .unwrap_or(forms.len() - 1);
+
+        let mut exceptions: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for (n, category, index) in samples {
+            if categories[&category] != index {
+                exceptions.entry(index).or_default().push(n.to_string());
+            }
+        }
+
+        let mut rules = vec![];
+        for (index, numbers) in exceptions {
+            rules.push(format!("{{{}}} {}", numbers.join(", "), forms[index]));
+        }
+
+        for (category, index) in categories {
+            if index != default {
+                rules.push(format!("{{{category}}} {}", forms[index]));
+            }
+        }
+
+        if rules.is_empty() {
+            return forms[default].clone();
+        }
+
+        rules.push(format!("{{other}} {}", forms[default]));
+
+        rules.join(" | ")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+/// A gettext plural expression, a subset of C expressions using the `n` variable.
+#[derive(Debug, PartialEq)]
+enum Expression {
+    N,
+    Number(u64),
+    Not(Box<Expression>),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    fn parse(source: &str) -> Result<Expression, String> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens: &tokens, position: 0, depth: 0 };
+
+        let expression = parser.conditional()?;
+        if parser.position != tokens.len() {
+            return Err(format!(
+                "invalid plural expression `{}`",
+                source.trim()
+            ));
+        }
+
+        Ok(expression)
+    }
+
+    fn evaluate(&self, n: u64) -> u64 {
+        match self {
+            Expression::N => n,
+            Expression::Number(number) => *number,
+            Expression::Not(operand) => u64::from(operand.evaluate(n) == 0),
+            Expression::Conditional(condition, then, otherwise) => {
+                if condition.evaluate(n) != 0 {
+                    then.evaluate(n)
+                } else {
+                    otherwise.evaluate(n)
+                }
+            }
+            Expression::Binary(operator, left, right) => {
+                let left = left.evaluate(n);
+                let right = right.evaluate(n);
+
+                match operator {
+                    Operator::Or => u64::from(left != 0 || right != 0),
+                    Operator::And => u64::from(left != 0 && right != 0),
+                    Operator::Equal => u64::from(left == right),
+                    Operator::NotEqual => u64::from(left != right),
+                    Operator::Less => u64::from(left < right),
+                    Operator::LessOrEqual => u64::from(left <= right),
+                    Operator::Greater => u64::from(left > right),
+                    Operator::GreaterOrEqual => u64::from(left >= right),
+                    Operator::Add => left.wrapping_add(right),
+                    Operator::Subtract => left.wrapping_sub(right),
+                    Operator::Multiply => left.wrapping_mul(right),
+                    Operator::Divide => left.checked_div(right).unwrap_or(0),
+                    Operator::Remainder => {
+                        left.checked_rem(right).unwrap_or(0)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<String>, String> {
+    let mut tokens = vec![];
+    let mut characters = source.chars().peekable();
+    while let Some(character) = characters.next() {
+        let token = match character {
+            c if c.is_whitespace() => continue,
+            'n' | '?' | ':' | '(' | ')' | '+' | '-' | '*' | '/' | '%' => {
+                character.to_string()
+            }
+            '0'..='9' => {
+                let mut number = character.to_string();
+                while let Some(digit) =
+                    characters.next_if(char::is_ascii_digit)
+                {
+                    number.push(digit);
+                }
+
+                number
+            }
+            '=' | '!' | '<' | '>' => match characters.next_if_eq(&'=') {
+                Some(_) => format!("{character}="),
+                None => character.to_string(),
+            },
+            '&' | '|' => match characters.next_if_eq(&character) {
+                Some(_) => format!("{character}{character}"),
+                None => return Err(format!("unexpected `{character}`")),
+            },
+            _ => return Err(format!("unexpected `{character}`")),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser for plural expressions, following the C operators precedence.
+struct Parser<'a> {
+    tokens: &'a [String],
+    position: usize,
+    /// The depth of the expression being parsed, see [`MAXIMUM_DEPTH`].
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).map(String::as_str)
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.peek() != Some(token) {
+            return Err(format!(
+                "expected `{token}`, found `{}`",
+                self.peek().unwrap_or("end of expression")
+            ));
+        }
+
+        self.position += 1;
+
+        Ok(())
+    }
+
+    /// Enter a nested expression, failing if it is nested too deeply.
+    fn enter(&mut self, depth: usize) -> Result<(), String> {
+        self.depth += depth;
+        if self.depth > MAXIMUM_DEPTH {
+            return Err(format!(
+                "plural expression exceeds the maximum depth of {MAXIMUM_DEPTH}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn conditional(&mut self) -> Result<Expression, String> {
+        self.enter(1)?;
+
+        let condition = self.binary(0)?;
+        if self.peek() != Some("?") {
+            self.depth -= 1;
+
+            return Ok(condition);
+        }
+
+        self.position += 1;
+        let then = self.conditional()?;
+        self.expect(":")?;
+        let otherwise = self.conditional()?;
+
+        self.depth -= 1;
+
+        Ok(Expression::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// Parse a binary expression of operators from the given precedence level and up.
+    fn binary(&mut self, level: usize) -> Result<Expression, String> {
+        const LEVELS: [&[(&str, Operator)]; 6] = [
+            &[("||", Operator::Or)],
+            &[("&&", Operator::And)],
+            &[("==", Operator::Equal), ("!=", Operator::NotEqual)],
+            &[
+                ("<", Operator::Less),
+                ("<=", Operator::LessOrEqual),
+                (">", Operator::Greater),
+                (">=", Operator::GreaterOrEqual),
+            ],
+            &[("+", Operator::Add), ("-", Operator::Subtract)],
+            &[
+                ("*", Operator::Multiply),
+                ("/", Operator::Divide),
+                ("%", Operator::Remainder),
+            ],
+        ];
+
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+
+        // each operator of a chain, e.g. `n + n + n`, nests the expression to its left.
+        let mut chain = 0;
+        let mut left = self.binary(level + 1)?;
+        while let Some(operator) = self.peek().and_then(|token| {
+            LEVELS[level]
+                .iter()
+                .find(|(symbol, _)| *symbol == token)
+                .map(|(_, operator)| *operator)
+        }) {
+            self.position += 1;
+            self.enter(1)?;
+            chain += 1;
+            let right = self.binary(level + 1)?;
+
+            left =
+                Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        self.depth -= chain;
+
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expression, String> {
+        let token = self
+            .peek()
+            .ok_or_else(|| "unexpected end of expression".to_string())?;
+        self.position += 1;
+
+        match token {
+            "!" => {
+                self.enter(1)?;
+                let operand = self.unary()?;
+                self.depth -= 1;
+
+                Ok(Expression::Not(Box::new(operand)))
+            }
+            "n" => Ok(Expression::N),
+            "(" => {
+                let expression = self.conditional()?;
+                self.expect(")")?;
+
+                Ok(expression)
+            }
+            _ => token
+                .parse()
+                .map(Expression::Number)
+                .map_err(|_| format!("unexpected `{token}`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::GermanVariant;
+    use crate::locale::RussianVariant;
+    use tokio;
+
+    #[tokio::test]
+    async fn load_test() {
+        let bag = load("examples/gettext").await.unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name} !"
+        );
+        assert_eq!(
+            catalogue.get("messages", "menu\u{4}File").unwrap(),
+            "Fichier"
+        );
+        assert_eq!(
+            catalogue.get("messages", "apple").unwrap(),
+            "{one} Une pomme | {other} {?} pommes"
+        );
+        assert_eq!(catalogue.get("messages", "fuzzy"), None);
+        assert_eq!(catalogue.get("messages", "untranslated"), None);
+
+        let catalogue = &bag.get(&Locale::Russian(RussianVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "apple").unwrap(),
+            "{one} {?} яблоко | {few} {?} яблока | {other} {?} яблок"
+        );
+
+        let catalogue = &bag.get(&Locale::German(GermanVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Hallo, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "menu\u{4}File").unwrap(),
+            "Datei"
+        );
+        assert_eq!(
+            catalogue.get("messages", "apple").unwrap(),
+            "{one} Ein Apfel | {other} {?} Äpfel"
+        );
+    }
+
+    #[test]
+    fn load_sync_test() {
+        let bag = load_sync("examples/gettext").unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "menu\u{4}File").unwrap(),
+            "Fichier"
+        );
+
+        let catalogue = &bag.get(&Locale::German(GermanVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "apple").unwrap(),
+            "{one} Ein Apfel | {other} {?} Äpfel"
+        );
+    }
+
+    #[test]
+    fn load_sync_with_test() {
+        let bag = load_sync_with(
+            "examples/layouts",
+            Configuration::new().with_recursion(true),
+        )
+        .unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("gettext.messages", "greeting").unwrap(),
+            "Bonjour, {name} !"
+        );
+        assert_eq!(catalogue.get("messages", "greeting"), None);
+    }
+
+    #[test]
+    fn load_override_test() {
+        // `messages.fr.po` is loaded after `messages.fr.mo`, and overrides its messages.
+        let bag = load_sync("examples/layouts/gettext").unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name} !"
+        );
+        assert_eq!(
+            catalogue.get("messages", "farewell").unwrap(),
+            "Au revoir, {name} !"
+        );
+    }
+
+    #[test]
+    fn po_parsing() {
+        let content = r#"
+# translator comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+#: src/main.rs:1
+msgid "multi"
+"line"
+msgstr ""
+"first\n"
+"second \"quoted\"\t\\"
+
+#, c-format, fuzzy
+msgid "fuzzy"
+msgstr "flou"
+
+#~ msgid "obsolete"
+#~ msgstr "obsolète"
+
+msgctxt "verb"
+msgid "open"
+msgstr "ouvrir"
+"#;
+
+        assert_eq!(
+            messages(
+                &Locale::French(FrenchVariant::Default),
+                "messages.fr.po",
+                content.as_bytes()
+            )
+            .unwrap(),
+            Vec::from([
+                (
+                    "multiline".to_string(),
+                    "first\nsecond \"quoted\"\t\\".to_string()
+                ),
+                ("verb\u{4}open".to_string(), "ouvrir".to_string()),
+            ])
+        );
+
+        for (content, error) in [
+            (
+                "msgid \"foo\"\nmsgstr \"bar",
+                "unparsable node: file `messages.fr.po`, invalid string `\"bar` on line 2.",
+            ),
+            (
+                "msgid \"foo\"\nmsgstr \"\\x\"",
+                "unparsable node: file `messages.fr.po`, invalid escape `\\x` on line 2.",
+            ),
+            (
+                "\"foo\"",
+                "unparsable node: file `messages.fr.po`, unexpected string on line 1.",
+            ),
+            (
+                "msgid \"foo\"\nmsgstr(0) \"bar\"",
+                "unparsable node: file `messages.fr.po`, unexpected keyword `msgstr(0)` on line 2.",
+            ),
+            (
+                "msgid \"foo\"",
+                "unparsable node: file `messages.fr.po`, missing `msgstr` at the end of the file.",
+            ),
+            (
+                "msgid \"\"\nmsgstr \"Plural-Forms: nplurals=2; plural=n >;\\n\"",
+                "unparsable node: file `messages.fr.po`, unexpected end of expression.",
+            ),
+        ] {
+            assert_eq!(
+                messages(
+                    &Locale::French(FrenchVariant::Default),
+                    "messages.fr.po",
+                    content.as_bytes()
+                )
+                .unwrap_err()
+                .to_string(),
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn plural_forms() {
+        let forms = |count: usize| -> Vec<String> {
+            (0..count).map(|index| format!("form {index}")).collect()
+        };
+
+        for (locale, header, count, expected) in [
+            (
+                Locale::English(EnglishVariant::Default),
+                "nplurals=2; plural=(n != 1);",
+                2,
+                "{one} form 0 | {other} form 1",
+            ),
+            (
+                Locale::Japanese,
+                "nplurals=1; plural=0;",
+                1,
+                "form 0",
+            ),
+            (
+                // a custom form for zero, on top of the CLDR categories.
+                Locale::English(EnglishVariant::Default),
+                "nplurals=3; plural=n==0 ? 0 : n==1 ? 1 : 2;",
+                3,
+                "{0} form 0 | {one} form 1 | {other} form 2",
+            ),
+            (
+                Locale::Polish,
+                "nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+                3,
+                "{one} form 0 | {few} form 1 | {other} form 2",
+            ),
+            (
+                Locale::Arabic(crate::locale::ArabicVariant::Default),
+                "nplurals=6; plural=n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5;",
+                6,
+                "{zero} form 0 | {one} form 1 | {two} form 2 | {few} form 3 | {many} form 4 | {other} form 5",
+            ),
+            (
+                // more forms in the header than in the message.
+                Locale::English(EnglishVariant::Default),
+                "nplurals=3; plural=n==0 ? 0 : n==1 ? 1 : 2;",
+                2,
+                "{0} form 0 | {other} form 1",
+            ),
+        ] {
+            assert_eq!(
+                PluralForms::parse(header)
+                    .unwrap()
+                    .message(&locale, &forms(count)),
+                expected,
+                "{header}"
+            );
+        }
+    }
+
+    #[test]
+    fn mo_parsing_errors() {
+        let header = |count: u32, originals: u32, translations: u32| {
+            [0x950412de, 0, count, originals, translations, 0, 0]
+                .iter()
+                .flat_map(|word: &u32| word.to_le_bytes())
+                .collect::<Vec<u8>>()
+        };
+
+        for content in [
+            // a corrupt entry count, far beyond the size of the file.
+            header(u32::MAX, 28, 28),
+            // string tables past the end of the file.
+            header(1, 28, 36),
+            // a truncated header.
+            header(1, 28, 28)[..20].to_vec(),
+        ] {
+            assert_eq!(
+                messages(
+                    &Locale::French(FrenchVariant::Default),
+                    "messages.fr.mo",
+                    &content
+                )
+                .unwrap_err()
+                .to_string(),
+                "unparsable node: file `messages.fr.mo`, unexpected end of file."
+            );
+        }
+
+        // a string whose offset and length overflow.
+        let mut content = header(1, 28, 36);
+        for word in [1u32, u32::MAX, 0, 0] {
+            content.extend(word.to_le_bytes());
+        }
+
+        assert_eq!(
+            messages(
+                &Locale::French(FrenchVariant::Default),
+                "messages.fr.mo",
+                &content
+            )
+            .unwrap_err()
+            .to_string(),
+            "unparsable node: file `messages.fr.mo`, unexpected end of file."
+        );
+    }
+
+    #[test]
+    fn plural_forms_escaping() {
+        let content = r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+msgid "file"
+msgid_plural "files"
+msgstr[0] "Un fichier | dossier"
+msgstr[1] "{?} fichiers | dossiers"
+"#;
+
+        let locale = Locale::French(FrenchVariant::Default);
+        let messages =
+            messages(&locale, "messages.fr.po", content.as_bytes()).unwrap();
+
+        assert_eq!(
+            messages,
+            Vec::from([(
+                "file".to_string(),
+                "{one} Un fichier || dossier | {other} {?} fichiers || dossiers"
+                    .to_string()
+            )])
+        );
+
+        let formatter: Box<dyn crate::formatter::Formatter> =
+            Default::default();
+        assert_eq!(
+            formatter
+                .format(&locale, &messages[0].1, &crate::context!(? = 1))
+                .unwrap(),
+            "Un fichier | dossier"
+        );
+        assert_eq!(
+            formatter
+                .format(&locale, &messages[0].1, &crate::context!(? = 3))
+                .unwrap(),
+            "3 fichiers | dossiers"
+        );
+    }
+
+    #[test]
+    fn plural_expressions() {
+        for (expression, n, expected) in [
+            ("n != 1", 1, 0),
+            ("n != 1", 5, 1),
+            ("(n % 10 == 1 && n % 100 != 11) ? 0 : 1", 21, 0),
+            ("(n % 10 == 1 && n % 100 != 11) ? 0 : 1", 11, 1),
+            ("!(n > 1)", 0, 1),
+            ("n * 2 + 1 - 3 / 3", 4, 8),
+            ("n / 0 + n % 0", 4, 0),
+            ("n <= 1 || n >= 10", 5, 0),
+            ("n < 2 ? n > 0 ? 1 : 0 : 2", 1, 1),
+        ] {
+            assert_eq!(
+                Expression::parse(expression).unwrap().evaluate(n),
+                expected,
+                "{expression}"
+            );
+        }
+
+        for expression in ["", "n ==", "(n", "n & 1", "x", "n 1"] {
+            assert!(Expression::parse(expression).is_err(), "{expression}");
+        }
+
+        for expression in [
+            format!("{}n{}", "(".repeat(100_000), ")".repeat(100_000)),
+            format!("{}n", "!".repeat(100_000)),
+            format!("{}0", "n ? ".repeat(100_000)),
+            "n + ".repeat(100_000) + "n",
+        ] {
+            assert_eq!(
+                Expression::parse(&expression).unwrap_err(),
+                "plural expression exceeds the maximum depth of 64"
+            );
+        }
+
+        let expression = format!("{}n{}", "(".repeat(60), ")".repeat(60));
+        assert_eq!(Expression::parse(&expression).unwrap().evaluate(3), 3);
+    }
+}
@@ -2,8 +2,12 @@ use crate::catalogue::Catalogue;
 use crate::catalogue::CatalogueBag;
 use crate::error::Error;
 use crate::loader::error::Error as LoadingError;
+use crate::loader::file;
+use crate::loader::file::Configuration;
+use crate::loader::file::Layout;
+use crate::loader::messages::Domains;
+use crate::loader::messages::Messages;
 
-use std::collections::HashMap;
 use std::path::Path;
 use toml;
 
@@ -11,6 +15,30 @@ use toml;
 ///
 /// Files within the directory should be named in the following format:  `{domain}.{locale}.toml`.
 ///
+/// Messages can be grouped into nested tables, in which case their ids are joined using `.`, e.g.
+/// the following file defines the `user.greeting` message:
+///
+/// ```toml
+/// [user]
+/// greeting = "Hello, {name}!"
+/// ```
+///
+/// Plural messages can be written as an array of rules, or as a table of CLDR plural categories,
+/// and exact numbers, containing the `other` category and at least one other category or number,
+/// e.g. the following messages are equivalent:
+///
+/// ```toml
+/// apple = "{0} No apples | {one} One apple | {other} {?} apples"
+/// pear = ["{0} No apples", "{one} One apple", "{other} {?} apples"]
+///
+/// [peach]
+/// 0 = "No apples"
+/// one = "One apple"
+/// other = "{?} apples"
+/// ```
+///
+/// See [`load_with`] to load files using a different layout.
+///
 /// # Examples
 ///
 /// ```
@@ -25,16 +53,48 @@ pub async fn load<T>(directory: T) -> Result<CatalogueBag, Error>
 where
     T: AsRef<Path> + 'static,
 {
-    use crate::loader::file::iterate;
+    load_with(directory, Configuration::default()).await
+}
+
+/// Load a catalogue bag from a directory containing toml files, using the given configuration.
+///
+/// With the [`Layout::LocaleFile`] layout, files should be named `{locale}.toml`, and
+/// contain a top-level table per domain.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::file::Layout;
+/// use tarjama::loader::toml::load_with;
+///
+/// let configuration = Configuration::new().with_layout(Layout::LocaleDirectory);
+/// let catalogue_bag = load_with("examples/layouts/directory", configuration)
+///     .await
+///     .expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use crate::loader::file::iterate_with;
     use futures_util::future::join_all;
     use tokio::fs;
 
-    let data = iterate(directory, &["toml".to_string()]).await?;
+    let data =
+        iterate_with(directory, &["toml".to_string()], configuration).await?;
 
     let mut bag = CatalogueBag::new();
     for (locale, domain_files) in data {
         let mut catalogue = Catalogue::new(locale);
         for (domain, message_files) in domain_files {
+            let domain = &domain;
             let message_groups =
                 join_all(message_files.iter().map(|path| async move {
                     fs::read_to_string(path)
@@ -48,19 +108,16 @@ where
                             )
                         })
                         .and_then(|content| {
-                            toml::from_str::<HashMap<String, String>>(&content)
-                                .map_err(|e| -> Error {
-                                    Error::LoadingError(
-                                        LoadingError::FailedToParseFile(e),
-                                    )
-                                })
+                            parse(&content, domain, configuration.layout())
                         })
                 }))
                 .await;
 
-            for messages in message_groups {
-                for (id, message) in messages? {
-                    catalogue.insert(&domain, &id, &message);
+            for domains in message_groups {
+                for (domain, messages) in domains? {
+                    for (id, message) in messages.0 {
+                        catalogue.insert(&domain, &id, &message);
+                    }
                 }
             }
         }
@@ -78,17 +135,30 @@ pub fn load_sync<T>(directory: T) -> Result<CatalogueBag, Error>
 where
     T: AsRef<Path>,
 {
-    use crate::loader::file::iterate_sync;
+    load_sync_with(directory, Configuration::default())
+}
+
+/// Synchronously load a catalogue bag from a directory containing toml files, using the given
+/// configuration.
+///
+/// See [`load_with`].
+pub fn load_sync_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    use crate::loader::file::iterate_with_sync;
     use std::fs;
 
-    // Utilize the previously created iterate_sync function
-    let data = iterate_sync(directory, &["toml".to_string()])?;
+    let data =
+        iterate_with_sync(directory, &["toml".to_string()], configuration)?;
 
     let mut bag = CatalogueBag::new();
     for (locale, domain_files) in data {
         let mut catalogue = Catalogue::new(locale);
         for (domain, message_files) in domain_files {
-            // Directly read and process files synchronously
             for path in message_files {
                 let content = fs::read_to_string(&path).map_err(|e| {
                     Error::LoadingError(LoadingError::FailedToReadFile(
@@ -97,13 +167,12 @@ where
                     ))
                 })?;
 
-                let messages: HashMap<String, String> =
-                    toml::from_str(&content).map_err(|e| {
-                        Error::LoadingError(LoadingError::FailedToParseFile(e))
-                    })?;
-
-                for (id, message) in messages {
-                    catalogue.insert(&domain, &id, &message);
+                for (domain, messages) in
+                    parse(&content, &domain, configuration.layout())?
+                {
+                    for (id, message) in messages.0 {
+                        catalogue.insert(&domain, &id, &message);
+                    }
                 }
             }
         }
@@ -114,19 +183,48 @@ where
     Ok(bag)
 }
 
+/// Returns the messages of a file, grouped by domain.
+fn parse(
+    content: &str,
+    domain: &str,
+    layout: Layout,
+) -> Result<Vec<(String, Messages)>, Error> {
+    let result = match layout {
+        Layout::LocaleFile => {
+            toml::from_str::<Domains>(content).map(|domains| {
+                domains
+                    .0
+                    .into_iter()
+                    .map(|(name, messages)| {
+                        (file::qualify(domain, name), messages)
+                    })
+                    .collect()
+            })
+        }
+        _ => toml::from_str::<Messages>(content)
+            .map(|messages| vec![(domain.to_string(), messages)]),
+    };
+
+    result.map_err(|e| {
+        Error::LoadingError(LoadingError::FailedToParseFile(e.into()))
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::context;
     use crate::locale::EnglishVariant;
     use crate::locale::FrenchVariant;
     use crate::locale::Locale;
+    use crate::Translator;
     use tokio;
 
     #[tokio::test]
     async fn load_test() {
         let bag = load("examples/translations").await.unwrap();
 
-        let catalogue = bag.get(&Locale::French(FrenchVariant::Default))[0];
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
         assert_eq!(
             catalogue.get("messages", "greeting").unwrap(),
             "Bonjour, {name}!"
@@ -140,7 +238,7 @@ mod test {
             "bar! {} {foo} {} {bar} {} 🥐"
         );
 
-        let catalogue = bag.get(&Locale::English(EnglishVariant::Default))[0];
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
         assert_eq!(
             catalogue.get("messages", "greeting").unwrap(),
             "Hello, {name}!"
@@ -155,6 +253,169 @@ mod test {
         );
     }
 
+    #[test]
+    fn load_nested_test() {
+        let bag = load_sync("examples/translations/nested").unwrap();
+
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
+        assert_eq!(catalogue.get("shop", "title").unwrap(), "Shop");
+        assert_eq!(
+            catalogue.get("shop", "apple").unwrap(),
+            "{0} There are no apples | {one} There is one apple | {other} There are {?} apples"
+        );
+        assert_eq!(
+            catalogue.get("shop", "cart.empty").unwrap(),
+            "Your cart is empty."
+        );
+        assert_eq!(
+            catalogue.get("shop", "cart.checkout.button").unwrap(),
+            "Checkout"
+        );
+        assert_eq!(
+            catalogue.get("shop", "cart.items").unwrap(),
+            "{0} No items | {one} One item | {other} {?} items"
+        );
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("shop", "apple").unwrap(),
+            "{0} Il n'y a pas de pommes | {one} Il y a {?} pomme | {other} Il y a {?} pommes"
+        );
+        assert_eq!(
+            catalogue.get("shop", "cart.checkout.button").unwrap(),
+            "Commander"
+        );
+        assert_eq!(
+            catalogue.get("shop", "cart.items").unwrap(),
+            "{0} Aucun article | {one} {?} article | {other} {?} articles"
+        );
+
+        let translator = Translator::with_catalogue_bag(bag);
+        assert_eq!(
+            translator
+                .trans("fr", "shop", "cart.items", context!(? = 0))
+                .unwrap(),
+            "Aucun article"
+        );
+        assert_eq!(
+            translator
+                .trans("fr", "shop", "cart.items", context!(? = 1))
+                .unwrap(),
+            "1 article"
+        );
+    }
+
+    #[test]
+    fn parse_domains_test() {
+        let content = "[users]\ntitle = \"Users\"\n";
+
+        let domains = parse(content, "", Layout::LocaleFile).unwrap();
+        assert_eq!(domains[0].0, "users");
+
+        let domains = parse(content, "admin", Layout::LocaleFile).unwrap();
+        assert_eq!(domains[0].0, "admin.users");
+    }
+
+    #[test]
+    fn parse_escaping_test() {
+        let messages = parse(
+            "[apple]\none = \"a | b\"\nother = \"{?} c | d\"\n",
+            "messages",
+            Layout::Flat,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages[0].1 .0,
+            vec![(
+                "apple".to_string(),
+                "{one} a || b | {other} {?} c || d".to_string()
+            )]
+        );
+
+        let mut catalogue =
+            Catalogue::new(Locale::English(EnglishVariant::Default));
+        for (id, message) in &messages[0].1 .0 {
+            catalogue.insert("messages", id, message);
+        }
+
+        let mut bag = CatalogueBag::new();
+        bag.insert(catalogue);
+
+        let translator = Translator::with_catalogue_bag(bag);
+        assert_eq!(
+            translator
+                .trans("en", "messages", "apple", context!(? = 1))
+                .unwrap(),
+            "a | b"
+        );
+        assert_eq!(
+            translator
+                .trans("en", "messages", "apple", context!(? = 2))
+                .unwrap(),
+            "2 c | d"
+        );
+    }
+
+    #[test]
+    fn load_layouts_test() {
+        let bag = load_sync_with(
+            "examples/layouts/file",
+            Configuration::new().with_layout(Layout::LocaleFile),
+        )
+        .unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "user.logout").unwrap(),
+            "Se déconnecter"
+        );
+        assert_eq!(
+            catalogue.get("errors", "not-found").unwrap(),
+            "Page introuvable."
+        );
+
+        let bag = load_sync_with(
+            "examples/layouts/directory",
+            Configuration::new()
+                .with_layout(Layout::LocaleDirectory)
+                .with_recursion(true),
+        )
+        .unwrap();
+
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Hello, {name}!"
+        );
+        assert_eq!(catalogue.get("admin.users", "title").unwrap(), "Users");
+
+        let error = load_sync_with(
+            "examples/layouts/recursive",
+            Configuration::new().with_layout(Layout::LocaleFile),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid filename: locale, expected valid locale code, found `messages.en` in `messages.en.toml`."
+        );
+    }
+
+    #[tokio::test]
+    async fn load_with_test() {
+        let configuration =
+            Configuration::new().with_layout(Layout::LocaleFile);
+
+        assert_eq!(
+            load_with("examples/layouts/file", configuration).await.unwrap(),
+            load_sync_with("examples/layouts/file", configuration).unwrap()
+        );
+    }
+
     macro_rules! test_loading_error {
         ($filename:literal, $error:literal) => {
             let error = load($filename).await.unwrap_err();
@@ -167,7 +428,7 @@ mod test {
     async fn load_error_test() {
         test_loading_error!(
             "examples/translations/invalid/parse",
-            "invalid type: sequence, expected a string for key `foo` at line 1 column 1."
+            "invalid type: map, expected a string for key `foo` at line 1 column 1."
         );
 
         test_loading_error!(
@@ -0,0 +1,972 @@
+use crate::catalogue::Catalogue;
+use crate::catalogue::CatalogueBag;
+use crate::error::Error;
+use crate::loader::error::Error as LoadingError;
+use crate::loader::file::Configuration;
+use crate::locale::Locale;
+
+use quick_xml::escape::escape;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::path::Path;
+
+/// The version of an XLIFF document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Version {
+    V1_2,
+    V2_0,
+}
+
+/// The translation state of a unit.
+///
+/// XLIFF 1.2 states are mapped to the XLIFF 2.0 states, i.e. `new` and `needs-translation` to
+/// `Initial`, `needs-review-translation` and `translated` to `Translated`, `signed-off` to
+/// `Reviewed`, and `final` to `Final`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Initial,
+    Translated,
+    Reviewed,
+    Final,
+}
+
+/// A translation unit, i.e. a `<trans-unit>` in XLIFF 1.2, or a `<unit>` in XLIFF 2.0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    /// The message id, the `resname` ( 1.2 ) or `name` ( 2.0 ) attribute if any, `id` otherwise.
+    pub id: String,
+    pub source: String,
+    /// The translated text, `None` for untranslated units.
+    pub target: Option<String>,
+    pub notes: Vec<String>,
+    /// The state of the unit, untranslated units are in the `Initial` state, unless stated
+    /// otherwise.
+    pub state: Option<State>,
+}
+
+/// A `<file>` element, holding the units of a single domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    /// The domain of the units, the `original` ( 1.2 ) or `id` ( 2.0 ) attribute.
+    pub domain: String,
+    pub units: Vec<Unit>,
+}
+
+/// An XLIFF document.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::loader::xliff::read_sync;
+/// use tarjama::loader::xliff::State;
+/// use tarjama::loader::xliff::Version;
+///
+/// let document = read_sync("examples/xliff/messages.fr.xlf").unwrap();
+///
+/// assert_eq!(document.version, Version::V1_2);
+/// assert_eq!(document.source_locale.code(), "en");
+///
+/// let unit = &document.files[0].units[0];
+/// assert_eq!(unit.id, "greeting");
+/// assert_eq!(unit.source, "Hello, {name}!");
+/// assert_eq!(unit.target.as_deref(), Some("Bonjour, {name}!"));
+/// assert_eq!(unit.state, Some(State::Final));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub version: Version,
+    pub source_locale: Locale,
+    pub target_locale: Option<Locale>,
+    pub files: Vec<File>,
+}
+
+impl Document {
+    /// Creates a document from a source catalogue, and its translation.
+    ///
+    /// A unit is created for each message of the source catalogue, sorted by domain and id,
+    /// messages missing from the target catalogue are kept as untranslated units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::catalogue::Catalogue;
+    /// use tarjama::loader::xliff::Document;
+    /// use tarjama::loader::xliff::State;
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::FrenchVariant;
+    ///
+    /// let mut source = Catalogue::new(Locale::English(EnglishVariant::Default));
+    /// source.insert("messages", "greeting", "Hello!");
+    /// source.insert("messages", "farewell", "Goodbye!");
+    ///
+    /// let mut target = Catalogue::new(Locale::French(FrenchVariant::Default));
+    /// target.insert("messages", "greeting", "Bonjour !");
+    ///
+    /// let document = Document::from_catalogues(&source, &target);
+    /// let units = &document.files[0].units;
+    ///
+    /// assert_eq!(units[0].id, "farewell");
+    /// assert_eq!(units[0].target, None);
+    /// assert_eq!(units[0].state, Some(State::Initial));
+    /// assert_eq!(units[1].target.as_deref(), Some("Bonjour !"));
+    /// assert_eq!(units[1].state, Some(State::Translated));
+    /// ```
+    pub fn from_catalogues(source: &Catalogue, target: &Catalogue) -> Self {
+        let mut domains = source.domains();
+        domains.sort();
+
+        let mut files = vec![];
+        for domain in domains {
+            let messages = source.get_all(domain).unwrap();
+            let mut ids: Vec<&String> = messages.keys().collect();
+            ids.sort();
+
+            let units = ids
+                .into_iter()
+                .map(|id| {
+                    let target = target.get(domain, id).cloned();
+                    let state = if target.is_some() {
+                        State::Translated
+                    } else {
+                        State::Initial
+                    };
+
+                    Unit {
+                        id: id.clone(),
+                        source: messages[id].as_str().to_string(),
+                        target,
+                        notes: vec![],
+                        state: Some(state),
+                    }
+                })
+                .collect();
+
+            files.push(File { domain: domain.clone(), units });
+        }
+
+        Document {
+            version: Version::V1_2,
+            source_locale: *source.locale(),
+            target_locale: Some(*target.locale()),
+            files,
+        }
+    }
+
+    /// Returns a catalogue of the source text of all units.
+    pub fn source_catalogue(&self) -> Catalogue {
+        let mut catalogue = Catalogue::new(self.source_locale);
+        for file in &self.files {
+            for unit in &file.units {
+                catalogue.insert(&file.domain, &unit.id, &unit.source);
+            }
+        }
+
+        catalogue
+    }
+
+    /// Returns a catalogue of the target text of all translated units, or `None` if the document
+    /// has no target locale.
+    pub fn target_catalogue(&self) -> Option<Catalogue> {
+        let mut catalogue = Catalogue::new(self.target_locale?);
+        for file in &self.files {
+            for unit in &file.units {
+                if let Some(target) = &unit.target {
+                    catalogue.insert(&file.domain, &unit.id, target);
+                }
+            }
+        }
+
+        Some(catalogue)
+    }
+
+    /// Serializes the document using the given XLIFF version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::loader::xliff::read_sync;
+    /// use tarjama::loader::xliff::Version;
+    ///
+    /// let document = read_sync("examples/xliff/messages.fr.xlf").unwrap();
+    /// let xliff = document.to_xliff(Version::V2_0);
+    ///
+    /// assert!(xliff.contains(r#"<xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0" srcLang="en" trgLang="fr">"#));
+    /// ```
+    pub fn to_xliff(&self, version: Version) -> String {
+        let source_locale = tag(&self.source_locale);
+        let target_locale = self.target_locale.as_ref().map(tag);
+
+        let mut xml =
+            String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        match version {
+            Version::V1_2 => {
+                xml.push_str("<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+                for file in &self.files {
+                    xml.push_str(&format!(
+                        "  <file source-language=\"{source_locale}\"",
+                    ));
+                    if let Some(target_locale) = &target_locale {
+                        xml.push_str(&format!(
+                            " target-language=\"{target_locale}\""
+                        ));
+                    }
+                    xml.push_str(&format!(
+                        " datatype=\"plaintext\" original=\"{}\">\n    <body>\n",
+                        escape(&file.domain)
+                    ));
+
+                    for (index, unit) in file.units.iter().enumerate() {
+                        xml.push_str(&format!(
+                            "      <trans-unit id=\"{}\" resname=\"{}\">\n",
+                            index + 1,
+                            escape(&unit.id)
+                        ));
+                        xml.push_str(&format!(
+                            "        <source>{}</source>\n",
+                            escape(&unit.source)
+                        ));
+                        if let Some(target) = &unit.target {
+                            xml.push_str("        <target");
+                            if let Some(state) = unit.state {
+                                xml.push_str(&format!(
+                                    " state=\"{}\"",
+                                    state.as_str(version)
+                                ));
+                            }
+                            xml.push_str(&format!(
+                                ">{}</target>\n",
+                                escape(target)
+                            ));
+                        }
+                        for note in &unit.notes {
+                            xml.push_str(&format!(
+                                "        <note>{}</note>\n",
+                                escape(note)
+                            ));
+                        }
+                        xml.push_str("      </trans-unit>\n");
+                    }
+
+                    xml.push_str("    </body>\n  </file>\n");
+                }
+            }
+            Version::V2_0 => {
+                xml.push_str(&format!(
+                    "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{source_locale}\"",
+                ));
+                if let Some(target_locale) = &target_locale {
+                    xml.push_str(&format!(" trgLang=\"{target_locale}\""));
+                }
+                xml.push_str(">\n");
+
+                for file in &self.files {
+                    xml.push_str(&format!(
+                        "  <file id=\"{}\">\n",
+                        escape(&file.domain)
+                    ));
+
+                    for (index, unit) in file.units.iter().enumerate() {
+                        xml.push_str(&format!(
+                            "    <unit id=\"{}\" name=\"{}\">\n",
+                            index + 1,
+                            escape(&unit.id)
+                        ));
+                        if !unit.notes.is_empty() {
+                            xml.push_str("      <notes>\n");
+                            for note in &unit.notes {
+                                xml.push_str(&format!(
+                                    "        <note>{}</note>\n",
+                                    escape(note)
+                                ));
+                            }
+                            xml.push_str("      </notes>\n");
+                        }
+                        xml.push_str("      <segment");
+                        if let Some(state) = unit.state {
+                            xml.push_str(&format!(
+                                " state=\"{}\"",
+                                state.as_str(version)
+                            ));
+                        }
+                        xml.push_str(&format!(
+                            ">\n        <source>{}</source>\n",
+                            escape(&unit.source)
+                        ));
+                        if let Some(target) = &unit.target {
+                            xml.push_str(&format!(
+                                "        <target>{}</target>\n",
+                                escape(target)
+                            ));
+                        }
+                        xml.push_str("      </segment>\n    </unit>\n");
+                    }
+
+                    xml.push_str("  </file>\n");
+                }
+            }
+        }
+
+        xml.push_str("</xliff>\n");
+
+        xml
+    }
+}
+
+impl State {
+    fn parse(value: &str) -> Option<State> {
+        match value {
+            "initial" | "new" | "needs-translation" | "needs-adaptation"
+            | "needs-l10n" => Some(State::Initial),
+            "translated"
+            | "needs-review-translation"
+            | "needs-review-adaptation"
+            | "needs-review-l10n" => Some(State::Translated),
+            "reviewed" | "signed-off" => Some(State::Reviewed),
+            "final" => Some(State::Final),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self, version: Version) -> &'static str {
+        match (self, version) {
+            (State::Initial, Version::V1_2) => "new",
+            (State::Initial, Version::V2_0) => "initial",
+            (State::Translated, _) => "translated",
+            (State::Reviewed, Version::V1_2) => "signed-off",
+            (State::Reviewed, Version::V2_0) => "reviewed",
+            (State::Final, _) => "final",
+        }
+    }
+}
+
+/// Load a catalogue bag from a directory containing XLIFF 1.2 or 2.0 `.xlf` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.xlf`.
+///
+/// The target text of each unit is inserted using the unit `resname` ( 1.2 ) or `name` ( 2.0 )
+/// attribute as id, falling back to the unit `id` attribute, untranslated units are skipped.
+///
+/// The units of each `<file>` element are inserted into its domain, i.e. its `original` ( 1.2 )
+/// or `id` ( 2.0 ) attribute, falling back to the domain of the filename, so that documents
+/// produced by [`export`] can be loaded back.
+///
+/// Use [`read`] to access the source text, notes and state of each unit.
+///
+/// See [`load_with`] to load files using a different layout.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::xliff::load;
+///
+/// let catalogue_bag = load("examples/xliff").await.expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    load_with(directory, Configuration::default()).await
+}
+
+/// Load a catalogue bag from a directory containing XLIFF 1.2 or 2.0 `.xlf` files, using the given
+/// configuration.
+///
+/// With the [`Layout::LocaleFile`](crate::loader::file::Layout::LocaleFile) layout, files should be
+/// named `{locale}.xlf`, and the units of `<file>` elements without a domain are inserted into the
+/// domain of their subdirectory, i.e. the empty domain at the top-level.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::xliff::load_with;
+///
+/// let configuration = Configuration::new().with_recursion(true);
+/// let catalogue_bag = load_with("examples/xliff", configuration)
+///     .await
+///     .expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use crate::loader::file::iterate_with;
+    use futures_util::future::join_all;
+
+    let data =
+        iterate_with(directory, &["xlf".to_string()], configuration).await?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            let documents = join_all(message_files.iter().map(read)).await;
+
+            for document in documents {
+                insert(&mut catalogue, &domain, document?);
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Synchronously load a catalogue bag from a directory containing XLIFF 1.2 or 2.0 `.xlf` files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.xlf`.
+pub fn load_sync<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    load_sync_with(directory, Configuration::default())
+}
+
+/// Synchronously load a catalogue bag from a directory containing XLIFF 1.2 or 2.0 `.xlf` files,
+/// using the given configuration.
+///
+/// See [`load_with`].
+pub fn load_sync_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    use crate::loader::file::iterate_with_sync;
+
+    let data =
+        iterate_with_sync(directory, &["xlf".to_string()], configuration)?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            for path in message_files {
+                insert(&mut catalogue, &domain, read_sync(path)?);
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Read an XLIFF 1.2 or 2.0 document from a file.
+#[cfg(feature = "async")]
+pub async fn read<T>(path: T) -> Result<Document, Error>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref().to_string_lossy().to_string();
+    let content = tokio::fs::read_to_string(&path).await.map_err(|e| {
+        Error::LoadingError(LoadingError::FailedToReadFile(path.clone(), e))
+    })?;
+
+    parse(&content).map_err(|reason| {
+        Error::LoadingError(LoadingError::FailedToParseXliffFile(path, reason))
+    })
+}
+
+/// Synchronously read an XLIFF 1.2 or 2.0 document from a file.
+pub fn read_sync<T>(path: T) -> Result<Document, Error>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref().to_string_lossy().to_string();
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        Error::LoadingError(LoadingError::FailedToReadFile(path.clone(), e))
+    })?;
+
+    parse(&content).map_err(|reason| {
+        Error::LoadingError(LoadingError::FailedToParseXliffFile(path, reason))
+    })
+}
+
+/// Export a source catalogue and its translation as an XLIFF document, including untranslated
+/// messages.
+///
+/// See [`Document::from_catalogues`].
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::catalogue::Catalogue;
+/// use tarjama::loader::xliff::export;
+/// use tarjama::loader::xliff::Version;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+///
+/// let mut source = Catalogue::new(Locale::English(EnglishVariant::Default));
+/// source.insert("messages", "greeting", "Hello!");
+///
+/// let target = Catalogue::new(Locale::French(FrenchVariant::Default));
+///
+/// let xliff = export(&source, &target, Version::V1_2);
+///
+/// assert!(xliff.contains(r#"<trans-unit id="1" resname="greeting">"#));
+/// assert!(xliff.contains("<source>Hello!</source>"));
+/// assert!(!xliff.contains("<target"));
+/// ```
+pub fn export(
+    source: &Catalogue,
+    target: &Catalogue,
+    version: Version,
+) -> String {
+    Document::from_catalogues(source, target).to_xliff(version)
+}
+
+fn insert(catalogue: &mut Catalogue, domain: &str, document: Document) {
+    for file in document.files {
+        let domain =
+            if file.domain.is_empty() { domain } else { &file.domain };
+        for unit in file.units {
+            if let Some(target) = unit.target {
+                catalogue.insert(domain, &unit.id, &target);
+            }
+        }
+    }
+}
+
+/// Returns the BCP 47 tag of a locale, e.g. `en-GB`.
+fn tag(locale: &Locale) -> String {
+    locale.code().replace('_', "-")
+}
+
+/// The element whose text is being collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Source,
+    Target,
+    Note,
+}
+
+fn parse(content: &str) -> Result<Document, String> {
+    let mut reader = Reader::from_str(content);
+
+    let mut version = None;
+    let mut source_locale = None;
+    let mut target_locale = None;
+    let mut files = vec![];
+
+    let mut unit: Option<Unit> = None;
+    let mut field: Option<(Field, String)> = None;
+    // the depth of `<alt-trans>` elements, whose sources and targets are ignored.
+    let mut alternatives = 0;
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            format!(
+                "invalid xml at position {}: {}",
+                reader.buffer_position(),
+                e.to_string().to_lowercase()
+            )
+        })?;
+
+        match event {
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                let empty = matches!(event, Event::Empty(_));
+                match element.local_name().as_ref() {
+                    b"xliff" => {
+                        version = match attribute(element, "version")?
+                            .as_deref()
+                        {
+                            Some("1.2") => Some(Version::V1_2),
+                            Some(v) if v.starts_with("2.") => {
+                                Some(Version::V2_0)
+                            }
+                            Some(v) => {
+                                return Err(format!(
+                                    "unsupported xliff version `{v}`"
+                                ))
+                            }
+                            None => {
+                                return Err("missing xliff version".to_string())
+                            }
+                        };
+
+                        source_locale = attribute(element, "srcLang")?;
+                        target_locale = attribute(element, "trgLang")?;
+                    }
+                    b"file" => {
+                        if let Some(locale) =
+                            attribute(element, "source-language")?
+                        {
+                            source_locale = Some(locale);
+                        }
+                        if let Some(locale) =
+                            attribute(element, "target-language")?
+                        {
+                            target_locale = Some(locale);
+                        }
+
+                        let domain = match attribute(element, "original")? {
+                            Some(domain) => Some(domain),
+                            None => attribute(element, "id")?,
+                        };
+
+                        files.push(File {
+                            domain: domain.unwrap_or_default(),
+                            units: vec![],
+                        });
+                    }
+                    b"trans-unit" | b"unit" => {
+                        let id = match attribute(element, "resname")? {
+                            Some(id) => Some(id),
+                            None => match attribute(element, "name")? {
+                                Some(id) => Some(id),
+                                None => attribute(element, "id")?,
+                            },
+                        };
+
+                        unit = Some(Unit {
+                            id: id.ok_or("missing unit id")?,
+                            source: String::new(),
+                            target: None,
+                            notes: vec![],
+                            state: None,
+                        });
+                    }
+                    b"alt-trans" if !empty => alternatives += 1,
+                    b"segment" => {
+                        if let Some(unit) = &mut unit {
+                            if unit.state.is_none() {
+                                unit.state = attribute(element, "state")?
+                                    .as_deref()
+                                    .and_then(State::parse);
+                            }
+                        }
+                    }
+                    name @ (b"source" | b"target" | b"note")
+                        if alternatives == 0 && unit.is_some() =>
+                    {
+                        let kind = match name {
+                            b"source" => Field::Source,
+                            b"target" => Field::Target,
+                            _ => Field::Note,
+                        };
+
+                        if kind == Field::Target {
+                            if let Some(state) = attribute(element, "state")?
+                                .as_deref()
+                                .and_then(State::parse)
+                            {
+                                unit.as_mut().unwrap().state = Some(state);
+                            }
+                        }
+
+                        field = Some((kind, String::new()));
+                        if empty {
+                            close(unit.as_mut(), field.take());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) => {
+                if let Some((_, buffer)) = &mut field {
+                    let text = text.unescape().map_err(|e| {
+                        format!(
+                            "invalid text: {}",
+                            e.to_string().to_lowercase()
+                        )
+                    })?;
+
+                    buffer.push_str(&text);
+                }
+            }
+            Event::CData(data) => {
+                if let Some((_, buffer)) = &mut field {
+                    buffer.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            Event::End(element) => match element.local_name().as_ref() {
+                b"source" | b"target" | b"note" if field.is_some() => {
+                    close(unit.as_mut(), field.take());
+                }
+                b"alt-trans" => alternatives -= 1,
+                b"trans-unit" | b"unit" => {
+                    if let (Some(mut unit), Some(file)) =
+                        (unit.take(), files.last_mut())
+                    {
+                        if unit.target.is_none() && unit.state.is_none() {
+                            unit.state = Some(State::Initial);
+                        }
+
+                        file.units.push(unit);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let version = version.ok_or("missing xliff element")?;
+    let source_locale = source_locale.ok_or("missing source language")?;
+    let source_locale = source_locale
+        .parse()
+        .map_err(|_| format!("invalid source language `{source_locale}`"))?;
+    let target_locale = match target_locale {
+        Some(locale) => Some(
+            locale
+                .parse()
+                .map_err(|_| format!("invalid target language `{locale}`"))?,
+        ),
+        None => None,
+    };
+
+    Ok(Document { version, source_locale, target_locale, files })
+}
+
+/// Stores the collected text of a `<source>`, `<target>` or `<note>` element into its unit.
+fn close(unit: Option<&mut Unit>, field: Option<(Field, String)>) {
+    let (Some(unit), Some((field, text))) = (unit, field) else {
+        return;
+    };
+
+    match field {
+        // XLIFF 2.0 units may contain multiple segments.
+        Field::Source => unit.source.push_str(&text),
+        Field::Target => match &mut unit.target {
+            Some(target) => target.push_str(&text),
+            None => unit.target = Some(text),
+        },
+        Field::Note => unit.notes.push(text),
+    }
+}
+
+fn attribute(
+    element: &BytesStart,
+    name: &str,
+) -> Result<Option<String>, String> {
+    let attribute = element.try_get_attribute(name).map_err(|e| {
+        format!("invalid attribute: {}", e.to_string().to_lowercase())
+    })?;
+
+    match attribute {
+        Some(attribute) => match attribute.unescape_value() {
+            Ok(value) => Ok(Some(value.to_string())),
+            Err(e) => Err(format!(
+                "invalid attribute `{name}`: {}",
+                e.to_string().to_lowercase()
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::catalogue::Catalogue;
+    use crate::loader::file::Configuration;
+    use crate::loader::xliff::export;
+    use crate::loader::xliff::load_sync;
+    use crate::loader::xliff::load_sync_with;
+    use crate::loader::xliff::parse;
+    use crate::loader::xliff::read_sync;
+    use crate::loader::xliff::Document;
+    use crate::loader::xliff::State;
+    use crate::loader::xliff::Version;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::GermanVariant;
+    use crate::locale::Locale;
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn load_test() {
+        use crate::loader::xliff::load;
+
+        let bag = load("examples/xliff").await.unwrap();
+
+        assert_eq!(bag, load_sync("examples/xliff").unwrap());
+    }
+
+    #[test]
+    fn load_sync_with_test() {
+        let bag = load_sync_with(
+            "examples",
+            Configuration::new().with_recursion(true),
+        )
+        .unwrap();
+
+        // the domain of the `<file>` element takes precedence over the filename.
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name}!"
+        );
+        assert_eq!(catalogue.get("xliff.messages", "greeting"), None);
+    }
+
+    #[test]
+    fn load_sync_test() {
+        let bag = load_sync("examples/xliff").unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "apple").unwrap(),
+            "{one} Une pomme | {other} {?} pommes"
+        );
+        assert_eq!(catalogue.get("messages", "farewell"), None);
+
+        let catalogue = &bag.get(&Locale::German(GermanVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Hallo, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "terms").unwrap(),
+            "Mit der Anmeldung akzeptieren Sie die <b>Bedingungen</b>."
+        );
+        assert_eq!(catalogue.get("messages", "farewell"), None);
+    }
+
+    #[test]
+    fn documents() {
+        let document = read_sync("examples/xliff/messages.de.xlf").unwrap();
+
+        assert_eq!(document.version, Version::V2_0);
+        assert_eq!(
+            document.source_locale,
+            Locale::English(EnglishVariant::Default)
+        );
+        assert_eq!(
+            document.target_locale,
+            Some(Locale::German(GermanVariant::Default))
+        );
+
+        let units = &document.files[0].units;
+        assert_eq!(document.files[0].domain, "messages");
+        assert_eq!(units.len(), 3);
+        assert_eq!(units[0].id, "greeting");
+        assert_eq!(units[0].notes, vec!["Shown on the home page."]);
+        assert_eq!(units[0].state, Some(State::Reviewed));
+        assert_eq!(units[1].id, "terms");
+        assert_eq!(
+            units[1].source,
+            "By signing in, you accept the <b>terms</b>."
+        );
+        assert_eq!(units[1].state, Some(State::Translated));
+        assert_eq!(units[2].id, "farewell");
+        assert_eq!(units[2].target, None);
+        assert_eq!(units[2].state, Some(State::Initial));
+
+        let source = document.source_catalogue();
+        assert_eq!(source.get("messages", "farewell").unwrap(), "Goodbye!");
+
+        let document = read_sync("examples/xliff/messages.fr.xlf").unwrap();
+        let units = &document.files[0].units;
+        assert_eq!(units[1].id, "apple");
+        assert_eq!(units[1].notes, vec!["Plural message.", "Keep it short."]);
+        assert_eq!(units[2].id, "farewell");
+        assert_eq!(units[2].target, None);
+        assert_eq!(units[2].state, Some(State::Initial));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut source =
+            Catalogue::new(Locale::English(EnglishVariant::Default));
+        source.insert("messages", "greeting", "Hello & <welcome>, {name}!");
+        source.insert("messages", "farewell", "Goodbye!");
+        source.insert("errors", "not-found", "\"{page}\" was not found.");
+
+        let mut target = Catalogue::new(Locale::French(FrenchVariant::Canada));
+        target.insert(
+            "messages",
+            "greeting",
+            "Bonjour & <bienvenue>, {name} !",
+        );
+        target.insert("errors", "not-found", "« {page} » est introuvable.");
+
+        let document = Document::from_catalogues(&source, &target);
+        for version in [Version::V1_2, Version::V2_0] {
+            let parsed = parse(&document.to_xliff(version)).unwrap();
+
+            assert_eq!(parsed.version, version);
+            assert_eq!(parsed.files, document.files);
+            assert_eq!(parsed.source_catalogue(), source);
+            assert_eq!(parsed.target_catalogue().unwrap(), target);
+        }
+    }
+
+    #[test]
+    fn export_load_round_trip() {
+        use std::fs;
+
+        let mut source =
+            Catalogue::new(Locale::English(EnglishVariant::Default));
+        source.insert("messages", "greeting", "Hello, {name}!");
+        source.insert("errors", "not-found", "Page not found.");
+
+        let mut target = Catalogue::new(Locale::French(FrenchVariant::Canada));
+        target.insert("messages", "greeting", "Bonjour, {name} !");
+        target.insert("errors", "not-found", "Page introuvable.");
+
+        for version in [Version::V1_2, Version::V2_0] {
+            let directory = std::env::temp_dir().join(format!(
+                "tarjama-xliff-{}-{version:?}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&directory).unwrap();
+            fs::write(
+                directory.join("messages.fr_CA.xlf"),
+                export(&source, &target, version),
+            )
+            .unwrap();
+
+            let bag = load_sync(&directory).unwrap();
+            fs::remove_dir_all(&directory).unwrap();
+
+            assert_eq!(
+                bag.get(&Locale::French(FrenchVariant::Canada)),
+                [target.clone()]
+            );
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("<xliff version=\"3.0\"></xliff>").unwrap_err(),
+            "unsupported xliff version `3.0`"
+        );
+        assert_eq!(
+            parse("<xliff version=\"2.0\"></xliff>").unwrap_err(),
+            "missing source language"
+        );
+        assert_eq!(
+            parse("<xliff version=\"2.0\" srcLang=\"xx\"></xliff>")
+                .unwrap_err(),
+            "invalid source language `xx`"
+        );
+        assert_eq!(
+            parse("<xliff version=\"2.0\" srcLang=\"en\"><file id=\"messages\"><unit></unit></file></xliff>").unwrap_err(),
+            "missing unit id"
+        );
+        assert!(parse("<xliff version=\"1.2\"><file></xliff>")
+            .unwrap_err()
+            .starts_with("invalid xml at position"));
+    }
+}
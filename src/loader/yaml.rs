@@ -0,0 +1,258 @@
+use crate::catalogue::Catalogue;
+use crate::catalogue::CatalogueBag;
+use crate::error::Error;
+use crate::loader::error::Error as LoadingError;
+use crate::loader::file;
+use crate::loader::file::Configuration;
+use crate::loader::file::Layout;
+use crate::loader::messages::Domains;
+use crate::loader::messages::Messages;
+
+use std::path::Path;
+
+/// Load a catalogue bag from a directory containing yaml files.
+///
+/// Files within the directory should be named in the following format:  `{domain}.{locale}.yaml`,
+/// or `{domain}.{locale}.yml`.
+///
+/// Each file contains a mapping of messages, messages can be grouped into nested mappings, in
+/// which case their ids are joined using `.`, e.g. `user: { greeting: Hello! }` defines the
+/// `user.greeting` message.
+///
+/// See [`load_with`] to load files using a different layout.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::yaml::load;
+///
+/// let catalogue_bag = load("examples/yaml").await.expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    load_with(directory, Configuration::default()).await
+}
+
+/// Load a catalogue bag from a directory containing yaml files, using the given configuration.
+///
+/// With the [`Layout::LocaleFile`] layout, files should be named `{locale}.yaml`, and
+/// contain a top-level mapping per domain.
+///
+/// # Examples
+///
+/// ```
+/// # async fn doc() {
+/// use tarjama::loader::file::Configuration;
+/// use tarjama::loader::file::Layout;
+/// use tarjama::loader::yaml::load_with;
+///
+/// let configuration = Configuration::new().with_layout(Layout::LocaleDirectory);
+/// let catalogue_bag = load_with("translations", configuration)
+///     .await
+///     .expect("Failed to load catalogue bag");
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn load_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path> + 'static,
+{
+    use crate::loader::file::iterate_with;
+    use futures_util::future::join_all;
+    use tokio::fs;
+
+    let data = iterate_with(
+        directory,
+        &["yaml".to_string(), "yml".to_string()],
+        configuration,
+    )
+    .await?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            let domain = &domain;
+            let message_groups =
+                join_all(message_files.iter().map(|path| async move {
+                    fs::read_to_string(path)
+                        .await
+                        .map_err(|e| -> Error {
+                            Error::LoadingError(
+                                LoadingError::FailedToReadFile(
+                                    path.to_string(),
+                                    e,
+                                ),
+                            )
+                        })
+                        .and_then(|content| {
+                            parse(&content, domain, configuration.layout())
+                        })
+                }))
+                .await;
+
+            for domains in message_groups {
+                for (domain, messages) in domains? {
+                    for (id, message) in messages.0 {
+                        catalogue.insert(&domain, &id, &message);
+                    }
+                }
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Synchronously load a catalogue bag from a directory containing yaml files.
+///
+/// Files within the directory should be named in the following format: `{domain}.{locale}.yaml`,
+/// or `{domain}.{locale}.yml`.
+pub fn load_sync<T>(directory: T) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    load_sync_with(directory, Configuration::default())
+}
+
+/// Synchronously load a catalogue bag from a directory containing yaml files, using the given
+/// configuration.
+///
+/// See [`load_with`].
+pub fn load_sync_with<T>(
+    directory: T,
+    configuration: Configuration,
+) -> Result<CatalogueBag, Error>
+where
+    T: AsRef<Path>,
+{
+    use crate::loader::file::iterate_with_sync;
+    use std::fs;
+
+    let data = iterate_with_sync(
+        directory,
+        &["yaml".to_string(), "yml".to_string()],
+        configuration,
+    )?;
+
+    let mut bag = CatalogueBag::new();
+    for (locale, domain_files) in data {
+        let mut catalogue = Catalogue::new(locale);
+        for (domain, message_files) in domain_files {
+            for path in message_files {
+                let content = fs::read_to_string(&path).map_err(|e| {
+                    Error::LoadingError(LoadingError::FailedToReadFile(
+                        path.clone(),
+                        e,
+                    ))
+                })?;
+
+                for (domain, messages) in
+                    parse(&content, &domain, configuration.layout())?
+                {
+                    for (id, message) in messages.0 {
+                        catalogue.insert(&domain, &id, &message);
+                    }
+                }
+            }
+        }
+
+        bag.insert(catalogue);
+    }
+
+    Ok(bag)
+}
+
+/// Returns the messages of a file, grouped by domain.
+fn parse(
+    content: &str,
+    domain: &str,
+    layout: Layout,
+) -> Result<Vec<(String, Messages)>, Error> {
+    let result = match layout {
+        Layout::LocaleFile => {
+            serde_yaml::from_str::<Domains>(content).map(|domains| {
+                domains
+                    .0
+                    .into_iter()
+                    .map(|(name, messages)| {
+                        (file::qualify(domain, name), messages)
+                    })
+                    .collect()
+            })
+        }
+        _ => serde_yaml::from_str::<Messages>(content)
+            .map(|messages| vec![(domain.to_string(), messages)]),
+    };
+
+    result.map_err(|e| {
+        Error::LoadingError(LoadingError::FailedToParseFile(e.into()))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::loader::yaml::load_sync;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::Locale;
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn load_test() {
+        use crate::loader::yaml::load;
+
+        let bag = load("examples/yaml").await.unwrap();
+
+        assert_eq!(bag, load_sync("examples/yaml").unwrap());
+    }
+
+    #[test]
+    fn load_sync_test() {
+        let bag = load_sync("examples/yaml").unwrap();
+
+        let catalogue = &bag.get(&Locale::French(FrenchVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Bonjour, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "user.profile.title").unwrap(),
+            "Profil de {name}"
+        );
+
+        let catalogue = &bag.get(&Locale::English(EnglishVariant::Default))[0];
+        assert_eq!(
+            catalogue.get("messages", "greeting").unwrap(),
+            "Hello, {name}!"
+        );
+        assert_eq!(
+            catalogue.get("messages", "user.profile.title").unwrap(),
+            "{name}'s profile"
+        );
+        assert_eq!(
+            catalogue.get("messages", "user.logout").unwrap(),
+            "Log out"
+        );
+    }
+
+    #[test]
+    fn load_error_test() {
+        let error = load_sync("examples/yaml/invalid").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "enabled: invalid type: boolean `true`, expected a message, a list of messages, or a map of messages at line 2 column 10."
+        );
+    }
+}
@@ -0,0 +1,84 @@
+/// The part of a locale tag that failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    Language,
+    Script,
+    Region,
+    Variant,
+    Extension,
+}
+
+impl ::std::fmt::Display for Part {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Part::Language => write!(f, "language"),
+            Part::Script => write!(f, "script"),
+            Part::Region => write!(f, "region"),
+            Part::Variant => write!(f, "variant"),
+            Part::Extension => write!(f, "extension"),
+        }
+    }
+}
+
+/// An error returned when parsing a `Locale` or a `LanguageTag` fails.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::error::Part;
+/// use tarjama::locale::Locale;
+///
+/// let error = "en-U$".parse::<Locale>().unwrap_err();
+///
+/// assert_eq!(error.input(), "en-U$");
+/// assert_eq!(error.part(), Part::Region);
+/// assert_eq!(error.subtag(), "U$");
+///
+/// let error = "xx-GB".parse::<Locale>().unwrap_err();
+///
+/// assert_eq!(error.part(), Part::Language);
+/// assert_eq!(error.subtag(), "xx");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    input: String,
+    part: Part,
+    subtag: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(input: &str, part: Part, subtag: &str) -> Self {
+        ParseError {
+            input: input.to_string(),
+            part,
+            subtag: subtag.to_string(),
+        }
+    }
+
+    /// Returns the string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the part of the tag that is invalid.
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    /// Returns the invalid subtag, as found in the input.
+    pub fn subtag(&self) -> &str {
+        &self.subtag
+    }
+}
+
+impl ::std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(
+            f,
+            "locale: invalid {} subtag `'{}'` in `'{}'`.",
+            self.part, self.subtag, self.input
+        )
+    }
+}
+
+impl ::std::error::Error for ParseError {}
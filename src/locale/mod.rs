@@ -0,0 +1,3271 @@
+pub mod error;
+
+mod data;
+mod parents;
+
+use crate::error::Error;
+use crate::locale::error::ParseError;
+use crate::locale::error::Part;
+use crate::tag::LanguageTag;
+
+use core::result::Result as CoreResult;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    Afar,
+    Abkhazian,
+    Afrikaans,
+    Akan,
+    Albanian,
+    Amharic,
+    Arabic(ArabicVariant),
+    Aragonese,
+    Armenian,
+    Assamese,
+    Avaric,
+    Avestan,
+    Aymara,
+    Azerbaijani,
+    Bashkir,
+    Bambara,
+    Basque,
+    Belarusian,
+    Bengali,
+    Bihari,
+    Bislama,
+    Tibetan,
+    Bosnian,
+    Breton,
+    Bulgarian,
+    Burmese,
+    Catalan,
+    Czech,
+    Chamorro,
+    Chechen,
+    Chinese(ChineseVariant),
+    ChurchSlavic,
+    Chuvash,
+    Cornish,
+    Corsican,
+    Cree,
+    Welsh,
+    Danish,
+    German(GermanVariant),
+    Divehi,
+    Dutch(DutchVariant),
+    Dzongkha,
+    Greek,
+    English(EnglishVariant),
+    Esperanto,
+    Estonian,
+    Ewe,
+    Faroese,
+    Persian,
+    Fijian,
+    Finnish,
+    French(FrenchVariant),
+    WesternFrisian,
+    Fulah,
+    Georgian,
+    Gaelic,
+    Irish,
+    Galician,
+    Manx,
+    Guarani,
+    Gujarati,
+    Haitian,
+    Hausa,
+    Hebrew,
+    Herero,
+    Hindi,
+    HiriMotu,
+    Croatian,
+    Hungarian,
+    Igbo,
+    Icelandic,
+    Ido,
+    SichuanYi,
+    Inuktitut,
+    Interlingue,
+    Indonesian,
+    Inupiaq,
+    Italian(ItalianVariant),
+    Javanese,
+    Japanese,
+    Kalaallisut,
+    Kannada,
+    Kashmiri,
+    Kanuri,
+    Kazakh,
+    CentralKhmer,
+    Kikuyu,
+    Kinyarwanda,
+    Kirghiz,
+    Komi,
+    Kongo,
+    Korean,
+    Kuanyama,
+    Kurdish,
+    Lao,
+    Latin,
+    Latvian,
+    Limburgan,
+    Lingala,
+    Lithuanian,
+    Luxembourgish,
+    LubaKatanga,
+    Ganda,
+    Macedonian,
+    Marshallese,
+    Malayalam,
+    Maori,
+    Marathi,
+    Malay,
+    Malagasy,
+    Maltese,
+    Mongolian,
+    Nauru,
+    Navajo,
+    SouthernNdebele,
+    NorthernNdebele,
+    Ndonga,
+    Nepali,
+    NorwegianNynorsk,
+    Norwegian,
+    Chichewa,
+    Occitan,
+    Ojibwa,
+    Oriya,
+    Oromo,
+    Ossetian,
+    Panjabi,
+    Pali,
+    Polish,
+    Portuguese(PortugueseVariant),
+    Pushto,
+    Quechua,
+    Romansh,
+    Romanian(RomanianVariant),
+    Rundi,
+    Russian(RussianVariant),
+    Sango,
+    Sanskrit,
+    Sinhala,
+    Slovak,
+    Slovenian,
+    NorthernSami,
+    Samoan,
+    Shona,
+    Sindhi,
+    Somali,
+    SouthernSotho,
+    Spanish(SpanishVariant),
+    Sardinian,
+    Serbian,
+    Swati,
+    Sundanese,
+    Swahili,
+    Swedish(SwedishVariant),
+    Tahitian,
+    Tamil,
+    Tatar,
+    Telugu,
+    Tajik,
+    Tagalog,
+    Thai,
+    Tigrinya,
+    Tonga,
+    Tswana,
+    Tsonga,
+    Turkmen,
+    Turkish,
+    Twi,
+    Uighur,
+    Ukrainian,
+    Urdu,
+    Uzbek,
+    Venda,
+    Vietnamese,
+    Walloon,
+    Wolof,
+    Xhosa,
+    Yiddish,
+    Yoruba,
+    Zhuang,
+    Zulu,
+    /// A locale of one of the languages above, identified by a language tag that has no dedicated
+    /// variant, e.g. `ja_JP`, `sr_Latn` or `zh_Hant_TW`.
+    ///
+    /// Use `Locale::try_from` to create a `Tag` locale, which makes sure the language is known, and
+    /// that tags with a dedicated variant, e.g. `en_GB`, are not represented as a `Tag`.
+    Tag(LanguageTag),
+}
+
+impl Locale {
+    /// Determine if the Locale has a specific variant (non-default)
+    pub fn has_variant(&self) -> bool {
+        match self {
+            Locale::Arabic(variant) => *variant != ArabicVariant::Default,
+            Locale::Chinese(variant) => *variant != ChineseVariant::Default,
+            Locale::German(variant) => *variant != GermanVariant::Default,
+            Locale::Dutch(variant) => *variant != DutchVariant::Default,
+            Locale::English(variant) => *variant != EnglishVariant::Default,
+            Locale::French(variant) => *variant != FrenchVariant::Default,
+            Locale::Italian(variant) => *variant != ItalianVariant::Default,
+            Locale::Portuguese(variant) => {
+                *variant != PortugueseVariant::Default
+            }
+            Locale::Romanian(variant) => *variant != RomanianVariant::Default,
+            Locale::Russian(variant) => *variant != RussianVariant::Default,
+            Locale::Spanish(variant) => *variant != SpanishVariant::Default,
+            Locale::Swedish(variant) => *variant != SwedishVariant::Default,
+            Locale::Tag(tag) => tag.as_str() != tag.language(),
+            _ => false,
+        }
+    }
+
+    /// Return the parent locale, according to the CLDR parent locales data.
+    ///
+    /// The parent of a locale is the locale without its last subtag, unless CLDR defines another
+    /// parent, e.g. `es_MX → es_419 → es`, and `en_AU → en_001 → en`.
+    ///
+    /// A locale using a script other than the default script of its language falls back to that
+    /// script, and then to the root locale, so scripts are never mixed, e.g. `zh_TW → zh_Hant`,
+    /// and `sr_Latn_RS → sr_Latn`.
+    ///
+    /// The root locale is represented by `None`, i.e. a locale without a variant has no parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ChineseVariant;
+    /// use tarjama::locale::EnglishVariant;
+    /// use tarjama::locale::SpanishVariant;
+    ///
+    /// let locale = Locale::Spanish(SpanishVariant::Mexico);
+    /// assert_eq!(locale.parent(), Some(Locale::Spanish(SpanishVariant::LatinAmerica)));
+    /// assert_eq!(
+    ///     Locale::Spanish(SpanishVariant::LatinAmerica).parent(),
+    ///     Some(Locale::Spanish(SpanishVariant::Default))
+    /// );
+    /// assert_eq!(Locale::Spanish(SpanishVariant::Default).parent(), None);
+    ///
+    /// let locale = Locale::English(EnglishVariant::Australia);
+    /// assert_eq!(locale.parent(), Some(Locale::English(EnglishVariant::World)));
+    ///
+    /// let locale = Locale::English(EnglishVariant::UnitedStates);
+    /// assert_eq!(locale.parent(), Some(Locale::English(EnglishVariant::Default)));
+    ///
+    /// let locale = Locale::Chinese(ChineseVariant::Taiwan);
+    /// assert_eq!(locale.parent(), "zh-Hant".parse().ok());
+    /// assert_eq!(locale.parent().unwrap().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<Locale> {
+        let tag = LanguageTag::from(self);
+        if tag.extensions().next().is_some() {
+            return tag.parent().and_then(Locale::from_cldr_tag);
+        }
+
+        let language_script = data::language(tag.language()).script;
+        let script = self.default_script();
+
+        // the script implied by the region, e.g. `zh_TW` is `zh_Hant_TW`.
+        let tag = match (tag.script(), tag.region()) {
+            (None, Some(region)) if script != language_script => {
+                LanguageTag::try_from(format!(
+                    "{}_{script}_{region}",
+                    tag.language()
+                ))
+                .ok()?
+            }
+            _ => tag,
+        };
+
+        if let Some(parent) = parents::parent(tag.as_str()) {
+            return parent.parse().ok().and_then(Locale::from_cldr_tag);
+        }
+
+        let parent = tag.parent()?;
+        if script != language_script && parent.script().is_none() {
+            return None;
+        }
+
+        Locale::from_cldr_tag(parent)
+    }
+
+    /// Return the locale of a CLDR locale tag, omitting its script when it is implied, e.g. the
+    /// locale of `zh_Hant_HK` is `zh_HK`.
+    fn from_cldr_tag(tag: LanguageTag) -> Option<Locale> {
+        if let (Some(script), Some(region)) = (tag.script(), tag.region()) {
+            let implied =
+                Locale::from_code(&format!("{}_{region}", tag.language()))
+                    .filter(|locale| locale.default_script() == script);
+
+            if implied.is_some() {
+                return implied;
+            }
+        }
+
+        tag.try_into().ok()
+    }
+
+    /// Return the locale with its default variant, if applicable
+    pub fn with_default_variant(self) -> Self {
+        match self {
+            Locale::Arabic(_) => Locale::Arabic(ArabicVariant::Default),
+            Locale::Chinese(_) => Locale::Chinese(ChineseVariant::Default),
+            Locale::German(_) => Locale::German(GermanVariant::Default),
+            Locale::Dutch(_) => Locale::Dutch(DutchVariant::Default),
+            Locale::English(_) => Locale::English(EnglishVariant::Default),
+            Locale::French(_) => Locale::French(FrenchVariant::Default),
+            Locale::Italian(_) => Locale::Italian(ItalianVariant::Default),
+            Locale::Portuguese(_) => {
+                Locale::Portuguese(PortugueseVariant::Default)
+            }
+            Locale::Romanian(_) => Locale::Romanian(RomanianVariant::Default),
+            Locale::Russian(_) => Locale::Russian(RussianVariant::Default),
+            Locale::Spanish(_) => Locale::Spanish(SpanishVariant::Default),
+            Locale::Swedish(_) => Locale::Swedish(SwedishVariant::Default),
+            Locale::Tag(tag) => {
+                Locale::from_code(tag.language()).unwrap_or(self)
+            }
+            _ => self,
+        }
+    }
+
+    /// Return the value of a unicode extension keyword of the locale, if any.
+    ///
+    /// See [`LanguageTag::extension`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    ///
+    /// let locale: Locale = "ar-u-nu-latn".try_into().unwrap();
+    ///
+    /// assert_eq!(locale.extension("nu"), Some("latn"));
+    /// assert_eq!(locale.extension("hc"), None);
+    /// assert_eq!(locale.parent(), Some(Locale::Arabic(ArabicVariant::Default)));
+    ///
+    /// assert_eq!(Locale::Arabic(ArabicVariant::Default).extension("nu"), None);
+    /// ```
+    pub fn extension(&self, key: &str) -> Option<&str> {
+        match self {
+            Locale::Tag(tag) => tag.extension(key),
+            _ => None,
+        }
+    }
+
+    /// Return an iterator over the unicode extension keywords of the locale, as `(key, value)`
+    /// pairs, sorted by key.
+    pub fn extensions(&self) -> impl Iterator<Item = (&str, &str)> {
+        match self {
+            Locale::Tag(tag) => Some(tag.extensions()),
+            _ => None,
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// Return the locale without its unicode extension keywords.
+    ///
+    /// See [`LanguageTag::without_extensions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::PortugueseVariant;
+    ///
+    /// let locale: Locale = "pt-BR-u-nu-latn".try_into().unwrap();
+    ///
+    /// assert_eq!(
+    ///     locale.without_extensions(),
+    ///     Locale::Portuguese(PortugueseVariant::Brazil)
+    /// );
+    /// ```
+    pub fn without_extensions(&self) -> Locale {
+        match self {
+            Locale::Tag(tag) => {
+                Locale::from_tag(tag.without_extensions()).unwrap_or(*self)
+            }
+            _ => *self,
+        }
+    }
+
+    /// Return the locale with the given unicode extension keyword, replacing the previous value
+    /// of the key if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// let locale = Locale::English(EnglishVariant::UnitedStates)
+    ///     .with_extension("hc", "h23")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(locale.to_string(), "en_US_u_hc_h23");
+    /// assert_eq!(locale.with_default_variant(), Locale::English(EnglishVariant::Default));
+    /// ```
+    pub fn with_extension(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CoreResult<Locale, Error> {
+        LanguageTag::from(self).with_extension(key, value)?.try_into()
+    }
+}
+
+/// Serialize a `Locale` as its code, e.g. `en_GB`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> CoreResult<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// Deserialize a `Locale` from its code, e.g. `en_GB` or `en-GB`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// let locale: Locale = serde_json::from_str("\"en-GB\"").unwrap();
+///
+/// assert_eq!(locale, Locale::English(EnglishVariant::UnitedKingdom));
+/// assert_eq!(serde_json::to_string(&locale).unwrap(), "\"en_GB\"");
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> CoreResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+
+        Locale::try_from(code).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<&Locale> for Locale {
+    fn from(value: &Locale) -> Self {
+        *value
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = Error;
+
+    fn try_from(value: String) -> CoreResult<Self, Self::Error> {
+        let locale = &*value;
+
+        locale.try_into()
+    }
+}
+
+/// Create a `Locale` from a string reference.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ArabicVariant;
+/// use tarjama::locale::ChineseVariant;
+/// use tarjama::locale::GermanVariant;
+/// use tarjama::locale::DutchVariant;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+/// use tarjama::locale::ItalianVariant;
+/// use tarjama::locale::PortugueseVariant;
+/// use tarjama::locale::RomanianVariant;
+/// use tarjama::locale::RussianVariant;
+/// use tarjama::locale::SpanishVariant;
+/// use tarjama::locale::SwedishVariant;
+///
+/// let locale: Locale = "ar".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar");
+///
+/// let locale: Locale = "ar_DZ".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_DZ");
+///
+/// let locale: Locale = "ar_BH".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_BH");
+///
+/// let locale: Locale = "ar_EG".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_EG");
+///
+/// let locale: Locale = "ar_IQ".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_IQ");
+///
+/// let locale: Locale = "ar_JO".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_JO");
+///
+/// let locale: Locale = "ar_KW".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_KW");
+///
+/// let locale: Locale = "ar_LB".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_LB");
+///
+/// let locale: Locale = "ar_LY".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_LY");
+///
+/// let locale: Locale = "ar_MA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_MA");
+///
+/// let locale: Locale = "ar_OM".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_OM");
+///
+/// let locale: Locale = "ar_QA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_QA");
+///
+/// let locale: Locale = "ar_SA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_SA");
+///
+/// let locale: Locale = "ar_SY".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_SY");
+///
+/// let locale: Locale = "ar_TN".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_TN");
+///
+/// let locale: Locale = "ar_AE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_AE");
+///
+/// let locale: Locale = "ar_YE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ar_YE");
+///
+/// let locale: Locale = "zh".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh");
+///
+/// let locale: Locale = "zh_HK".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh_HK");
+///
+/// let locale: Locale = "zh_CN".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh_CN");
+///
+/// let locale: Locale = "zh_SG".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh_SG");
+///
+/// let locale: Locale = "zh_TW".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh_TW");
+///
+/// let locale: Locale = "de".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "de");
+///
+/// let locale: Locale = "de_AT".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "de_AT");
+///
+/// let locale: Locale = "de_LI".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "de_LI");
+///
+/// let locale: Locale = "de_LU".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "de_LU");
+///
+/// let locale: Locale = "de_CH".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "de_CH");
+///
+/// let locale: Locale = "nl".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "nl");
+///
+/// let locale: Locale = "nl_BE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "nl_BE");
+///
+/// let locale: Locale = "en".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en");
+///
+/// let locale: Locale = "en_001".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_001");
+///
+/// let locale: Locale = "en_AU".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_AU");
+///
+/// let locale: Locale = "en_BZ".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_BZ");
+///
+/// let locale: Locale = "en_CA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_CA");
+///
+/// let locale: Locale = "en_IE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_IE");
+///
+/// let locale: Locale = "en_JM".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_JM");
+///
+/// let locale: Locale = "en_NZ".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_NZ");
+///
+/// let locale: Locale = "en_ZA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_ZA");
+///
+/// let locale: Locale = "en_TT".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_TT");
+///
+/// let locale: Locale = "en_GB".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_GB");
+///
+/// let locale: Locale = "en_US".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "en_US");
+///
+/// let locale: Locale = "fr".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr");
+///
+/// let locale: Locale = "fr_FR".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr_FR");
+///
+/// let locale: Locale = "fr_BE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr_BE");
+///
+/// let locale: Locale = "fr_CA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr_CA");
+///
+/// let locale: Locale = "fr_LU".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr_LU");
+///
+/// let locale: Locale = "fr_CH".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "fr_CH");
+///
+/// let locale: Locale = "it".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "it");
+///
+/// let locale: Locale = "it_CH".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "it_CH");
+///
+/// let locale: Locale = "pt".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "pt");
+///
+/// let locale: Locale = "pt_BR".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "pt_BR");
+///
+/// let locale: Locale = "ro".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ro");
+///
+/// let locale: Locale = "ro_MD".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ro_MD");
+///
+/// let locale: Locale = "ru".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ru");
+///
+/// let locale: Locale = "ru_MD".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ru_MD");
+///
+/// let locale: Locale = "es".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es");
+///
+/// let locale: Locale = "es_419".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_419");
+///
+/// let locale: Locale = "es_AR".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_AR");
+///
+/// let locale: Locale = "es_BO".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_BO");
+///
+/// let locale: Locale = "es_CL".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_CL");
+///
+/// let locale: Locale = "es_CO".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_CO");
+///
+/// let locale: Locale = "es_CR".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_CR");
+///
+/// let locale: Locale = "es_DO".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_DO");
+///
+/// let locale: Locale = "es_EC".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_EC");
+///
+/// let locale: Locale = "es_SV".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_SV");
+///
+/// let locale: Locale = "es_GT".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_GT");
+///
+/// let locale: Locale = "es_HN".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_HN");
+///
+/// let locale: Locale = "es_MX".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_MX");
+///
+/// let locale: Locale = "es_NI".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_NI");
+///
+/// let locale: Locale = "es_PA".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_PA");
+///
+/// let locale: Locale = "es_PY".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_PY");
+///
+/// let locale: Locale = "es_PE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_PE");
+///
+/// let locale: Locale = "es_PR".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_PR");
+///
+/// let locale: Locale = "es_UY".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_UY");
+///
+/// let locale: Locale = "es_VE".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "es_VE");
+///
+/// let locale: Locale = "sv".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "sv");
+///
+/// let locale: Locale = "sv_FI".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "sv_FI");
+///
+/// let locale: Locale = "sv-FI".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "sv_FI");
+///
+/// let locale: Locale = "EN-gb".try_into().unwrap();
+/// assert_eq!(locale, Locale::English(EnglishVariant::UnitedKingdom));
+///
+/// let locale: Locale = "ja-JP".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "ja_JP");
+///
+/// let locale: Locale = "zh-Hant-TW".try_into().unwrap();
+/// assert_eq!(locale.to_string(), "zh_Hant_TW");
+/// assert_eq!(locale.with_default_variant(), Locale::Chinese(ChineseVariant::Default));
+/// ```
+impl TryFrom<&str> for Locale {
+    type Error = Error;
+
+    fn try_from(value: &str) -> CoreResult<Self, Self::Error> {
+        Ok(value.parse()?)
+    }
+}
+
+/// Parse a `Locale` from a string.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::error::Part;
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::PortugueseVariant;
+///
+/// let locale: Locale = "pt-BR".parse().unwrap();
+/// assert_eq!(locale, Locale::Portuguese(PortugueseVariant::Brazil));
+///
+/// let error = "pt-BR-abc".parse::<Locale>().unwrap_err();
+/// assert_eq!(error.part(), Part::Variant);
+/// assert_eq!(error.subtag(), "abc");
+/// assert_eq!(error.to_string(), "locale: invalid variant subtag `'abc'` in `'pt-BR-abc'`.");
+/// ```
+impl FromStr for Locale {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> CoreResult<Self, Self::Err> {
+        if let Some(locale) = Locale::from_code(&value.replace('-', "_")) {
+            return Ok(locale);
+        }
+
+        let tag = value.parse::<LanguageTag>()?;
+
+        Locale::from_tag(tag).ok_or_else(|| {
+            // the tag is well-formed, only its language is not supported.
+            let language = value.split(['-', '_']).next().unwrap_or_default();
+
+            ParseError::new(value, Part::Language, language)
+        })
+    }
+}
+
+/// Every locale with a dedicated variant, ordered by code.
+static LOCALES: [Locale; 245] = [
+    Locale::Afar,
+    Locale::Abkhazian,
+    Locale::Avestan,
+    Locale::Afrikaans,
+    Locale::Akan,
+    Locale::Amharic,
+    Locale::Aragonese,
+    Locale::Arabic(ArabicVariant::Default),
+    Locale::Arabic(ArabicVariant::UnitedArabEmirates),
+    Locale::Arabic(ArabicVariant::Bahrain),
+    Locale::Arabic(ArabicVariant::Algeria),
+    Locale::Arabic(ArabicVariant::Egypt),
+    Locale::Arabic(ArabicVariant::Iraq),
+    Locale::Arabic(ArabicVariant::Jordan),
+    Locale::Arabic(ArabicVariant::Kuwait),
+    Locale::Arabic(ArabicVariant::Lebanon),
+    Locale::Arabic(ArabicVariant::Libya),
+    Locale::Arabic(ArabicVariant::Morocco),
+    Locale::Arabic(ArabicVariant::Oman),
+    Locale::Arabic(ArabicVariant::Qatar),
+    Locale::Arabic(ArabicVariant::SaudiArabia),
+    Locale::Arabic(ArabicVariant::Syria),
+    Locale::Arabic(ArabicVariant::Tunisia),
+    Locale::Arabic(ArabicVariant::Yemen),
+    Locale::Assamese,
+    Locale::Avaric,
+    Locale::Aymara,
+    Locale::Azerbaijani,
+    Locale::Bashkir,
+    Locale::Belarusian,
+    Locale::Bulgarian,
+    Locale::Bihari,
+    Locale::Bislama,
+    Locale::Bambara,
+    Locale::Bengali,
+    Locale::Tibetan,
+    Locale::Breton,
+    Locale::Bosnian,
+    Locale::Catalan,
+    Locale::Chechen,
+    Locale::Chamorro,
+    Locale::Corsican,
+    Locale::Cree,
+    Locale::Czech,
+    Locale::ChurchSlavic,
+    Locale::Chuvash,
+    Locale::Welsh,
+    Locale::Danish,
+    Locale::German(GermanVariant::Default),
+    Locale::German(GermanVariant::Austria),
+    Locale::German(GermanVariant::Switzerland),
+    Locale::German(GermanVariant::Liechtenstein),
+    Locale::German(GermanVariant::Luxembourg),
+    Locale::Divehi,
+    Locale::Dzongkha,
+    Locale::Ewe,
+    Locale::Greek,
+    Locale::English(EnglishVariant::Default),
+    Locale::English(EnglishVariant::World),
+    Locale::English(EnglishVariant::Australia),
+    Locale::English(EnglishVariant::Belize),
+    Locale::English(EnglishVariant::Canada),
+    Locale::English(EnglishVariant::UnitedKingdom),
+    Locale::English(EnglishVariant::Ireland),
+    Locale::English(EnglishVariant::Jamaica),
+    Locale::English(EnglishVariant::NewZealand),
+    Locale::English(EnglishVariant::Trinidad),
+    Locale::English(EnglishVariant::UnitedStates),
+    Locale::English(EnglishVariant::SouthAfrica),
+    Locale::Esperanto,
+    Locale::Spanish(SpanishVariant::Default),
+    Locale::Spanish(SpanishVariant::LatinAmerica),
+    Locale::Spanish(SpanishVariant::Argentina),
+    Locale::Spanish(SpanishVariant::Bolivia),
+    Locale::Spanish(SpanishVariant::Chile),
+    Locale::Spanish(SpanishVariant::Colombia),
+    Locale::Spanish(SpanishVariant::CostaRica),
+    Locale::Spanish(SpanishVariant::Ecuador),
+    Locale::Spanish(SpanishVariant::Guatemala),
+    Locale::Spanish(SpanishVariant::Honduras),
+    Locale::Spanish(SpanishVariant::Mexico),
+    Locale::Spanish(SpanishVariant::Nicaragua),
+    Locale::Spanish(SpanishVariant::Panama),
+    Locale::Spanish(SpanishVariant::Peru),
+    Locale::Spanish(SpanishVariant::PuertoRico),
+    Locale::Spanish(SpanishVariant::Paraguay),
+    Locale::Spanish(SpanishVariant::ElSalvador),
+    Locale::Spanish(SpanishVariant::Uruguay),
+    Locale::Spanish(SpanishVariant::Venezuela),
+    Locale::Estonian,
+    Locale::Basque,
+    Locale::Persian,
+    Locale::Fulah,
+    Locale::Finnish,
+    Locale::Fijian,
+    Locale::Faroese,
+    Locale::French(FrenchVariant::Default),
+    Locale::French(FrenchVariant::Belgium),
+    Locale::French(FrenchVariant::Canada),
+    Locale::French(FrenchVariant::Switzerland),
+    Locale::French(FrenchVariant::France),
+    Locale::French(FrenchVariant::Luxembourg),
+    Locale::WesternFrisian,
+    Locale::Irish,
+    Locale::Gaelic,
+    Locale::Galician,
+    Locale::Guarani,
+    Locale::Gujarati,
+    Locale::Manx,
+    Locale::Hausa,
+    Locale::Hebrew,
+    Locale::Hindi,
+    Locale::HiriMotu,
+    Locale::Croatian,
+    Locale::Haitian,
+    Locale::Hungarian,
+    Locale::Armenian,
+    Locale::Herero,
+    Locale::Indonesian,
+    Locale::Interlingue,
+    Locale::Igbo,
+    Locale::SichuanYi,
+    Locale::Inupiaq,
+    Locale::Ido,
+    Locale::Icelandic,
+    Locale::Italian(ItalianVariant::Default),
+    Locale::Italian(ItalianVariant::Switzerland),
+    Locale::Inuktitut,
+    Locale::Japanese,
+    Locale::Javanese,
+    Locale::Georgian,
+    Locale::Kongo,
+    Locale::Kikuyu,
+    Locale::Kuanyama,
+    Locale::Kazakh,
+    Locale::Kalaallisut,
+    Locale::CentralKhmer,
+    Locale::Kannada,
+    Locale::Korean,
+    Locale::Kanuri,
+    Locale::Kashmiri,
+    Locale::Kurdish,
+    Locale::Komi,
+    Locale::Cornish,
+    Locale::Kirghiz,
+    Locale::Latin,
+    Locale::Luxembourgish,
+    Locale::Ganda,
+    Locale::Limburgan,
+    Locale::Lingala,
+    Locale::Lao,
+    Locale::Lithuanian,
+    Locale::LubaKatanga,
+    Locale::Latvian,
+    Locale::Malagasy,
+    Locale::Marshallese,
+    Locale::Maori,
+    Locale::Macedonian,
+    Locale::Malayalam,
+    Locale::Mongolian,
+    Locale::Marathi,
+    Locale::Malay,
+    Locale::Maltese,
+    Locale::Burmese,
+    Locale::Nauru,
+    Locale::NorthernNdebele,
+    Locale::Nepali,
+    Locale::Ndonga,
+    Locale::Dutch(DutchVariant::Default),
+    Locale::Dutch(DutchVariant::Belgium),
+    Locale::NorwegianNynorsk,
+    Locale::Norwegian,
+    Locale::SouthernNdebele,
+    Locale::Navajo,
+    Locale::Chichewa,
+    Locale::Occitan,
+    Locale::Ojibwa,
+    Locale::Oromo,
+    Locale::Oriya,
+    Locale::Ossetian,
+    Locale::Panjabi,
+    Locale::Pali,
+    Locale::Polish,
+    Locale::Pushto,
+    Locale::Portuguese(PortugueseVariant::Default),
+    Locale::Portuguese(PortugueseVariant::Brazil),
+    Locale::Quechua,
+    Locale::Romansh,
+    Locale::Rundi,
+    Locale::Romanian(RomanianVariant::Default),
+    Locale::Romanian(RomanianVariant::Moldova),
+    Locale::Russian(RussianVariant::Default),
+    Locale::Russian(RussianVariant::Moldova),
+    Locale::Kinyarwanda,
+    Locale::Sanskrit,
+    Locale::Sardinian,
+    Locale::Sindhi,
+    Locale::NorthernSami,
+    Locale::Sango,
+    Locale::Sinhala,
+    Locale::Slovak,
+    Locale::Slovenian,
+    Locale::Samoan,
+    Locale::Shona,
+    Locale::Somali,
+    Locale::Albanian,
+    Locale::Serbian,
+    Locale::Swati,
+    Locale::SouthernSotho,
+    Locale::Sundanese,
+    Locale::Swedish(SwedishVariant::Default),
+    Locale::Swedish(SwedishVariant::Finland),
+    Locale::Swahili,
+    Locale::Tamil,
+    Locale::Telugu,
+    Locale::Tajik,
+    Locale::Thai,
+    Locale::Tigrinya,
+    Locale::Turkmen,
+    Locale::Tagalog,
+    Locale::Tswana,
+    Locale::Tonga,
+    Locale::Turkish,
+    Locale::Tsonga,
+    Locale::Tatar,
+    Locale::Twi,
+    Locale::Tahitian,
+    Locale::Uighur,
+    Locale::Ukrainian,
+    Locale::Urdu,
+    Locale::Uzbek,
+    Locale::Venda,
+    Locale::Vietnamese,
+    Locale::Walloon,
+    Locale::Wolof,
+    Locale::Xhosa,
+    Locale::Yiddish,
+    Locale::Yoruba,
+    Locale::Zhuang,
+    Locale::Chinese(ChineseVariant::Default),
+    Locale::Chinese(ChineseVariant::China),
+    Locale::Chinese(ChineseVariant::HongKong),
+    Locale::Chinese(ChineseVariant::Singapore),
+    Locale::Chinese(ChineseVariant::Taiwan),
+    Locale::Zulu,
+];
+
+/// Create a `Locale` from a `LanguageTag`.
+///
+/// Tags with a dedicated variant are converted to that variant, other tags of a known language
+/// are converted to a `Locale::Tag`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::tag::LanguageTag;
+///
+/// let tag: LanguageTag = "sr".try_into().unwrap();
+/// assert_eq!(Locale::try_from(tag).unwrap(), Locale::Serbian);
+///
+/// let tag: LanguageTag = "sr-Latn".try_into().unwrap();
+/// assert_eq!(Locale::try_from(tag).unwrap(), Locale::Tag(tag));
+///
+/// let tag: LanguageTag = "xx".try_into().unwrap();
+/// assert!(Locale::try_from(tag).is_err());
+/// ```
+impl TryFrom<LanguageTag> for Locale {
+    type Error = Error;
+
+    fn try_from(tag: LanguageTag) -> CoreResult<Self, Self::Error> {
+        Locale::from_tag(tag)
+            .ok_or_else(|| Error::InvalidLocale(tag.to_string()))
+    }
+}
+
+/// Locales are ordered by their code, e.g. `en` < `en_GB` < `fr`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+///
+/// let locales = BTreeSet::from([
+///     Locale::French(FrenchVariant::Default),
+///     Locale::English(EnglishVariant::UnitedKingdom),
+///     Locale::English(EnglishVariant::Default),
+/// ]);
+///
+/// assert_eq!(
+///     locales.into_iter().collect::<Vec<_>>(),
+///     vec![
+///         Locale::English(EnglishVariant::Default),
+///         Locale::English(EnglishVariant::UnitedKingdom),
+///         Locale::French(FrenchVariant::Default),
+///     ]
+/// );
+/// ```
+impl Ord for Locale {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let is_tag = |locale: &Locale| matches!(locale, Locale::Tag(_));
+
+        self.code()
+            .cmp(other.code())
+            .then_with(|| is_tag(self).cmp(&is_tag(other)))
+    }
+}
+
+impl PartialOrd for Locale {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Locale {
+    /// Return an iterator over every locale with a dedicated variant, including the regional
+    /// variants of each language, ordered by code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// let english: Vec<Locale> = Locale::all()
+    ///     .filter(|locale| locale.language() == "en")
+    ///     .collect();
+    ///
+    /// assert_eq!(english[0], Locale::English(EnglishVariant::Default));
+    /// assert!(english.contains(&Locale::English(EnglishVariant::UnitedKingdom)));
+    ///
+    /// assert!(Locale::all().any(|locale| locale == Locale::Japanese));
+    /// ```
+    pub fn all() -> impl ExactSizeIterator<Item = Locale> {
+        LOCALES.iter().copied()
+    }
+
+    /// Return the locale for the given tag, if its language is supported.
+    fn from_tag(tag: LanguageTag) -> Option<Locale> {
+        if let Some(locale) = Locale::from_code(tag.as_str()) {
+            return Some(locale);
+        }
+
+        Locale::from_code(tag.language()).map(|_| Locale::Tag(tag))
+    }
+
+    /// Return the locale with a dedicated variant for the given canonical code, if any.
+    fn from_code(code: &str) -> Option<Locale> {
+        match code {
+            "aa" => Some(Locale::Afar),
+            "ab" => Some(Locale::Abkhazian),
+            "af" => Some(Locale::Afrikaans),
+            "ak" => Some(Locale::Akan),
+            "sq" => Some(Locale::Albanian),
+            "am" => Some(Locale::Amharic),
+            "ar" => Some(Locale::Arabic(ArabicVariant::Default)),
+            "ar_DZ" => Some(Locale::Arabic(ArabicVariant::Algeria)),
+            "ar_BH" => Some(Locale::Arabic(ArabicVariant::Bahrain)),
+            "ar_EG" => Some(Locale::Arabic(ArabicVariant::Egypt)),
+            "ar_IQ" => Some(Locale::Arabic(ArabicVariant::Iraq)),
+            "ar_JO" => Some(Locale::Arabic(ArabicVariant::Jordan)),
+            "ar_KW" => Some(Locale::Arabic(ArabicVariant::Kuwait)),
+            "ar_LB" => Some(Locale::Arabic(ArabicVariant::Lebanon)),
+            "ar_LY" => Some(Locale::Arabic(ArabicVariant::Libya)),
+            "ar_MA" => Some(Locale::Arabic(ArabicVariant::Morocco)),
+            "ar_OM" => Some(Locale::Arabic(ArabicVariant::Oman)),
+            "ar_QA" => Some(Locale::Arabic(ArabicVariant::Qatar)),
+            "ar_SA" => Some(Locale::Arabic(ArabicVariant::SaudiArabia)),
+            "ar_SY" => Some(Locale::Arabic(ArabicVariant::Syria)),
+            "ar_TN" => Some(Locale::Arabic(ArabicVariant::Tunisia)),
+            "ar_AE" => Some(Locale::Arabic(ArabicVariant::UnitedArabEmirates)),
+            "ar_YE" => Some(Locale::Arabic(ArabicVariant::Yemen)),
+            "an" => Some(Locale::Aragonese),
+            "hy" => Some(Locale::Armenian),
+            "as" => Some(Locale::Assamese),
+            "av" => Some(Locale::Avaric),
+            "ae" => Some(Locale::Avestan),
+            "ay" => Some(Locale::Aymara),
+            "az" => Some(Locale::Azerbaijani),
+            "ba" => Some(Locale::Bashkir),
+            "bm" => Some(Locale::Bambara),
+            "eu" => Some(Locale::Basque),
+            "be" => Some(Locale::Belarusian),
+            "bn" => Some(Locale::Bengali),
+            "bh" => Some(Locale::Bihari),
+            "bi" => Some(Locale::Bislama),
+            "bo" => Some(Locale::Tibetan),
+            "bs" => Some(Locale::Bosnian),
+            "br" => Some(Locale::Breton),
+            "bg" => Some(Locale::Bulgarian),
+            "my" => Some(Locale::Burmese),
+            "ca" => Some(Locale::Catalan),
+            "cs" => Some(Locale::Czech),
+            "ch" => Some(Locale::Chamorro),
+            "ce" => Some(Locale::Chechen),
+            "zh" => Some(Locale::Chinese(ChineseVariant::Default)),
+            "zh_HK" => Some(Locale::Chinese(ChineseVariant::HongKong)),
+            "zh_CN" => Some(Locale::Chinese(ChineseVariant::China)),
+            "zh_SG" => Some(Locale::Chinese(ChineseVariant::Singapore)),
+            "zh_TW" => Some(Locale::Chinese(ChineseVariant::Taiwan)),
+            "cu" => Some(Locale::ChurchSlavic),
+            "cv" => Some(Locale::Chuvash),
+            "kw" => Some(Locale::Cornish),
+            "co" => Some(Locale::Corsican),
+            "cr" => Some(Locale::Cree),
+            "cy" => Some(Locale::Welsh),
+            "da" => Some(Locale::Danish),
+            "de" => Some(Locale::German(GermanVariant::Default)),
+            "de_AT" => Some(Locale::German(GermanVariant::Austria)),
+            "de_LI" => Some(Locale::German(GermanVariant::Liechtenstein)),
+            "de_LU" => Some(Locale::German(GermanVariant::Luxembourg)),
+            "de_CH" => Some(Locale::German(GermanVariant::Switzerland)),
+            "dv" => Some(Locale::Divehi),
+            "nl" => Some(Locale::Dutch(DutchVariant::Default)),
+            "nl_BE" => Some(Locale::Dutch(DutchVariant::Belgium)),
+            "dz" => Some(Locale::Dzongkha),
+            "el" => Some(Locale::Greek),
+            "en" => Some(Locale::English(EnglishVariant::Default)),
+            "en_001" => Some(Locale::English(EnglishVariant::World)),
+            "en_AU" => Some(Locale::English(EnglishVariant::Australia)),
+            "en_BZ" => Some(Locale::English(EnglishVariant::Belize)),
+            "en_CA" => Some(Locale::English(EnglishVariant::Canada)),
+            "en_IE" => Some(Locale::English(EnglishVariant::Ireland)),
+            "en_JM" => Some(Locale::English(EnglishVariant::Jamaica)),
+            "en_NZ" => Some(Locale::English(EnglishVariant::NewZealand)),
+            "en_ZA" => Some(Locale::English(EnglishVariant::SouthAfrica)),
+            "en_TT" => Some(Locale::English(EnglishVariant::Trinidad)),
+            "en_GB" => Some(Locale::English(EnglishVariant::UnitedKingdom)),
+            "en_US" => Some(Locale::English(EnglishVariant::UnitedStates)),
+            "eo" => Some(Locale::Esperanto),
+            "et" => Some(Locale::Estonian),
+            "ee" => Some(Locale::Ewe),
+            "fo" => Some(Locale::Faroese),
+            "fa" => Some(Locale::Persian),
+            "fj" => Some(Locale::Fijian),
+            "fi" => Some(Locale::Finnish),
+            "fr" => Some(Locale::French(FrenchVariant::Default)),
+            "fr_FR" => Some(Locale::French(FrenchVariant::France)),
+            "fr_BE" => Some(Locale::French(FrenchVariant::Belgium)),
+            "fr_CA" => Some(Locale::French(FrenchVariant::Canada)),
+            "fr_LU" => Some(Locale::French(FrenchVariant::Luxembourg)),
+            "fr_CH" => Some(Locale::French(FrenchVariant::Switzerland)),
+            "fy" => Some(Locale::WesternFrisian),
+            "ff" => Some(Locale::Fulah),
+            "ka" => Some(Locale::Georgian),
+            "gd" => Some(Locale::Gaelic),
+            "ga" => Some(Locale::Irish),
+            "gl" => Some(Locale::Galician),
+            "gv" => Some(Locale::Manx),
+            "gn" => Some(Locale::Guarani),
+            "gu" => Some(Locale::Gujarati),
+            "ht" => Some(Locale::Haitian),
+            "ha" => Some(Locale::Hausa),
+            "he" => Some(Locale::Hebrew),
+            "hz" => Some(Locale::Herero),
+            "hi" => Some(Locale::Hindi),
+            "ho" => Some(Locale::HiriMotu),
+            "hr" => Some(Locale::Croatian),
+            "hu" => Some(Locale::Hungarian),
+            "ig" => Some(Locale::Igbo),
+            "is" => Some(Locale::Icelandic),
+            "io" => Some(Locale::Ido),
+            "ii" => Some(Locale::SichuanYi),
+            "iu" => Some(Locale::Inuktitut),
+            "ie" => Some(Locale::Interlingue),
+            "id" => Some(Locale::Indonesian),
+            "ik" => Some(Locale::Inupiaq),
+            "it" => Some(Locale::Italian(ItalianVariant::Default)),
+            "it_CH" => Some(Locale::Italian(ItalianVariant::Switzerland)),
+            "jv" => Some(Locale::Javanese),
+            "ja" => Some(Locale::Japanese),
+            "kl" => Some(Locale::Kalaallisut),
+            "kn" => Some(Locale::Kannada),
+            "ks" => Some(Locale::Kashmiri),
+            "kr" => Some(Locale::Kanuri),
+            "kk" => Some(Locale::Kazakh),
+            "km" => Some(Locale::CentralKhmer),
+            "ki" => Some(Locale::Kikuyu),
+            "rw" => Some(Locale::Kinyarwanda),
+            "ky" => Some(Locale::Kirghiz),
+            "kv" => Some(Locale::Komi),
+            "kg" => Some(Locale::Kongo),
+            "ko" => Some(Locale::Korean),
+            "kj" => Some(Locale::Kuanyama),
+            "ku" => Some(Locale::Kurdish),
+            "lo" => Some(Locale::Lao),
+            "la" => Some(Locale::Latin),
+            "lv" => Some(Locale::Latvian),
+            "li" => Some(Locale::Limburgan),
+            "ln" => Some(Locale::Lingala),
+            "lt" => Some(Locale::Lithuanian),
+            "lb" => Some(Locale::Luxembourgish),
+            "lu" => Some(Locale::LubaKatanga),
+            "lg" => Some(Locale::Ganda),
+            "mk" => Some(Locale::Macedonian),
+            "mh" => Some(Locale::Marshallese),
+            "ml" => Some(Locale::Malayalam),
+            "mi" => Some(Locale::Maori),
+            "mr" => Some(Locale::Marathi),
+            "ms" => Some(Locale::Malay),
+            "mg" => Some(Locale::Malagasy),
+            "mt" => Some(Locale::Maltese),
+            "mn" => Some(Locale::Mongolian),
+            "na" => Some(Locale::Nauru),
+            "nv" => Some(Locale::Navajo),
+            "nr" => Some(Locale::SouthernNdebele),
+            "nd" => Some(Locale::NorthernNdebele),
+            "ng" => Some(Locale::Ndonga),
+            "ne" => Some(Locale::Nepali),
+            "nn" => Some(Locale::NorwegianNynorsk),
+            "no" => Some(Locale::Norwegian),
+            "ny" => Some(Locale::Chichewa),
+            "oc" => Some(Locale::Occitan),
+            "oj" => Some(Locale::Ojibwa),
+            "or" => Some(Locale::Oriya),
+            "om" => Some(Locale::Oromo),
+            "os" => Some(Locale::Ossetian),
+            "pa" => Some(Locale::Panjabi),
+            "pi" => Some(Locale::Pali),
+            "pl" => Some(Locale::Polish),
+            "pt" => Some(Locale::Portuguese(PortugueseVariant::Default)),
+            "pt_BR" => Some(Locale::Portuguese(PortugueseVariant::Brazil)),
+            "ps" => Some(Locale::Pushto),
+            "qu" => Some(Locale::Quechua),
+            "rm" => Some(Locale::Romansh),
+            "ro" => Some(Locale::Romanian(RomanianVariant::Default)),
+            "ro_MD" => Some(Locale::Romanian(RomanianVariant::Moldova)),
+            "rn" => Some(Locale::Rundi),
+            "ru" => Some(Locale::Russian(RussianVariant::Default)),
+            "ru_MD" => Some(Locale::Russian(RussianVariant::Moldova)),
+            "sg" => Some(Locale::Sango),
+            "sa" => Some(Locale::Sanskrit),
+            "si" => Some(Locale::Sinhala),
+            "sk" => Some(Locale::Slovak),
+            "sl" => Some(Locale::Slovenian),
+            "se" => Some(Locale::NorthernSami),
+            "sm" => Some(Locale::Samoan),
+            "sn" => Some(Locale::Shona),
+            "sd" => Some(Locale::Sindhi),
+            "so" => Some(Locale::Somali),
+            "st" => Some(Locale::SouthernSotho),
+            "es" => Some(Locale::Spanish(SpanishVariant::Default)),
+            "es_419" => Some(Locale::Spanish(SpanishVariant::LatinAmerica)),
+            "es_AR" => Some(Locale::Spanish(SpanishVariant::Argentina)),
+            "es_BO" => Some(Locale::Spanish(SpanishVariant::Bolivia)),
+            "es_CL" => Some(Locale::Spanish(SpanishVariant::Chile)),
+            "es_CO" => Some(Locale::Spanish(SpanishVariant::Colombia)),
+            "es_CR" => Some(Locale::Spanish(SpanishVariant::CostaRica)),
+            "es_DO" => {
+                Some(Locale::Spanish(SpanishVariant::DominicanRepublic))
+            }
+            "es_EC" => Some(Locale::Spanish(SpanishVariant::Ecuador)),
+            "es_SV" => Some(Locale::Spanish(SpanishVariant::ElSalvador)),
+            "es_GT" => Some(Locale::Spanish(SpanishVariant::Guatemala)),
+            "es_HN" => Some(Locale::Spanish(SpanishVariant::Honduras)),
+            "es_MX" => Some(Locale::Spanish(SpanishVariant::Mexico)),
+            "es_NI" => Some(Locale::Spanish(SpanishVariant::Nicaragua)),
+            "es_PA" => Some(Locale::Spanish(SpanishVariant::Panama)),
+            "es_PY" => Some(Locale::Spanish(SpanishVariant::Paraguay)),
+            "es_PE" => Some(Locale::Spanish(SpanishVariant::Peru)),
+            "es_PR" => Some(Locale::Spanish(SpanishVariant::PuertoRico)),
+            "es_UY" => Some(Locale::Spanish(SpanishVariant::Uruguay)),
+            "es_VE" => Some(Locale::Spanish(SpanishVariant::Venezuela)),
+            "sc" => Some(Locale::Sardinian),
+            "sr" => Some(Locale::Serbian),
+            "ss" => Some(Locale::Swati),
+            "su" => Some(Locale::Sundanese),
+            "sw" => Some(Locale::Swahili),
+            "sv" => Some(Locale::Swedish(SwedishVariant::Default)),
+            "sv_FI" => Some(Locale::Swedish(SwedishVariant::Finland)),
+            "ty" => Some(Locale::Tahitian),
+            "ta" => Some(Locale::Tamil),
+            "tt" => Some(Locale::Tatar),
+            "te" => Some(Locale::Telugu),
+            "tg" => Some(Locale::Tajik),
+            "tl" => Some(Locale::Tagalog),
+            "th" => Some(Locale::Thai),
+            "ti" => Some(Locale::Tigrinya),
+            "to" => Some(Locale::Tonga),
+            "tn" => Some(Locale::Tswana),
+            "ts" => Some(Locale::Tsonga),
+            "tk" => Some(Locale::Turkmen),
+            "tr" => Some(Locale::Turkish),
+            "tw" => Some(Locale::Twi),
+            "ug" => Some(Locale::Uighur),
+            "uk" => Some(Locale::Ukrainian),
+            "ur" => Some(Locale::Urdu),
+            "uz" => Some(Locale::Uzbek),
+            "ve" => Some(Locale::Venda),
+            "vi" => Some(Locale::Vietnamese),
+            "wa" => Some(Locale::Walloon),
+            "wo" => Some(Locale::Wolof),
+            "xh" => Some(Locale::Xhosa),
+            "yi" => Some(Locale::Yiddish),
+            "yo" => Some(Locale::Yoruba),
+            "za" => Some(Locale::Zhuang),
+            "zu" => Some(Locale::Zulu),
+            _ => None,
+        }
+    }
+}
+
+/// Display a `Locale`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+///
+/// let locale = Locale::Afar;
+/// assert_eq!(locale.to_string(), "aa");
+///
+/// let locale = Locale::Abkhazian;
+/// assert_eq!(locale.to_string(), "ab");
+///
+/// let locale = Locale::Afrikaans;
+/// assert_eq!(locale.to_string(), "af");
+///
+/// let locale = Locale::Akan;
+/// assert_eq!(locale.to_string(), "ak");
+///
+/// let locale = Locale::Albanian;
+/// assert_eq!(locale.to_string(), "sq");
+///
+/// let locale = Locale::Amharic;
+/// assert_eq!(locale.to_string(), "am");
+///
+/// let locale = Locale::Aragonese;
+/// assert_eq!(locale.to_string(), "an");
+///
+/// let locale = Locale::Armenian;
+/// assert_eq!(locale.to_string(), "hy");
+///
+/// let locale = Locale::Assamese;
+/// assert_eq!(locale.to_string(), "as");
+///
+/// let locale = Locale::Avaric;
+/// assert_eq!(locale.to_string(), "av");
+///
+/// let locale = Locale::Avestan;
+/// assert_eq!(locale.to_string(), "ae");
+///
+/// let locale = Locale::Aymara;
+/// assert_eq!(locale.to_string(), "ay");
+///
+/// let locale = Locale::Azerbaijani;
+/// assert_eq!(locale.to_string(), "az");
+///
+/// let locale = Locale::Bashkir;
+/// assert_eq!(locale.to_string(), "ba");
+///
+/// let locale = Locale::Bambara;
+/// assert_eq!(locale.to_string(), "bm");
+///
+/// let locale = Locale::Basque;
+/// assert_eq!(locale.to_string(), "eu");
+///
+/// let locale = Locale::Belarusian;
+/// assert_eq!(locale.to_string(), "be");
+///
+/// let locale = Locale::Bengali;
+/// assert_eq!(locale.to_string(), "bn");
+///
+/// let locale = Locale::Bihari;
+/// assert_eq!(locale.to_string(), "bh");
+///
+/// let locale = Locale::Bislama;
+/// assert_eq!(locale.to_string(), "bi");
+///
+/// let locale = Locale::Tibetan;
+/// assert_eq!(locale.to_string(), "bo");
+///
+/// let locale = Locale::Bosnian;
+/// assert_eq!(locale.to_string(), "bs");
+///
+/// let locale = Locale::Breton;
+/// assert_eq!(locale.to_string(), "br");
+///
+/// let locale = Locale::Bulgarian;
+/// assert_eq!(locale.to_string(), "bg");
+///
+/// let locale = Locale::Burmese;
+/// assert_eq!(locale.to_string(), "my");
+///
+/// let locale = Locale::Catalan;
+/// assert_eq!(locale.to_string(), "ca");
+///
+/// let locale = Locale::Czech;
+/// assert_eq!(locale.to_string(), "cs");
+///
+/// let locale = Locale::Chamorro;
+/// assert_eq!(locale.to_string(), "ch");
+///
+/// let locale = Locale::Chechen;
+/// assert_eq!(locale.to_string(), "ce");
+///
+/// let locale = Locale::ChurchSlavic;
+/// assert_eq!(locale.to_string(), "cu");
+///
+/// let locale = Locale::Chuvash;
+/// assert_eq!(locale.to_string(), "cv");
+///
+/// let locale = Locale::Cornish;
+/// assert_eq!(locale.to_string(), "kw");
+///
+/// let locale = Locale::Corsican;
+/// assert_eq!(locale.to_string(), "co");
+///
+/// let locale = Locale::Cree;
+/// assert_eq!(locale.to_string(), "cr");
+///
+/// let locale = Locale::Welsh;
+/// assert_eq!(locale.to_string(), "cy");
+///
+/// let locale = Locale::Danish;
+/// assert_eq!(locale.to_string(), "da");
+///
+/// let locale = Locale::Divehi;
+/// assert_eq!(locale.to_string(), "dv");
+///
+/// let locale = Locale::Dzongkha;
+/// assert_eq!(locale.to_string(), "dz");
+///
+/// let locale = Locale::Greek;
+/// assert_eq!(locale.to_string(), "el");
+///
+/// let locale = Locale::Esperanto;
+/// assert_eq!(locale.to_string(), "eo");
+///
+/// let locale = Locale::Estonian;
+/// assert_eq!(locale.to_string(), "et");
+///
+/// let locale = Locale::Ewe;
+/// assert_eq!(locale.to_string(), "ee");
+///
+/// let locale = Locale::Faroese;
+/// assert_eq!(locale.to_string(), "fo");
+///
+/// let locale = Locale::Persian;
+/// assert_eq!(locale.to_string(), "fa");
+///
+/// let locale = Locale::Fijian;
+/// assert_eq!(locale.to_string(), "fj");
+///
+/// let locale = Locale::Finnish;
+/// assert_eq!(locale.to_string(), "fi");
+///
+/// let locale = Locale::WesternFrisian;
+/// assert_eq!(locale.to_string(), "fy");
+///
+/// let locale = Locale::Fulah;
+/// assert_eq!(locale.to_string(), "ff");
+///
+/// let locale = Locale::Georgian;
+/// assert_eq!(locale.to_string(), "ka");
+///
+/// let locale = Locale::Gaelic;
+/// assert_eq!(locale.to_string(), "gd");
+///
+/// let locale = Locale::Irish;
+/// assert_eq!(locale.to_string(), "ga");
+///
+/// let locale = Locale::Galician;
+/// assert_eq!(locale.to_string(), "gl");
+///
+/// let locale = Locale::Manx;
+/// assert_eq!(locale.to_string(), "gv");
+///
+/// let locale = Locale::Guarani;
+/// assert_eq!(locale.to_string(), "gn");
+///
+/// let locale = Locale::Gujarati;
+/// assert_eq!(locale.to_string(), "gu");
+///
+/// let locale = Locale::Haitian;
+/// assert_eq!(locale.to_string(), "ht");
+///
+/// let locale = Locale::Hausa;
+/// assert_eq!(locale.to_string(), "ha");
+///
+/// let locale = Locale::Hebrew;
+/// assert_eq!(locale.to_string(), "he");
+///
+/// let locale = Locale::Herero;
+/// assert_eq!(locale.to_string(), "hz");
+///
+/// let locale = Locale::Hindi;
+/// assert_eq!(locale.to_string(), "hi");
+///
+/// let locale = Locale::HiriMotu;
+/// assert_eq!(locale.to_string(), "ho");
+///
+/// let locale = Locale::Croatian;
+/// assert_eq!(locale.to_string(), "hr");
+///
+/// let locale = Locale::Hungarian;
+/// assert_eq!(locale.to_string(), "hu");
+///
+/// let locale = Locale::Igbo;
+/// assert_eq!(locale.to_string(), "ig");
+///
+/// let locale = Locale::Icelandic;
+/// assert_eq!(locale.to_string(), "is");
+///
+/// let locale = Locale::Ido;
+/// assert_eq!(locale.to_string(), "io");
+///
+/// let locale = Locale::SichuanYi;
+/// assert_eq!(locale.to_string(), "ii");
+///
+/// let locale = Locale::Inuktitut;
+/// assert_eq!(locale.to_string(), "iu");
+///
+/// let locale = Locale::Interlingue;
+/// assert_eq!(locale.to_string(), "ie");
+///
+/// let locale = Locale::Indonesian;
+/// assert_eq!(locale.to_string(), "id");
+///
+/// let locale = Locale::Inupiaq;
+/// assert_eq!(locale.to_string(), "ik");
+///
+/// let locale = Locale::Javanese;
+/// assert_eq!(locale.to_string(), "jv");
+///
+/// let locale = Locale::Japanese;
+/// assert_eq!(locale.to_string(), "ja");
+///
+/// let locale = Locale::Kalaallisut;
+/// assert_eq!(locale.to_string(), "kl");
+///
+/// let locale = Locale::Kannada;
+/// assert_eq!(locale.to_string(), "kn");
+///
+/// let locale = Locale::Kashmiri;
+/// assert_eq!(locale.to_string(), "ks");
+///
+/// let locale = Locale::Kanuri;
+/// assert_eq!(locale.to_string(), "kr");
+///
+/// let locale = Locale::Kazakh;
+/// assert_eq!(locale.to_string(), "kk");
+///
+/// let locale = Locale::CentralKhmer;
+/// assert_eq!(locale.to_string(), "km");
+///
+/// let locale = Locale::Kikuyu;
+/// assert_eq!(locale.to_string(), "ki");
+///
+/// let locale = Locale::Kinyarwanda;
+/// assert_eq!(locale.to_string(), "rw");
+///
+/// let locale = Locale::Kirghiz;
+/// assert_eq!(locale.to_string(), "ky");
+///
+/// let locale = Locale::Komi;
+/// assert_eq!(locale.to_string(), "kv");
+///
+/// let locale = Locale::Kongo;
+/// assert_eq!(locale.to_string(), "kg");
+///
+/// let locale = Locale::Korean;
+/// assert_eq!(locale.to_string(), "ko");
+///
+/// let locale = Locale::Kuanyama;
+/// assert_eq!(locale.to_string(), "kj");
+///
+/// let locale = Locale::Kurdish;
+/// assert_eq!(locale.to_string(), "ku");
+///
+/// let locale = Locale::Lao;
+/// assert_eq!(locale.to_string(), "lo");
+///
+/// let locale = Locale::Latin;
+/// assert_eq!(locale.to_string(), "la");
+///
+/// let locale = Locale::Latvian;
+/// assert_eq!(locale.to_string(), "lv");
+///
+/// let locale = Locale::Limburgan;
+/// assert_eq!(locale.to_string(), "li");
+///
+/// let locale = Locale::Lingala;
+/// assert_eq!(locale.to_string(), "ln");
+///
+/// let locale = Locale::Lithuanian;
+/// assert_eq!(locale.to_string(), "lt");
+///
+/// let locale = Locale::Luxembourgish;
+/// assert_eq!(locale.to_string(), "lb");
+///
+/// let locale = Locale::LubaKatanga;
+/// assert_eq!(locale.to_string(), "lu");
+///
+/// let locale = Locale::Ganda;
+/// assert_eq!(locale.to_string(), "lg");
+///
+/// let locale = Locale::Macedonian;
+/// assert_eq!(locale.to_string(), "mk");
+///
+/// let locale = Locale::Marshallese;
+/// assert_eq!(locale.to_string(), "mh");
+///
+/// let locale = Locale::Malayalam;
+/// assert_eq!(locale.to_string(), "ml");
+///
+/// let locale = Locale::Maori;
+/// assert_eq!(locale.to_string(), "mi");
+///
+/// let locale = Locale::Marathi;
+/// assert_eq!(locale.to_string(), "mr");
+///
+/// let locale = Locale::Malay;
+/// assert_eq!(locale.to_string(), "ms");
+///
+/// let locale = Locale::Malagasy;
+/// assert_eq!(locale.to_string(), "mg");
+///
+/// let locale = Locale::Maltese;
+/// assert_eq!(locale.to_string(), "mt");
+///
+/// let locale = Locale::Mongolian;
+/// assert_eq!(locale.to_string(), "mn");
+///
+/// let locale = Locale::Nauru;
+/// assert_eq!(locale.to_string(), "na");
+///
+/// let locale = Locale::Navajo;
+/// assert_eq!(locale.to_string(), "nv");
+///
+/// let locale = Locale::SouthernNdebele;
+/// assert_eq!(locale.to_string(), "nr");
+///
+/// let locale = Locale::NorthernNdebele;
+/// assert_eq!(locale.to_string(), "nd");
+///
+/// let locale = Locale::Ndonga;
+/// assert_eq!(locale.to_string(), "ng");
+///
+/// let locale = Locale::Nepali;
+/// assert_eq!(locale.to_string(), "ne");
+///
+/// let locale = Locale::NorwegianNynorsk;
+/// assert_eq!(locale.to_string(), "nn");
+///
+/// let locale = Locale::Norwegian;
+/// assert_eq!(locale.to_string(), "no");
+///
+/// let locale = Locale::Chichewa;
+/// assert_eq!(locale.to_string(), "ny");
+///
+/// let locale = Locale::Occitan;
+/// assert_eq!(locale.to_string(), "oc");
+///
+/// let locale = Locale::Ojibwa;
+/// assert_eq!(locale.to_string(), "oj");
+///
+/// let locale = Locale::Oriya;
+/// assert_eq!(locale.to_string(), "or");
+///
+/// let locale = Locale::Oromo;
+/// assert_eq!(locale.to_string(), "om");
+///
+/// let locale = Locale::Ossetian;
+/// assert_eq!(locale.to_string(), "os");
+///
+/// let locale = Locale::Panjabi;
+/// assert_eq!(locale.to_string(), "pa");
+///
+/// let locale = Locale::Pali;
+/// assert_eq!(locale.to_string(), "pi");
+///
+/// let locale = Locale::Polish;
+/// assert_eq!(locale.to_string(), "pl");
+///
+/// let locale = Locale::Pushto;
+/// assert_eq!(locale.to_string(), "ps");
+///
+/// let locale = Locale::Quechua;
+/// assert_eq!(locale.to_string(), "qu");
+///
+/// let locale = Locale::Romansh;
+/// assert_eq!(locale.to_string(), "rm");
+///
+/// let locale = Locale::Rundi;
+/// assert_eq!(locale.to_string(), "rn");
+///
+/// let locale = Locale::Sango;
+/// assert_eq!(locale.to_string(), "sg");
+///
+/// let locale = Locale::Sanskrit;
+/// assert_eq!(locale.to_string(), "sa");
+///
+/// let locale = Locale::Sinhala;
+/// assert_eq!(locale.to_string(), "si");
+///
+/// let locale = Locale::Slovak;
+/// assert_eq!(locale.to_string(), "sk");
+///
+/// let locale = Locale::Slovenian;
+/// assert_eq!(locale.to_string(), "sl");
+///
+/// let locale = Locale::NorthernSami;
+/// assert_eq!(locale.to_string(), "se");
+///
+/// let locale = Locale::Samoan;
+/// assert_eq!(locale.to_string(), "sm");
+///
+/// let locale = Locale::Shona;
+/// assert_eq!(locale.to_string(), "sn");
+///
+/// let locale = Locale::Sindhi;
+/// assert_eq!(locale.to_string(), "sd");
+///
+/// let locale = Locale::Somali;
+/// assert_eq!(locale.to_string(), "so");
+///
+/// let locale = Locale::SouthernSotho;
+/// assert_eq!(locale.to_string(), "st");
+///
+/// let locale = Locale::Sardinian;
+/// assert_eq!(locale.to_string(), "sc");
+///
+/// let locale = Locale::Serbian;
+/// assert_eq!(locale.to_string(), "sr");
+///
+/// let locale = Locale::Swati;
+/// assert_eq!(locale.to_string(), "ss");
+///
+/// let locale = Locale::Sundanese;
+/// assert_eq!(locale.to_string(), "su");
+///
+/// let locale = Locale::Swahili;
+/// assert_eq!(locale.to_string(), "sw");
+///
+/// let locale = Locale::Tahitian;
+/// assert_eq!(locale.to_string(), "ty");
+///
+/// let locale = Locale::Tamil;
+/// assert_eq!(locale.to_string(), "ta");
+///
+/// let locale = Locale::Tatar;
+/// assert_eq!(locale.to_string(), "tt");
+///
+/// let locale = Locale::Telugu;
+/// assert_eq!(locale.to_string(), "te");
+///
+/// let locale = Locale::Tajik;
+/// assert_eq!(locale.to_string(), "tg");
+///
+/// let locale = Locale::Tagalog;
+/// assert_eq!(locale.to_string(), "tl");
+///
+/// let locale = Locale::Thai;
+/// assert_eq!(locale.to_string(), "th");
+///
+/// let locale = Locale::Tigrinya;
+/// assert_eq!(locale.to_string(), "ti");
+///
+/// let locale = Locale::Tonga;
+/// assert_eq!(locale.to_string(), "to");
+///
+/// let locale = Locale::Tswana;
+/// assert_eq!(locale.to_string(), "tn");
+///
+/// let locale = Locale::Tsonga;
+/// assert_eq!(locale.to_string(), "ts");
+///
+/// let locale = Locale::Turkmen;
+/// assert_eq!(locale.to_string(), "tk");
+///
+/// let locale = Locale::Turkish;
+/// assert_eq!(locale.to_string(), "tr");
+///
+/// let locale = Locale::Twi;
+/// assert_eq!(locale.to_string(), "tw");
+///
+/// let locale = Locale::Uighur;
+/// assert_eq!(locale.to_string(), "ug");
+///
+/// let locale = Locale::Ukrainian;
+/// assert_eq!(locale.to_string(), "uk");
+///
+/// let locale = Locale::Urdu;
+/// assert_eq!(locale.to_string(), "ur");
+///
+/// let locale = Locale::Uzbek;
+/// assert_eq!(locale.to_string(), "uz");
+///
+/// let locale = Locale::Venda;
+/// assert_eq!(locale.to_string(), "ve");
+///
+/// let locale = Locale::Vietnamese;
+/// assert_eq!(locale.to_string(), "vi");
+///
+/// let locale = Locale::Walloon;
+/// assert_eq!(locale.to_string(), "wa");
+///
+/// let locale = Locale::Wolof;
+/// assert_eq!(locale.to_string(), "wo");
+///
+/// let locale = Locale::Xhosa;
+/// assert_eq!(locale.to_string(), "xh");
+///
+/// let locale = Locale::Yiddish;
+/// assert_eq!(locale.to_string(), "yi");
+///
+/// let locale = Locale::Yoruba;
+/// assert_eq!(locale.to_string(), "yo");
+///
+/// let locale = Locale::Zhuang;
+/// assert_eq!(locale.to_string(), "za");
+///
+/// let locale = Locale::Zulu;
+/// assert_eq!(locale.to_string(), "zu");
+/// ```
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl Locale {
+    /// Return the locale code, e.g. `en` or `en_GB`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// assert_eq!(Locale::English(EnglishVariant::UnitedKingdom).code(), "en_GB");
+    /// assert_eq!(Locale::Japanese.code(), "ja");
+    /// ```
+    pub fn code(&self) -> &str {
+        match self {
+            Locale::Afar => "aa",
+            Locale::Abkhazian => "ab",
+            Locale::Afrikaans => "af",
+            Locale::Akan => "ak",
+            Locale::Albanian => "sq",
+            Locale::Amharic => "am",
+            Locale::Arabic(ArabicVariant::Default) => "ar",
+            Locale::Arabic(ArabicVariant::Algeria) => "ar_DZ",
+            Locale::Arabic(ArabicVariant::Bahrain) => "ar_BH",
+            Locale::Arabic(ArabicVariant::Egypt) => "ar_EG",
+            Locale::Arabic(ArabicVariant::Iraq) => "ar_IQ",
+            Locale::Arabic(ArabicVariant::Jordan) => "ar_JO",
+            Locale::Arabic(ArabicVariant::Kuwait) => "ar_KW",
+            Locale::Arabic(ArabicVariant::Lebanon) => "ar_LB",
+            Locale::Arabic(ArabicVariant::Libya) => "ar_LY",
+            Locale::Arabic(ArabicVariant::Morocco) => "ar_MA",
+            Locale::Arabic(ArabicVariant::Oman) => "ar_OM",
+            Locale::Arabic(ArabicVariant::Qatar) => "ar_QA",
+            Locale::Arabic(ArabicVariant::SaudiArabia) => "ar_SA",
+            Locale::Arabic(ArabicVariant::Syria) => "ar_SY",
+            Locale::Arabic(ArabicVariant::Tunisia) => "ar_TN",
+            Locale::Arabic(ArabicVariant::UnitedArabEmirates) => "ar_AE",
+            Locale::Arabic(ArabicVariant::Yemen) => "ar_YE",
+            Locale::Aragonese => "an",
+            Locale::Armenian => "hy",
+            Locale::Assamese => "as",
+            Locale::Avaric => "av",
+            Locale::Avestan => "ae",
+            Locale::Aymara => "ay",
+            Locale::Azerbaijani => "az",
+            Locale::Bashkir => "ba",
+            Locale::Bambara => "bm",
+            Locale::Basque => "eu",
+            Locale::Belarusian => "be",
+            Locale::Bengali => "bn",
+            Locale::Bihari => "bh",
+            Locale::Bislama => "bi",
+            Locale::Tibetan => "bo",
+            Locale::Bosnian => "bs",
+            Locale::Breton => "br",
+            Locale::Bulgarian => "bg",
+            Locale::Burmese => "my",
+            Locale::Catalan => "ca",
+            Locale::Czech => "cs",
+            Locale::Chamorro => "ch",
+            Locale::Chechen => "ce",
+            Locale::Chinese(ChineseVariant::Default) => "zh",
+            Locale::Chinese(ChineseVariant::HongKong) => "zh_HK",
+            Locale::Chinese(ChineseVariant::China) => "zh_CN",
+            Locale::Chinese(ChineseVariant::Singapore) => "zh_SG",
+            Locale::Chinese(ChineseVariant::Taiwan) => "zh_TW",
+            Locale::ChurchSlavic => "cu",
+            Locale::Chuvash => "cv",
+            Locale::Cornish => "kw",
+            Locale::Corsican => "co",
+            Locale::Cree => "cr",
+            Locale::Welsh => "cy",
+            Locale::Danish => "da",
+            Locale::German(GermanVariant::Default) => "de",
+            Locale::German(GermanVariant::Austria) => "de_AT",
+            Locale::German(GermanVariant::Liechtenstein) => "de_LI",
+            Locale::German(GermanVariant::Luxembourg) => "de_LU",
+            Locale::German(GermanVariant::Switzerland) => "de_CH",
+            Locale::Divehi => "dv",
+            Locale::Dutch(DutchVariant::Default) => "nl",
+            Locale::Dutch(DutchVariant::Belgium) => "nl_BE",
+            Locale::Dzongkha => "dz",
+            Locale::Greek => "el",
+            Locale::English(EnglishVariant::Default) => "en",
+            Locale::English(EnglishVariant::World) => "en_001",
+            Locale::English(EnglishVariant::Australia) => "en_AU",
+            Locale::English(EnglishVariant::Belize) => "en_BZ",
+            Locale::English(EnglishVariant::Canada) => "en_CA",
+            Locale::English(EnglishVariant::Ireland) => "en_IE",
+            Locale::English(EnglishVariant::Jamaica) => "en_JM",
+            Locale::English(EnglishVariant::NewZealand) => "en_NZ",
+            Locale::English(EnglishVariant::SouthAfrica) => "en_ZA",
+            Locale::English(EnglishVariant::Trinidad) => "en_TT",
+            Locale::English(EnglishVariant::UnitedKingdom) => "en_GB",
+            Locale::English(EnglishVariant::UnitedStates) => "en_US",
+            Locale::Esperanto => "eo",
+            Locale::Estonian => "et",
+            Locale::Ewe => "ee",
+            Locale::Faroese => "fo",
+            Locale::Persian => "fa",
+            Locale::Fijian => "fj",
+            Locale::Finnish => "fi",
+            Locale::French(FrenchVariant::Default) => "fr",
+            Locale::French(FrenchVariant::France) => "fr_FR",
+            Locale::French(FrenchVariant::Belgium) => "fr_BE",
+            Locale::French(FrenchVariant::Canada) => "fr_CA",
+            Locale::French(FrenchVariant::Luxembourg) => "fr_LU",
+            Locale::French(FrenchVariant::Switzerland) => "fr_CH",
+            Locale::WesternFrisian => "fy",
+            Locale::Fulah => "ff",
+            Locale::Georgian => "ka",
+            Locale::Gaelic => "gd",
+            Locale::Irish => "ga",
+            Locale::Galician => "gl",
+            Locale::Manx => "gv",
+            Locale::Guarani => "gn",
+            Locale::Gujarati => "gu",
+            Locale::Haitian => "ht",
+            Locale::Hausa => "ha",
+            Locale::Hebrew => "he",
+            Locale::Herero => "hz",
+            Locale::Hindi => "hi",
+            Locale::HiriMotu => "ho",
+            Locale::Croatian => "hr",
+            Locale::Hungarian => "hu",
+            Locale::Igbo => "ig",
+            Locale::Icelandic => "is",
+            Locale::Ido => "io",
+            Locale::SichuanYi => "ii",
+            Locale::Inuktitut => "iu",
+            Locale::Interlingue => "ie",
+            Locale::Indonesian => "id",
+            Locale::Inupiaq => "ik",
+            Locale::Italian(ItalianVariant::Default) => "it",
+            Locale::Italian(ItalianVariant::Switzerland) => "it_CH",
+            Locale::Javanese => "jv",
+            Locale::Japanese => "ja",
+            Locale::Kalaallisut => "kl",
+            Locale::Kannada => "kn",
+            Locale::Kashmiri => "ks",
+            Locale::Kanuri => "kr",
+            Locale::Kazakh => "kk",
+            Locale::CentralKhmer => "km",
+            Locale::Kikuyu => "ki",
+            Locale::Kinyarwanda => "rw",
+            Locale::Kirghiz => "ky",
+            Locale::Komi => "kv",
+            Locale::Kongo => "kg",
+            Locale::Korean => "ko",
+            Locale::Kuanyama => "kj",
+            Locale::Kurdish => "ku",
+            Locale::Lao => "lo",
+            Locale::Latin => "la",
+            Locale::Latvian => "lv",
+            Locale::Limburgan => "li",
+            Locale::Lingala => "ln",
+            Locale::Lithuanian => "lt",
+            Locale::Luxembourgish => "lb",
+            Locale::LubaKatanga => "lu",
+            Locale::Ganda => "lg",
+            Locale::Macedonian => "mk",
+            Locale::Marshallese => "mh",
+            Locale::Malayalam => "ml",
+            Locale::Maori => "mi",
+            Locale::Marathi => "mr",
+            Locale::Malay => "ms",
+            Locale::Malagasy => "mg",
+            Locale::Maltese => "mt",
+            Locale::Mongolian => "mn",
+            Locale::Nauru => "na",
+            Locale::Navajo => "nv",
+            Locale::SouthernNdebele => "nr",
+            Locale::NorthernNdebele => "nd",
+            Locale::Ndonga => "ng",
+            Locale::Nepali => "ne",
+            Locale::NorwegianNynorsk => "nn",
+            Locale::Norwegian => "no",
+            Locale::Chichewa => "ny",
+            Locale::Occitan => "oc",
+            Locale::Ojibwa => "oj",
+            Locale::Oriya => "or",
+            Locale::Oromo => "om",
+            Locale::Ossetian => "os",
+            Locale::Panjabi => "pa",
+            Locale::Pali => "pi",
+            Locale::Polish => "pl",
+            Locale::Portuguese(PortugueseVariant::Default) => "pt",
+            Locale::Portuguese(PortugueseVariant::Brazil) => "pt_BR",
+            Locale::Pushto => "ps",
+            Locale::Quechua => "qu",
+            Locale::Romansh => "rm",
+            Locale::Romanian(RomanianVariant::Default) => "ro",
+            Locale::Romanian(RomanianVariant::Moldova) => "ro_MD",
+            Locale::Rundi => "rn",
+            Locale::Russian(RussianVariant::Default) => "ru",
+            Locale::Russian(RussianVariant::Moldova) => "ru_MD",
+            Locale::Sango => "sg",
+            Locale::Sanskrit => "sa",
+            Locale::Sinhala => "si",
+            Locale::Slovak => "sk",
+            Locale::Slovenian => "sl",
+            Locale::NorthernSami => "se",
+            Locale::Samoan => "sm",
+            Locale::Shona => "sn",
+            Locale::Sindhi => "sd",
+            Locale::Somali => "so",
+            Locale::SouthernSotho => "st",
+            Locale::Spanish(SpanishVariant::Default) => "es",
+            Locale::Spanish(SpanishVariant::LatinAmerica) => "es_419",
+            Locale::Spanish(SpanishVariant::Argentina) => "es_AR",
+            Locale::Spanish(SpanishVariant::Bolivia) => "es_BO",
+            Locale::Spanish(SpanishVariant::Chile) => "es_CL",
+            Locale::Spanish(SpanishVariant::Colombia) => "es_CO",
+            Locale::Spanish(SpanishVariant::CostaRica) => "es_CR",
+            Locale::Spanish(SpanishVariant::DominicanRepublic) => "es_DO",
+            Locale::Spanish(SpanishVariant::Ecuador) => "es_EC",
+            Locale::Spanish(SpanishVariant::ElSalvador) => "es_SV",
+            Locale::Spanish(SpanishVariant::Guatemala) => "es_GT",
+            Locale::Spanish(SpanishVariant::Honduras) => "es_HN",
+            Locale::Spanish(SpanishVariant::Mexico) => "es_MX",
+            Locale::Spanish(SpanishVariant::Nicaragua) => "es_NI",
+            Locale::Spanish(SpanishVariant::Panama) => "es_PA",
+            Locale::Spanish(SpanishVariant::Paraguay) => "es_PY",
+            Locale::Spanish(SpanishVariant::Peru) => "es_PE",
+            Locale::Spanish(SpanishVariant::PuertoRico) => "es_PR",
+            Locale::Spanish(SpanishVariant::Uruguay) => "es_UY",
+            Locale::Spanish(SpanishVariant::Venezuela) => "es_VE",
+            Locale::Sardinian => "sc",
+            Locale::Serbian => "sr",
+            Locale::Swati => "ss",
+            Locale::Sundanese => "su",
+            Locale::Swahili => "sw",
+            Locale::Swedish(SwedishVariant::Default) => "sv",
+            Locale::Swedish(SwedishVariant::Finland) => "sv_FI",
+            Locale::Tahitian => "ty",
+            Locale::Tamil => "ta",
+            Locale::Tatar => "tt",
+            Locale::Telugu => "te",
+            Locale::Tajik => "tg",
+            Locale::Tagalog => "tl",
+            Locale::Thai => "th",
+            Locale::Tigrinya => "ti",
+            Locale::Tonga => "to",
+            Locale::Tswana => "tn",
+            Locale::Tsonga => "ts",
+            Locale::Turkmen => "tk",
+            Locale::Turkish => "tr",
+            Locale::Twi => "tw",
+            Locale::Uighur => "ug",
+            Locale::Ukrainian => "uk",
+            Locale::Urdu => "ur",
+            Locale::Uzbek => "uz",
+            Locale::Venda => "ve",
+            Locale::Vietnamese => "vi",
+            Locale::Walloon => "wa",
+            Locale::Wolof => "wo",
+            Locale::Xhosa => "xh",
+            Locale::Yiddish => "yi",
+            Locale::Yoruba => "yo",
+            Locale::Zhuang => "za",
+            Locale::Zulu => "zu",
+            Locale::Tag(tag) => tag.as_str(),
+        }
+    }
+}
+
+/// The writing direction of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Locale {
+    /// Return the language code of the locale, e.g. `en` for `en_GB`.
+    pub fn language(&self) -> &str {
+        match self {
+            Locale::Tag(tag) => tag.language(),
+            _ => self.code().split('_').next().unwrap_or_default(),
+        }
+    }
+
+    /// Return the name of the locale in English.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    /// use tarjama::locale::SpanishVariant;
+    ///
+    /// assert_eq!(Locale::Arabic(ArabicVariant::Default).english_name(), "Arabic");
+    /// assert_eq!(Locale::Spanish(SpanishVariant::Mexico).english_name(), "Spanish (Mexico)");
+    ///
+    /// let locale: Locale = "zh-Hant-TW".try_into().unwrap();
+    /// assert_eq!(locale.english_name(), "Chinese (Traditional, Taiwan)");
+    /// ```
+    pub fn english_name(&self) -> String {
+        self.name("en").unwrap_or_else(|| self.code().to_string())
+    }
+
+    /// Return the name of the locale in its own language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    /// use tarjama::locale::FrenchVariant;
+    ///
+    /// assert_eq!(Locale::Arabic(ArabicVariant::Default).endonym(), "العربية");
+    /// assert_eq!(Locale::French(FrenchVariant::Default).endonym(), "français");
+    /// assert_eq!(Locale::French(FrenchVariant::Canada).endonym(), "français (Canada)");
+    /// assert_eq!(Locale::Japanese.endonym(), "日本語");
+    /// ```
+    pub fn endonym(&self) -> String {
+        self.name(self.language()).unwrap_or_else(|| {
+            data::language(self.language()).endonym.to_string()
+        })
+    }
+
+    /// Return the name of the locale in the language of the given locale, or `None` if names are
+    /// not available in that language.
+    ///
+    /// Names are available in every language with CLDR locale data, and each locale can be named
+    /// in its own language, see [`Locale::endonym`].
+    ///
+    /// Scripts and regions without a name in the given language are displayed using their code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    /// use tarjama::locale::ChineseVariant;
+    /// use tarjama::locale::FrenchVariant;
+    /// use tarjama::locale::GermanVariant;
+    ///
+    /// let french = Locale::French(FrenchVariant::Default);
+    ///
+    /// assert_eq!(
+    ///     Locale::German(GermanVariant::Switzerland).display_name(&french),
+    ///     Some("allemand (Suisse)".to_string())
+    /// );
+    /// assert_eq!(
+    ///     Locale::Arabic(ArabicVariant::Egypt).display_name(&Locale::Chinese(ChineseVariant::Default)),
+    ///     Some("阿拉伯语（埃及）".to_string())
+    /// );
+    /// assert_eq!(Locale::Japanese.display_name(&Locale::Japanese), Some("日本語".to_string()));
+    /// assert_eq!(french.display_name(&Locale::Japanese), Some("フランス語".to_string()));
+    /// assert_eq!(french.display_name(&Locale::Divehi), None);
+    /// ```
+    pub fn display_name(&self, locale: &Locale) -> Option<String> {
+        self.name(locale.language())
+    }
+
+    /// Return the writing direction of the locale, according to its script.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ArabicVariant;
+    /// use tarjama::locale::Direction;
+    /// use tarjama::locale::EnglishVariant;
+    ///
+    /// assert_eq!(Locale::Arabic(ArabicVariant::Default).direction(), Direction::RightToLeft);
+    /// assert_eq!(Locale::Hebrew.direction(), Direction::RightToLeft);
+    /// assert_eq!(Locale::English(EnglishVariant::Default).direction(), Direction::LeftToRight);
+    ///
+    /// let locale: Locale = "pa-Arab".try_into().unwrap();
+    /// assert_eq!(locale.direction(), Direction::RightToLeft);
+    /// ```
+    pub fn direction(&self) -> Direction {
+        data::direction(self.default_script())
+    }
+
+    /// Return the ISO 15924 code of the script of the locale, e.g. `Latn` or `Cyrl`.
+    ///
+    /// The script subtag of a `Tag` locale takes precedence over the default script of its
+    /// language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::ChineseVariant;
+    ///
+    /// assert_eq!(Locale::Serbian.default_script(), "Cyrl");
+    /// assert_eq!(Locale::Chinese(ChineseVariant::China).default_script(), "Hans");
+    /// assert_eq!(Locale::Chinese(ChineseVariant::Taiwan).default_script(), "Hant");
+    ///
+    /// let locale: Locale = "sr-Latn".try_into().unwrap();
+    /// assert_eq!(locale.default_script(), "Latn");
+    /// ```
+    pub fn default_script(&self) -> &str {
+        match self {
+            Locale::Tag(tag) => {
+                match (tag.script(), tag.language(), tag.region()) {
+                    (Some(script), _, _) => script,
+                    (_, "zh", Some("HK" | "MO" | "TW")) => "Hant",
+                    (_, language, _) => data::language(language).script,
+                }
+            }
+            Locale::Chinese(
+                ChineseVariant::HongKong | ChineseVariant::Taiwan,
+            ) => "Hant",
+            _ => data::language(self.language()).script,
+        }
+    }
+
+    /// Return the ISO 639-2 ( terminology ) code of the language of the locale, e.g. `deu`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::locale::Locale;
+    /// use tarjama::locale::GermanVariant;
+    ///
+    /// let locale = Locale::German(GermanVariant::Austria);
+    ///
+    /// assert_eq!(locale.iso639_2(), "deu");
+    /// assert_eq!(locale.iso639_2b(), "ger");
+    /// assert_eq!(locale.iso639_3(), Some("deu"));
+    /// ```
+    pub fn iso639_2(&self) -> &'static str {
+        data::language(self.language()).iso639_2
+    }
+
+    /// Return the ISO 639-2 bibliographic code of the language of the locale, e.g. `ger`.
+    ///
+    /// The bibliographic code is the same as the terminology code for most languages.
+    pub fn iso639_2b(&self) -> &'static str {
+        data::language(self.language()).iso639_2b
+    }
+
+    /// Return the ISO 639-3 code of the language of the locale, e.g. `deu`, or `None` if the
+    /// language is a collection of languages without an ISO 639-3 code, e.g. Bihari.
+    pub fn iso639_3(&self) -> Option<&'static str> {
+        data::language(self.language()).iso639_3
+    }
+
+    fn name(&self, display: &str) -> Option<String> {
+        let tag = LanguageTag::from(self);
+        let language = if data::has_names(display) {
+            data::language_name(display, tag.language())?
+        } else if display == tag.language() {
+            data::language(display).endonym
+        } else {
+            return None;
+        };
+
+        let qualifiers: Vec<&str> = tag
+            .script()
+            .map(|script| data::script_name(display, script).unwrap_or(script))
+            .into_iter()
+            .chain(tag.region().map(|region| {
+                data::region_name(display, region).unwrap_or(region)
+            }))
+            .chain(tag.variants())
+            .collect();
+
+        if qualifiers.is_empty() {
+            return Some(language.to_string());
+        }
+
+        let (open, separator, close) = data::pattern(display);
+
+        Some(format!("{language}{open}{}{close}", qualifiers.join(separator)))
+    }
+}
+
+/// A `ArabicVariant` enum for `Arabic` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ArabicVariant;
+///
+/// let locale = Locale::Arabic(ArabicVariant::Default);
+/// assert_eq!(locale.to_string(), "ar");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Algeria);
+/// assert_eq!(locale.to_string(), "ar_DZ");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Bahrain);
+/// assert_eq!(locale.to_string(), "ar_BH");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Egypt);
+/// assert_eq!(locale.to_string(), "ar_EG");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Iraq);
+/// assert_eq!(locale.to_string(), "ar_IQ");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Jordan);
+/// assert_eq!(locale.to_string(), "ar_JO");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Kuwait);
+/// assert_eq!(locale.to_string(), "ar_KW");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Lebanon);
+/// assert_eq!(locale.to_string(), "ar_LB");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Libya);
+/// assert_eq!(locale.to_string(), "ar_LY");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Morocco);
+/// assert_eq!(locale.to_string(), "ar_MA");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Oman);
+/// assert_eq!(locale.to_string(), "ar_OM");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Qatar);
+/// assert_eq!(locale.to_string(), "ar_QA");
+///
+/// let locale = Locale::Arabic(ArabicVariant::SaudiArabia);
+/// assert_eq!(locale.to_string(), "ar_SA");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Syria);
+/// assert_eq!(locale.to_string(), "ar_SY");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Tunisia);
+/// assert_eq!(locale.to_string(), "ar_TN");
+///
+/// let locale = Locale::Arabic(ArabicVariant::UnitedArabEmirates);
+/// assert_eq!(locale.to_string(), "ar_AE");
+///
+/// let locale = Locale::Arabic(ArabicVariant::Yemen);
+/// assert_eq!(locale.to_string(), "ar_YE");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArabicVariant {
+    Default,
+    Algeria,
+    Bahrain,
+    Egypt,
+    Iraq,
+    Jordan,
+    Kuwait,
+    Lebanon,
+    Libya,
+    Morocco,
+    Oman,
+    Qatar,
+    SaudiArabia,
+    Syria,
+    Tunisia,
+    UnitedArabEmirates,
+    Yemen,
+}
+
+impl ArabicVariant {
+    /// Return the region code of the variant, e.g. `DZ` for `ArabicVariant::Algeria`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            ArabicVariant::Default => None,
+            ArabicVariant::Algeria => Some("DZ"),
+            ArabicVariant::Bahrain => Some("BH"),
+            ArabicVariant::Egypt => Some("EG"),
+            ArabicVariant::Iraq => Some("IQ"),
+            ArabicVariant::Jordan => Some("JO"),
+            ArabicVariant::Kuwait => Some("KW"),
+            ArabicVariant::Lebanon => Some("LB"),
+            ArabicVariant::Libya => Some("LY"),
+            ArabicVariant::Morocco => Some("MA"),
+            ArabicVariant::Oman => Some("OM"),
+            ArabicVariant::Qatar => Some("QA"),
+            ArabicVariant::SaudiArabia => Some("SA"),
+            ArabicVariant::Syria => Some("SY"),
+            ArabicVariant::Tunisia => Some("TN"),
+            ArabicVariant::UnitedArabEmirates => Some("AE"),
+            ArabicVariant::Yemen => Some("YE"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Algeria`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `الجزائر`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("ar", self.region()?)
+    }
+}
+
+/// A `ChineseVariant` enum for `Chinese` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ChineseVariant;
+///
+/// let locale = Locale::Chinese(ChineseVariant::Default);
+/// assert_eq!(locale.to_string(), "zh");
+///
+/// let locale = Locale::Chinese(ChineseVariant::HongKong);
+/// assert_eq!(locale.to_string(), "zh_HK");
+///
+/// let locale = Locale::Chinese(ChineseVariant::China);
+/// assert_eq!(locale.to_string(), "zh_CN");
+///
+/// let locale = Locale::Chinese(ChineseVariant::Singapore);
+/// assert_eq!(locale.to_string(), "zh_SG");
+///
+/// let locale = Locale::Chinese(ChineseVariant::Taiwan);
+/// assert_eq!(locale.to_string(), "zh_TW");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChineseVariant {
+    Default,
+    HongKong,
+    China,
+    Singapore,
+    Taiwan,
+}
+
+impl ChineseVariant {
+    /// Return the region code of the variant, e.g. `HK` for `ChineseVariant::HongKong`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            ChineseVariant::Default => None,
+            ChineseVariant::HongKong => Some("HK"),
+            ChineseVariant::China => Some("CN"),
+            ChineseVariant::Singapore => Some("SG"),
+            ChineseVariant::Taiwan => Some("TW"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Hong Kong`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `香港`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("zh", self.region()?)
+    }
+}
+
+/// A `GermanVariant` enum for `German` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::GermanVariant;
+///
+/// let locale = Locale::German(GermanVariant::Default);
+/// assert_eq!(locale.to_string(), "de");
+///
+/// let locale = Locale::German(GermanVariant::Austria);
+/// assert_eq!(locale.to_string(), "de_AT");
+///
+/// let locale = Locale::German(GermanVariant::Liechtenstein);
+/// assert_eq!(locale.to_string(), "de_LI");
+///
+/// let locale = Locale::German(GermanVariant::Luxembourg);
+/// assert_eq!(locale.to_string(), "de_LU");
+///
+/// let locale = Locale::German(GermanVariant::Switzerland);
+/// assert_eq!(locale.to_string(), "de_CH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GermanVariant {
+    Default,
+    Austria,
+    Liechtenstein,
+    Luxembourg,
+    Switzerland,
+}
+
+impl GermanVariant {
+    /// Return the region code of the variant, e.g. `AT` for `GermanVariant::Austria`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            GermanVariant::Default => None,
+            GermanVariant::Austria => Some("AT"),
+            GermanVariant::Liechtenstein => Some("LI"),
+            GermanVariant::Luxembourg => Some("LU"),
+            GermanVariant::Switzerland => Some("CH"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Austria`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Österreich`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("de", self.region()?)
+    }
+}
+
+/// A `DutchVariant` enum for `Dutch` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::DutchVariant;
+///
+/// let locale = Locale::Dutch(DutchVariant::Default);
+/// assert_eq!(locale.to_string(), "nl");
+///
+/// let locale = Locale::Dutch(DutchVariant::Belgium);
+/// assert_eq!(locale.to_string(), "nl_BE");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DutchVariant {
+    Default,
+    Belgium,
+}
+
+impl DutchVariant {
+    /// Return the region code of the variant, e.g. `BE` for `DutchVariant::Belgium`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            DutchVariant::Default => None,
+            DutchVariant::Belgium => Some("BE"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Belgium`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `België`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("nl", self.region()?)
+    }
+}
+
+/// A `EnglishVariant` enum for `English` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+///
+/// let locale = Locale::English(EnglishVariant::Default);
+/// assert_eq!(locale.to_string(), "en");
+///
+/// let locale = Locale::English(EnglishVariant::World);
+/// assert_eq!(locale.to_string(), "en_001");
+///
+/// let locale = Locale::English(EnglishVariant::Australia);
+/// assert_eq!(locale.to_string(), "en_AU");
+///
+/// let locale = Locale::English(EnglishVariant::Belize);
+/// assert_eq!(locale.to_string(), "en_BZ");
+///
+/// let locale = Locale::English(EnglishVariant::Canada);
+/// assert_eq!(locale.to_string(), "en_CA");
+///
+/// let locale = Locale::English(EnglishVariant::Ireland);
+/// assert_eq!(locale.to_string(), "en_IE");
+///
+/// let locale = Locale::English(EnglishVariant::Jamaica);
+/// assert_eq!(locale.to_string(), "en_JM");
+///
+/// let locale = Locale::English(EnglishVariant::NewZealand);
+/// assert_eq!(locale.to_string(), "en_NZ");
+///
+/// let locale = Locale::English(EnglishVariant::SouthAfrica);
+/// assert_eq!(locale.to_string(), "en_ZA");
+///
+/// let locale = Locale::English(EnglishVariant::Trinidad);
+/// assert_eq!(locale.to_string(), "en_TT");
+///
+/// let locale = Locale::English(EnglishVariant::UnitedKingdom);
+/// assert_eq!(locale.to_string(), "en_GB");
+///
+/// let locale = Locale::English(EnglishVariant::UnitedStates);
+/// assert_eq!(locale.to_string(), "en_US");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnglishVariant {
+    Default,
+    /// International English, `en_001`.
+    World,
+    Australia,
+    Belize,
+    Canada,
+    Ireland,
+    Jamaica,
+    NewZealand,
+    SouthAfrica,
+    Trinidad,
+    UnitedKingdom,
+    UnitedStates,
+}
+
+impl EnglishVariant {
+    /// Return the region code of the variant, e.g. `001` for `EnglishVariant::World`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            EnglishVariant::Default => None,
+            EnglishVariant::World => Some("001"),
+            EnglishVariant::Australia => Some("AU"),
+            EnglishVariant::Belize => Some("BZ"),
+            EnglishVariant::Canada => Some("CA"),
+            EnglishVariant::Ireland => Some("IE"),
+            EnglishVariant::Jamaica => Some("JM"),
+            EnglishVariant::NewZealand => Some("NZ"),
+            EnglishVariant::SouthAfrica => Some("ZA"),
+            EnglishVariant::Trinidad => Some("TT"),
+            EnglishVariant::UnitedKingdom => Some("GB"),
+            EnglishVariant::UnitedStates => Some("US"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `World`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `World`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+}
+
+/// A `FrenchVariant` enum for `French` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::FrenchVariant;
+///
+/// let locale = Locale::French(FrenchVariant::Default);
+/// assert_eq!(locale.to_string(), "fr");
+///
+/// let locale = Locale::French(FrenchVariant::France);
+/// assert_eq!(locale.to_string(), "fr_FR");
+///
+/// let locale = Locale::French(FrenchVariant::Belgium);
+/// assert_eq!(locale.to_string(), "fr_BE");
+///
+/// let locale = Locale::French(FrenchVariant::Canada);
+/// assert_eq!(locale.to_string(), "fr_CA");
+///
+/// let locale = Locale::French(FrenchVariant::Luxembourg);
+/// assert_eq!(locale.to_string(), "fr_LU");
+///
+/// let locale = Locale::French(FrenchVariant::Switzerland);
+/// assert_eq!(locale.to_string(), "fr_CH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrenchVariant {
+    Default,
+    France,
+    Belgium,
+    Canada,
+    Luxembourg,
+    Switzerland,
+}
+
+impl FrenchVariant {
+    /// Return the region code of the variant, e.g. `FR` for `FrenchVariant::France`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            FrenchVariant::Default => None,
+            FrenchVariant::France => Some("FR"),
+            FrenchVariant::Belgium => Some("BE"),
+            FrenchVariant::Canada => Some("CA"),
+            FrenchVariant::Luxembourg => Some("LU"),
+            FrenchVariant::Switzerland => Some("CH"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `France`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `France`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("fr", self.region()?)
+    }
+}
+
+/// A `ItalianVariant` enum for `Italian` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ItalianVariant;
+///
+/// let locale = Locale::Italian(ItalianVariant::Default);
+/// assert_eq!(locale.to_string(), "it");
+///
+/// let locale = Locale::Italian(ItalianVariant::Switzerland);
+/// assert_eq!(locale.to_string(), "it_CH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItalianVariant {
+    Default,
+    Switzerland,
+}
+
+impl ItalianVariant {
+    /// Return the region code of the variant, e.g. `CH` for `ItalianVariant::Switzerland`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            ItalianVariant::Default => None,
+            ItalianVariant::Switzerland => Some("CH"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Switzerland`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Svizzera`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("it", self.region()?)
+    }
+}
+
+/// A `PortugueseVariant` enum for `Portuguese` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::PortugueseVariant;
+///
+/// let locale = Locale::Portuguese(PortugueseVariant::Default);
+/// assert_eq!(locale.to_string(), "pt");
+///
+/// let locale = Locale::Portuguese(PortugueseVariant::Brazil);
+/// assert_eq!(locale.to_string(), "pt_BR");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PortugueseVariant {
+    Default,
+    Brazil,
+}
+
+impl PortugueseVariant {
+    /// Return the region code of the variant, e.g. `BR` for `PortugueseVariant::Brazil`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            PortugueseVariant::Default => None,
+            PortugueseVariant::Brazil => Some("BR"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Brazil`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Brasil`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("pt", self.region()?)
+    }
+}
+
+/// A `RomanianVariant` enum for `Romanian` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::RomanianVariant;
+///
+/// let locale = Locale::Romanian(RomanianVariant::Default);
+/// assert_eq!(locale.to_string(), "ro");
+///
+/// let locale = Locale::Romanian(RomanianVariant::Moldova);
+/// assert_eq!(locale.to_string(), "ro_MD");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RomanianVariant {
+    Default,
+    Moldova,
+}
+
+impl RomanianVariant {
+    /// Return the region code of the variant, e.g. `MD` for `RomanianVariant::Moldova`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            RomanianVariant::Default => None,
+            RomanianVariant::Moldova => Some("MD"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Moldova`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Moldova, Republica`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("ro", self.region()?)
+    }
+}
+
+/// A `RussianVariant` enum for `Russian` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::RussianVariant;
+///
+/// let locale = Locale::Russian(RussianVariant::Default);
+/// assert_eq!(locale.to_string(), "ru");
+///
+/// let locale = Locale::Russian(RussianVariant::Moldova);
+/// assert_eq!(locale.to_string(), "ru_MD");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RussianVariant {
+    Default,
+    Moldova,
+}
+
+impl RussianVariant {
+    /// Return the region code of the variant, e.g. `MD` for `RussianVariant::Moldova`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            RussianVariant::Default => None,
+            RussianVariant::Moldova => Some("MD"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Moldova`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Молдавия`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("ru", self.region()?)
+    }
+}
+
+/// A `SpanishVariant` enum for `Spanish` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::SpanishVariant;
+///
+/// let locale = Locale::Spanish(SpanishVariant::Default);
+/// assert_eq!(locale.to_string(), "es");
+///
+/// let locale = Locale::Spanish(SpanishVariant::LatinAmerica);
+/// assert_eq!(locale.to_string(), "es_419");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Argentina);
+/// assert_eq!(locale.to_string(), "es_AR");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Bolivia);
+/// assert_eq!(locale.to_string(), "es_BO");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Chile);
+/// assert_eq!(locale.to_string(), "es_CL");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Colombia);
+/// assert_eq!(locale.to_string(), "es_CO");
+///
+/// let locale = Locale::Spanish(SpanishVariant::CostaRica);
+/// assert_eq!(locale.to_string(), "es_CR");
+///
+/// let locale = Locale::Spanish(SpanishVariant::DominicanRepublic);
+/// assert_eq!(locale.to_string(), "es_DO");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Ecuador);
+/// assert_eq!(locale.to_string(), "es_EC");
+///
+/// let locale = Locale::Spanish(SpanishVariant::ElSalvador);
+/// assert_eq!(locale.to_string(), "es_SV");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Guatemala);
+/// assert_eq!(locale.to_string(), "es_GT");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Honduras);
+/// assert_eq!(locale.to_string(), "es_HN");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Mexico);
+/// assert_eq!(locale.to_string(), "es_MX");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Nicaragua);
+/// assert_eq!(locale.to_string(), "es_NI");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Panama);
+/// assert_eq!(locale.to_string(), "es_PA");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Paraguay);
+/// assert_eq!(locale.to_string(), "es_PY");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Peru);
+/// assert_eq!(locale.to_string(), "es_PE");
+///
+/// let locale = Locale::Spanish(SpanishVariant::PuertoRico);
+/// assert_eq!(locale.to_string(), "es_PR");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Uruguay);
+/// assert_eq!(locale.to_string(), "es_UY");
+///
+/// let locale = Locale::Spanish(SpanishVariant::Venezuela);
+/// assert_eq!(locale.to_string(), "es_VE");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpanishVariant {
+    Default,
+    /// Latin American Spanish, `es_419`.
+    LatinAmerica,
+    Argentina,
+    Bolivia,
+    Chile,
+    Colombia,
+    CostaRica,
+    DominicanRepublic,
+    Ecuador,
+    ElSalvador,
+    Guatemala,
+    Honduras,
+    Mexico,
+    Nicaragua,
+    Panama,
+    Paraguay,
+    Peru,
+    PuertoRico,
+    Uruguay,
+    Venezuela,
+}
+
+impl SpanishVariant {
+    /// Return the region code of the variant, e.g. `419` for `SpanishVariant::LatinAmerica`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            SpanishVariant::Default => None,
+            SpanishVariant::LatinAmerica => Some("419"),
+            SpanishVariant::Argentina => Some("AR"),
+            SpanishVariant::Bolivia => Some("BO"),
+            SpanishVariant::Chile => Some("CL"),
+            SpanishVariant::Colombia => Some("CO"),
+            SpanishVariant::CostaRica => Some("CR"),
+            SpanishVariant::DominicanRepublic => Some("DO"),
+            SpanishVariant::Ecuador => Some("EC"),
+            SpanishVariant::ElSalvador => Some("SV"),
+            SpanishVariant::Guatemala => Some("GT"),
+            SpanishVariant::Honduras => Some("HN"),
+            SpanishVariant::Mexico => Some("MX"),
+            SpanishVariant::Nicaragua => Some("NI"),
+            SpanishVariant::Panama => Some("PA"),
+            SpanishVariant::Paraguay => Some("PY"),
+            SpanishVariant::Peru => Some("PE"),
+            SpanishVariant::PuertoRico => Some("PR"),
+            SpanishVariant::Uruguay => Some("UY"),
+            SpanishVariant::Venezuela => Some("VE"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Latin America`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Latinoamérica`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("es", self.region()?)
+    }
+}
+
+/// A `SwedishVariant` enum for `Swedish` locale.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::SwedishVariant;
+///
+/// let locale = Locale::Swedish(SwedishVariant::Default);
+/// assert_eq!(locale.to_string(), "sv");
+///
+/// let locale = Locale::Swedish(SwedishVariant::Finland);
+/// assert_eq!(locale.to_string(), "sv_FI");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwedishVariant {
+    Default,
+    Finland,
+}
+
+impl SwedishVariant {
+    /// Return the region code of the variant, e.g. `FI` for `SwedishVariant::Finland`, or
+    /// `None` for the default variant.
+    pub fn region(&self) -> Option<&'static str> {
+        match self {
+            SwedishVariant::Default => None,
+            SwedishVariant::Finland => Some("FI"),
+        }
+    }
+
+    /// Return the English name of the region of the variant, e.g. `Finland`.
+    pub fn english_name(&self) -> Option<&'static str> {
+        data::region_name("en", self.region()?)
+    }
+
+    /// Return the name of the region of the variant in its own language, e.g. `Finland`.
+    pub fn endonym(&self) -> Option<&'static str> {
+        data::region_name("sv", self.region()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::locale::error::Part;
+    use crate::locale::Locale;
+    use crate::locale::SwedishVariant;
+
+    #[test]
+    fn enumeration() {
+        let locales: Vec<Locale> = Locale::all().collect();
+
+        assert_eq!(locales.len(), 245);
+        assert!(locales.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for locale in locales {
+            assert_eq!(locale.code().parse::<Locale>(), Ok(locale));
+            assert!(!matches!(locale, Locale::Tag(_)));
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let tag: Locale = "sr-Latn".parse().unwrap();
+
+        assert!(Locale::Serbian < tag);
+        assert!(tag < Locale::Swedish(SwedishVariant::Default));
+        assert_eq!(tag.cmp(&tag), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn parents() {
+        let chain = |code: &str| -> Vec<String> {
+            let mut chain = vec![];
+            let mut locale = code.parse::<Locale>().ok();
+            while let Some(current) = locale {
+                chain.push(current.code().to_string());
+                locale = current.parent();
+            }
+
+            chain
+        };
+
+        assert_eq!(chain("zh-TW"), ["zh_TW", "zh_Hant"]);
+        assert_eq!(chain("zh-Hant-TW"), ["zh_Hant_TW", "zh_Hant"]);
+        assert_eq!(chain("zh-Hant-HK"), ["zh_Hant_HK", "zh_Hant"]);
+        assert_eq!(chain("zh-HK"), ["zh_HK", "zh_Hant"]);
+        assert_eq!(chain("zh-MO"), ["zh_MO", "zh_HK", "zh_Hant"]);
+        assert_eq!(chain("zh-SG"), ["zh_SG", "zh"]);
+        assert_eq!(chain("sr-Latn-RS"), ["sr_Latn_RS", "sr_Latn"]);
+        assert_eq!(chain("sr-RS"), ["sr_RS", "sr"]);
+        assert_eq!(chain("pa-Arab-PK"), ["pa_Arab_PK", "pa_Arab"]);
+        assert_eq!(chain("en-DE"), ["en_DE", "en_150", "en_001", "en"]);
+        assert_eq!(chain("pt-AO"), ["pt_AO", "pt_PT", "pt"]);
+        assert_eq!(chain("es-US"), ["es_US", "es_419", "es"]);
+        assert_eq!(chain("hi-Latn"), ["hi_Latn", "en_IN", "en_001", "en"]);
+        assert_eq!(
+            chain("ar-EG-u-nu-latn"),
+            ["ar_EG_u_nu_latn", "ar_EG", "ar"]
+        );
+    }
+
+    #[test]
+    fn names() {
+        let locale = |code: &str| code.parse::<Locale>().unwrap();
+
+        assert_eq!(locale("ja-JP").endonym(), "日本語 (日本)");
+        assert_eq!(locale("sr-Latn").endonym(), "српски (латиница)");
+        assert_eq!(locale("ru-UA").endonym(), "русский (Украина)");
+        assert_eq!(locale("el").endonym(), "Ελληνικά");
+        assert_eq!(locale("hi-IN").endonym(), "हिन्दी (भारत)");
+        assert_eq!(locale("ar-EG").endonym(), "العربية (مصر)");
+        assert_eq!(locale("zh-Hant-TW").endonym(), "中文（繁体，台湾）");
+        assert_eq!(locale("ko-KR").endonym(), "한국어(대한민국)");
+        assert_eq!(locale("am-ET").endonym(), "አማርኛ (ኢትዮጵያ)");
+        assert_eq!(locale("he").endonym(), "עברית");
+        assert_eq!(locale("ka").endonym(), "ქართული");
+
+        // languages without CLDR locale data are named using their autonym.
+        assert_eq!(Locale::Divehi.endonym(), "ދިވެހި");
+        assert_eq!(Locale::Bihari.endonym(), "भोजपुरी");
+
+        assert_eq!(
+            locale("en").display_name(&Locale::Japanese),
+            Some("英語".to_string())
+        );
+        assert_eq!(
+            locale("fr-CA").display_name(&locale("ar")),
+            Some("الفرنسية (كندا)".to_string())
+        );
+        assert_eq!(locale("en").display_name(&Locale::Divehi), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "xx".parse::<Locale>().unwrap_err();
+        assert_eq!(error.part(), Part::Language);
+        assert_eq!(error.subtag(), "xx");
+
+        let error = "EN-gb-oxendict-u".parse::<Locale>().unwrap_err();
+        assert_eq!(error.part(), Part::Extension);
+        assert_eq!(error.subtag(), "u");
+
+        let error = Locale::try_from("en-U$").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "locale: invalid region subtag `'U$'` in `'en-U$'`."
+        );
+        assert!(matches!(
+            error,
+            Error::ParseError(error) if error.part() == Part::Region
+        ));
+    }
+}
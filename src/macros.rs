@@ -15,12 +15,26 @@ macro_rules! context {
                 (stringify!($k).to_string(), $crate::context::Value::from($v)),
             )*];
 
-            $crate::context::Context::new(values, Some($c))
+            $crate::context::Context::new(values, None).with_count($c)
+        }
+    );
+    ($($k:ident = $v:expr),+, # = $c:expr) => (
+        {
+            let values: Vec<(String, $crate::context::Value)> = vec![$(
+                (stringify!($k).to_string(), $crate::context::Value::from($v)),
+            )*];
+
+            $crate::context::Context::new(values, None).with_count($c).with_ordinal(true)
         }
     );
     (? = $c:expr) => (
         {
-            $crate::context::Context::new(vec![], Some($c))
+            $crate::context::Context::new(vec![], None).with_count($c)
+        }
+    );
+    (# = $c:expr) => (
+        {
+            $crate::context::Context::new(vec![], None).with_count($c).with_ordinal(true)
         }
     );
     () => (
@@ -0,0 +1,260 @@
+use crate::locale::Locale;
+
+/// Returns the matching distance between a requested locale and an available locale, or `None` if
+/// the locales don't match at all, i.e. they are of different languages.
+///
+/// The lower the distance, the better the match:
+///
+/// - `0`: the locales are identical.
+/// - `1..`: the available locale is a parent of the requested locale, e.g. `es_MX` → `es_419` ( `1` ),
+///   `es_MX` → `es` ( `2` ).
+/// - `10`: the requested locale is a parent of the available locale, e.g. `en` → `en_US`.
+/// - `20`: the locales share a parent, e.g. `es_MX` → `es_AR` share `es_419`.
+/// - `30`: the locales are of the same language, e.g. `fr_CA` → `fr_BE`.
+///
+/// A script mismatch ( e.g. `zh_TW` uses the traditional script, while `zh_CN` uses the simplified
+/// script ) adds `40` to the distance.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+/// use tarjama::locale::SpanishVariant;
+/// use tarjama::negotiation::distance;
+///
+/// assert_eq!(distance(&Locale::Spanish(SpanishVariant::Mexico), &Locale::Spanish(SpanishVariant::Mexico)), Some(0));
+/// assert_eq!(distance(&Locale::Spanish(SpanishVariant::Mexico), &Locale::Spanish(SpanishVariant::LatinAmerica)), Some(1));
+/// assert_eq!(distance(&Locale::Spanish(SpanishVariant::Mexico), &Locale::Spanish(SpanishVariant::Default)), Some(2));
+/// assert_eq!(distance(&Locale::English(EnglishVariant::Default), &Locale::English(EnglishVariant::UnitedStates)), Some(10));
+/// assert_eq!(distance(&Locale::Spanish(SpanishVariant::Mexico), &Locale::Spanish(SpanishVariant::Argentina)), Some(20));
+/// assert_eq!(distance(&Locale::French(FrenchVariant::Canada), &Locale::French(FrenchVariant::Belgium)), Some(30));
+/// assert_eq!(distance(&Locale::French(FrenchVariant::Canada), &Locale::English(EnglishVariant::Canada)), None);
+/// ```
+pub fn distance(requested: &Locale, available: &Locale) -> Option<u32> {
+    if requested.with_default_variant() != available.with_default_variant() {
+        return None;
+    }
+
+    let penalty = if requested.default_script() == available.default_script() {
+        0
+    } else {
+        40
+    };
+
+    let requested_parents = parents(requested);
+    if let Some(position) =
+        requested_parents.iter().position(|l| l == available)
+    {
+        return Some(position as u32 + penalty);
+    }
+
+    let available_parents = parents(available);
+    let distance = if available_parents.contains(requested) {
+        10
+    } else if requested_parents[1..]
+        .iter()
+        .any(|l| available_parents[1..].contains(l) && l.has_variant())
+    {
+        20
+    } else {
+        30
+    };
+
+    Some(distance + penalty)
+}
+
+/// Returns the best available locale for the given requested locales, and their quality weights,
+/// as found in an `Accept-Language` header.
+///
+/// Requested locales are tried from the highest to the lowest weight, keeping the given order for
+/// equal weights, locales with a weight of `0` are not acceptable, and ignored.
+///
+/// For the first requested locale that matches any of the available locales, the available locale
+/// with the lowest [`distance`] is returned, ties are broken using the locale code.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::FrenchVariant;
+/// use tarjama::locale::GermanVariant;
+/// use tarjama::negotiation::negotiate;
+///
+/// let available = [
+///     Locale::English(EnglishVariant::Default),
+///     Locale::French(FrenchVariant::Default),
+///     Locale::French(FrenchVariant::Belgium),
+/// ];
+///
+/// assert_eq!(
+///     negotiate(
+///         [
+///             (Locale::German(GermanVariant::Default), 1.0),
+///             (Locale::French(FrenchVariant::Canada), 0.9),
+///             (Locale::English(EnglishVariant::Default), 0.8),
+///         ],
+///         &available,
+///     ),
+///     Some(Locale::French(FrenchVariant::Default))
+/// );
+///
+/// assert_eq!(
+///     negotiate(
+///         [
+///             (Locale::French(FrenchVariant::Canada), 0.5),
+///             (Locale::English(EnglishVariant::UnitedStates), 0.8),
+///         ],
+///         &available,
+///     ),
+///     Some(Locale::English(EnglishVariant::Default))
+/// );
+///
+/// assert_eq!(negotiate([(Locale::German(GermanVariant::Default), 1.0)], &available), None);
+/// ```
+pub fn negotiate<'a, I, A>(requested: I, available: A) -> Option<Locale>
+where
+    I: IntoIterator<Item = (Locale, f32)>,
+    A: IntoIterator<Item = &'a Locale>,
+{
+    let available: Vec<&Locale> = available.into_iter().collect();
+    let mut requested: Vec<(Locale, f32)> =
+        requested.into_iter().filter(|(_, q)| *q > 0.0).collect();
+    // stable sort, keeps the given order for equal weights.
+    requested.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    for (locale, _) in requested {
+        let best = available
+            .iter()
+            .filter_map(|candidate| {
+                distance(&locale, candidate).map(|d| (d, *candidate))
+            })
+            .min_by(|(a, a_locale), (b, b_locale)| {
+                a.cmp(b).then_with(|| a_locale.code().cmp(b_locale.code()))
+            });
+
+        if let Some((_, locale)) = best {
+            return Some(*locale);
+        }
+    }
+
+    None
+}
+
+/// The locale itself, followed by its parents.
+fn parents(locale: &Locale) -> Vec<Locale> {
+    let mut parents = vec![*locale];
+    while let Some(parent) = parents[parents.len() - 1].parent() {
+        parents.push(parent);
+    }
+
+    parents
+}
+
+#[cfg(test)]
+mod test {
+    use crate::locale::ChineseVariant;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+    use crate::locale::SpanishVariant;
+    use crate::negotiation::distance;
+    use crate::negotiation::negotiate;
+
+    #[test]
+    fn script_distance() {
+        assert_eq!(
+            distance(
+                &Locale::Chinese(ChineseVariant::HongKong),
+                &Locale::Chinese(ChineseVariant::Taiwan)
+            ),
+            Some(20)
+        );
+        assert_eq!(
+            distance(
+                &Locale::Chinese(ChineseVariant::Taiwan),
+                &Locale::try_from("zh-Hant").unwrap()
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            distance(
+                &Locale::Chinese(ChineseVariant::Taiwan),
+                &Locale::Chinese(ChineseVariant::Default)
+            ),
+            Some(70)
+        );
+        assert_eq!(
+            distance(
+                &Locale::Chinese(ChineseVariant::Taiwan),
+                &Locale::Chinese(ChineseVariant::China)
+            ),
+            Some(70)
+        );
+    }
+
+    #[test]
+    fn negotiation() {
+        let available = [
+            Locale::Spanish(SpanishVariant::Argentina),
+            Locale::Spanish(SpanishVariant::Default),
+            Locale::Spanish(SpanishVariant::LatinAmerica),
+            Locale::Chinese(ChineseVariant::China),
+            Locale::Chinese(ChineseVariant::HongKong),
+            Locale::English(EnglishVariant::UnitedKingdom),
+            Locale::English(EnglishVariant::UnitedStates),
+        ];
+
+        for (requested, expected) in [
+            (
+                Locale::Spanish(SpanishVariant::Mexico),
+                Locale::Spanish(SpanishVariant::LatinAmerica),
+            ),
+            (
+                Locale::Spanish(SpanishVariant::Default),
+                Locale::Spanish(SpanishVariant::Default),
+            ),
+            (
+                Locale::Chinese(ChineseVariant::Taiwan),
+                Locale::Chinese(ChineseVariant::HongKong),
+            ),
+            (
+                Locale::Chinese(ChineseVariant::Singapore),
+                Locale::Chinese(ChineseVariant::China),
+            ),
+            (
+                Locale::English(EnglishVariant::Default),
+                Locale::English(EnglishVariant::UnitedKingdom),
+            ),
+            (
+                Locale::English(EnglishVariant::Australia),
+                Locale::English(EnglishVariant::UnitedKingdom),
+            ),
+            (
+                Locale::try_from("zh-Hant-MO").unwrap(),
+                Locale::Chinese(ChineseVariant::HongKong),
+            ),
+            (
+                Locale::try_from("zh-MO").unwrap(),
+                Locale::Chinese(ChineseVariant::HongKong),
+            ),
+        ] {
+            assert_eq!(
+                negotiate([(requested, 1.0)], &available),
+                Some(expected)
+            );
+        }
+
+        assert_eq!(
+            negotiate(
+                [
+                    (Locale::English(EnglishVariant::Default), 0.0),
+                    (Locale::Spanish(SpanishVariant::Default), 0.2),
+                ],
+                &available
+            ),
+            Some(Locale::Spanish(SpanishVariant::Default))
+        );
+    }
+}
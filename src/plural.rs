@@ -0,0 +1,1076 @@
+use crate::error::Error;
+use crate::locale::Locale;
+
+use core::result::Result as CoreResult;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+
+/// A CLDR plural category.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::plural::PluralCategory;
+///
+/// let category: PluralCategory = "few".try_into().unwrap();
+///
+/// assert_eq!(category, PluralCategory::Few);
+/// assert_eq!(category.to_string(), "few");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl TryFrom<&str> for PluralCategory {
+    type Error = Error;
+
+    fn try_from(value: &str) -> CoreResult<Self, Self::Error> {
+        match value {
+            "zero" => Ok(PluralCategory::Zero),
+            "one" => Ok(PluralCategory::One),
+            "two" => Ok(PluralCategory::Two),
+            "few" => Ok(PluralCategory::Few),
+            "many" => Ok(PluralCategory::Many),
+            "other" => Ok(PluralCategory::Other),
+            _ => Err(Error::FormattingError(format!(
+                "formatting: invalid plural category, expected one of `'zero'`, `'one'`, `'two'`, `'few'`, `'many'` or `'other'`, found `'{value}'`."
+            ))),
+        }
+    }
+}
+
+impl Display for PluralCategory {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let name = match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// The CLDR plural operands of a number.
+///
+/// - `n`: the absolute value of the number.
+/// - `i`: the integer digits of `n`.
+/// - `v`: the number of visible fraction digits, with trailing zeros.
+/// - `w`: the number of visible fraction digits, without trailing zeros.
+/// - `f`: the visible fraction digits, with trailing zeros.
+/// - `t`: the visible fraction digits, without trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::plural::PluralOperands;
+///
+/// let operands = PluralOperands::from(-42);
+///
+/// assert_eq!(operands.n, 42.0);
+/// assert_eq!(operands.i, 42);
+/// assert_eq!(operands.v, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PluralOperands {
+    pub n: f64,
+    pub i: u64,
+    pub v: usize,
+    pub w: usize,
+    pub f: u64,
+    pub t: u64,
+}
+
+impl From<i64> for PluralOperands {
+    fn from(value: i64) -> Self {
+        let i = value.unsigned_abs();
+
+        PluralOperands { n: i as f64, i, v: 0, w: 0, f: 0, t: 0 }
+    }
+}
+
+/// Create `PluralOperands` from a decimal number.
+///
+/// The visible fraction digits are those of the shortest representation of the number, so
+/// `1.50` has the same operands as `1.5`, use the `&str` conversion to keep trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::plural::PluralOperands;
+///
+/// let operands = PluralOperands::from(-1.25);
+///
+/// assert_eq!(operands.n, 1.25);
+/// assert_eq!(operands.i, 1);
+/// assert_eq!(operands.v, 2);
+/// assert_eq!(operands.f, 25);
+/// ```
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        if !value.is_finite() {
+            return PluralOperands { n: value.abs(), ..Default::default() };
+        }
+
+        let representation = value.abs().to_string();
+
+        PluralOperands::try_from(representation.as_str())
+            .unwrap_or(PluralOperands { n: value.abs(), ..Default::default() })
+    }
+}
+
+/// Create `PluralOperands` from the string representation of a decimal number.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::plural::PluralOperands;
+///
+/// let operands: PluralOperands = "1.50".try_into().unwrap();
+///
+/// assert_eq!(operands.i, 1);
+/// assert_eq!(operands.v, 2);
+/// assert_eq!(operands.w, 1);
+/// assert_eq!(operands.f, 50);
+/// assert_eq!(operands.t, 5);
+///
+/// assert!(PluralOperands::try_from("1.5.0").is_err());
+/// ```
+impl TryFrom<&str> for PluralOperands {
+    type Error = Error;
+
+    fn try_from(value: &str) -> CoreResult<Self, Self::Error> {
+        let invalid = || {
+            Error::FormattingError(format!(
+                "formatting: invalid plural operand, expected a decimal number, found `'{value}'`."
+            ))
+        };
+
+        let digits = value.trim().trim_start_matches(['-', '+']);
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+
+        if integer.is_empty()
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let n = digits.parse::<f64>().map_err(|_| invalid())?;
+        let i = integer.parse::<u64>().unwrap_or(u64::MAX);
+        let trimmed = fraction.trim_end_matches('0');
+        // only the first 18 fraction digits are significant for plural rules, and fit in a `u64`.
+        let f =
+            fraction.get(..18).unwrap_or(fraction).parse::<u64>().unwrap_or(0);
+        let t =
+            trimmed.get(..18).unwrap_or(trimmed).parse::<u64>().unwrap_or(0);
+
+        Ok(PluralOperands { n, i, v: fraction.len(), w: trimmed.len(), f, t })
+    }
+}
+
+impl PluralOperands {
+    /// `n` is an integer, and equals `value`.
+    fn n_is(&self, value: u64) -> bool {
+        self.f == 0 && self.i == value
+    }
+
+    /// `n` is an integer, and is within `from..=to`.
+    fn n_in(&self, from: u64, to: u64) -> bool {
+        self.f == 0 && self.i >= from && self.i <= to
+    }
+
+    /// `n % modulo` is an integer, and is within `from..=to`.
+    fn n_mod_in(&self, modulo: u64, from: u64, to: u64) -> bool {
+        self.f == 0 && self.i % modulo >= from && self.i % modulo <= to
+    }
+}
+
+/// Select the CLDR cardinal plural category of `operands` for `locale`.
+///
+/// Languages without CLDR plural rules use the root rules, where every number is `other`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::ArabicVariant;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::plural::cardinal;
+/// use tarjama::plural::PluralCategory;
+///
+/// let english = Locale::English(EnglishVariant::Default);
+///
+/// assert_eq!(cardinal(&english, &1.into()), PluralCategory::One);
+/// assert_eq!(cardinal(&english, &2.into()), PluralCategory::Other);
+///
+/// let arabic = Locale::Arabic(ArabicVariant::Tunisia);
+///
+/// assert_eq!(cardinal(&arabic, &0.into()), PluralCategory::Zero);
+/// assert_eq!(cardinal(&arabic, &2.into()), PluralCategory::Two);
+/// assert_eq!(cardinal(&arabic, &103.into()), PluralCategory::Few);
+/// assert_eq!(cardinal(&arabic, &111.into()), PluralCategory::Many);
+/// assert_eq!(cardinal(&arabic, &100.into()), PluralCategory::Other);
+///
+/// assert_eq!(cardinal(&Locale::Polish, &22.into()), PluralCategory::Few);
+/// assert_eq!(cardinal(&Locale::Polish, &25.into()), PluralCategory::Many);
+/// ```
+pub fn cardinal(locale: &Locale, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let o = operands;
+    let (i, v, f, t) = (o.i, o.v, o.f, o.t);
+
+    match locale.with_default_variant().code() {
+        "af" | "an" | "az" | "bg" | "ce" | "dv" | "ee" | "el" | "eo"
+        | "eu" | "fo" | "ha" | "hu" | "ka" | "kk" | "kl" | "ks" | "ku"
+        | "ky" | "lb" | "lg" | "ml" | "mn" | "mr" | "nd" | "ne" | "nn"
+        | "no" | "nr" | "ny" | "om" | "or" | "os" | "ps" | "rm" | "sd"
+        | "sn" | "so" | "sq" | "ss" | "st" | "ta" | "te" | "tk" | "tn"
+        | "tr" | "ts" | "ug" | "uz" | "ve" | "xh" => {
+            if o.n_is(1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "de" | "en" | "et" | "fi" | "fy" | "gl" | "io" | "nl" | "sc"
+        | "sv" | "sw" | "ur" | "yi" => {
+            if i == 1 && v == 0 {
+                One
+            } else {
+                Other
+            }
+        }
+        "ak" | "bh" | "ln" | "mg" | "pa" | "ti" | "wa" => {
+            if o.n_in(0, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "am" | "as" | "bn" | "fa" | "gu" | "hi" | "kn" | "zu" => {
+            if i == 0 || o.n_is(1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "ff" | "hy" => {
+            if i <= 1 {
+                One
+            } else {
+                Other
+            }
+        }
+        "si" => {
+            if o.n_in(0, 1) || (i == 0 && f == 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "da" => {
+            if o.n_is(1) || (t != 0 && i <= 1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "is" => {
+            if (t == 0 && i % 10 == 1 && i % 100 != 11)
+                || (t % 10 == 1 && t % 100 != 11)
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "mk" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11)
+                || (f % 10 == 1 && f % 100 != 11)
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "tl" => {
+            if (v == 0 && ![4, 6, 9].contains(&(i % 10)))
+                || (v != 0 && ![4, 6, 9].contains(&(f % 10)))
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "lv" => {
+            if o.n_mod_in(10, 0, 0)
+                || o.n_mod_in(100, 11, 19)
+                || (v == 2 && (11..=19).contains(&(f % 100)))
+            {
+                Zero
+            } else if (o.n_mod_in(10, 1, 1) && !o.n_mod_in(100, 11, 11))
+                || (f % 10 == 1 && (v != 2 || f % 100 != 11))
+            {
+                One
+            } else {
+                Other
+            }
+        }
+        "se" | "iu" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else {
+                Other
+            }
+        }
+        "he" => {
+            if (i == 1 && v == 0) || (i == 0 && v != 0) {
+                One
+            } else if i == 2 && v == 0 {
+                Two
+            } else {
+                Other
+            }
+        }
+        "es" => {
+            if o.n_is(1) {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ca" | "it" => {
+            if i == 1 && v == 0 {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "fr" | "pt" => {
+            if i <= 1 {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ro" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v != 0 || o.n_is(0) || o.n_mod_in(100, 1, 19) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "bs" | "hr" | "sr" => {
+            if (v == 0 && i % 10 == 1 && i % 100 != 11)
+                || (f % 10 == 1 && f % 100 != 11)
+            {
+                One
+            } else if (v == 0
+                && (2..=4).contains(&(i % 10))
+                && !(12..=14).contains(&(i % 100)))
+                || ((2..=4).contains(&(f % 10))
+                    && !(12..=14).contains(&(f % 100)))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+        "gd" => {
+            if o.n_is(1) || o.n_is(11) {
+                One
+            } else if o.n_is(2) || o.n_is(12) {
+                Two
+            } else if o.n_in(3, 10) || o.n_in(13, 19) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "sl" => {
+            if v == 0 && i % 100 == 1 {
+                One
+            } else if v == 0 && i % 100 == 2 {
+                Two
+            } else if v != 0 || (3..=4).contains(&(i % 100)) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "cs" | "sk" => {
+            if i == 1 && v == 0 {
+                One
+            } else if (2..=4).contains(&i) && v == 0 {
+                Few
+            } else if v != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "pl" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v == 0
+                && (2..=4).contains(&(i % 10))
+                && !(12..=14).contains(&(i % 100))
+            {
+                Few
+            } else if v == 0
+                && ((i != 1 && i % 10 <= 1)
+                    || (5..=9).contains(&(i % 10))
+                    || (12..=14).contains(&(i % 100)))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "be" => {
+            if o.n_mod_in(10, 1, 1) && !o.n_mod_in(100, 11, 11) {
+                One
+            } else if o.n_mod_in(10, 2, 4) && !o.n_mod_in(100, 12, 14) {
+                Few
+            } else if o.n_mod_in(10, 0, 0)
+                || o.n_mod_in(10, 5, 9)
+                || o.n_mod_in(100, 11, 14)
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "lt" => {
+            if o.n_mod_in(10, 1, 1) && !o.n_mod_in(100, 11, 19) {
+                One
+            } else if o.n_mod_in(10, 2, 9) && !o.n_mod_in(100, 11, 19) {
+                Few
+            } else if f != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ru" | "uk" => {
+            if v == 0 && i % 10 == 1 && i % 100 != 11 {
+                One
+            } else if v == 0
+                && (2..=4).contains(&(i % 10))
+                && !(12..=14).contains(&(i % 100))
+            {
+                Few
+            } else if v == 0
+                && (i % 10 == 0
+                    || (5..=9).contains(&(i % 10))
+                    || (11..=14).contains(&(i % 100)))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "br" => {
+            if o.n_mod_in(10, 1, 1) && ![11, 71, 91].contains(&(i % 100)) {
+                One
+            } else if o.n_mod_in(10, 2, 2)
+                && ![12, 72, 92].contains(&(i % 100))
+            {
+                Two
+            } else if (o.n_mod_in(10, 3, 4) || o.n_mod_in(10, 9, 9))
+                && !o.n_mod_in(100, 10, 19)
+                && !o.n_mod_in(100, 70, 79)
+                && !o.n_mod_in(100, 90, 99)
+            {
+                Few
+            } else if f == 0 && i != 0 && i % 1_000_000 == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "mt" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_is(0) || o.n_mod_in(100, 3, 10) {
+                Few
+            } else if o.n_mod_in(100, 11, 19) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ga" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_in(3, 6) {
+                Few
+            } else if o.n_in(7, 10) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "gv" => {
+            if v == 0 && i % 10 == 1 {
+                One
+            } else if v == 0 && i % 10 == 2 {
+                Two
+            } else if v == 0 && [0, 20, 40, 60, 80].contains(&(i % 100)) {
+                Few
+            } else if v != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+        "kw" => {
+            if o.n_is(0) {
+                Zero
+            } else if o.n_is(1) {
+                One
+            } else if f == 0
+                && ([2, 22, 42, 62, 82].contains(&(i % 100))
+                    || (i % 1000 == 0
+                        && ((1000..=20000).contains(&(i % 100_000))
+                            || [40000, 60000, 80000]
+                                .contains(&(i % 100_000))))
+                    || (i != 0 && i % 1_000_000 == 100_000))
+            {
+                Two
+            } else if f == 0 && [3, 23, 43, 63, 83].contains(&(i % 100)) {
+                Few
+            } else if f == 0
+                && i != 1
+                && [1, 21, 41, 61, 81].contains(&(i % 100))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ar" => {
+            if o.n_is(0) {
+                Zero
+            } else if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_mod_in(100, 3, 10) {
+                Few
+            } else if o.n_mod_in(100, 11, 99) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "cy" => {
+            if o.n_is(0) {
+                Zero
+            } else if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_is(3) {
+                Few
+            } else if o.n_is(6) {
+                Many
+            } else {
+                Other
+            }
+        }
+        _ => Other,
+    }
+}
+
+/// Select the CLDR ordinal plural category of `operands` for `locale`.
+///
+/// Languages without CLDR ordinal rules use the root rules, where every number is `other`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::SwedishVariant;
+/// use tarjama::plural::ordinal;
+/// use tarjama::plural::PluralCategory;
+///
+/// let english = Locale::English(EnglishVariant::Default);
+///
+/// assert_eq!(ordinal(&english, &1.into()), PluralCategory::One);
+/// assert_eq!(ordinal(&english, &22.into()), PluralCategory::Two);
+/// assert_eq!(ordinal(&english, &43.into()), PluralCategory::Few);
+/// assert_eq!(ordinal(&english, &11.into()), PluralCategory::Other);
+///
+/// let swedish = Locale::Swedish(SwedishVariant::Default);
+///
+/// assert_eq!(ordinal(&swedish, &2.into()), PluralCategory::One);
+/// assert_eq!(ordinal(&swedish, &3.into()), PluralCategory::Other);
+/// ```
+pub fn ordinal(locale: &Locale, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let o = operands;
+    let i = o.i;
+
+    match locale.with_default_variant().code() {
+        "en" => {
+            if o.n_mod_in(10, 1, 1) && !o.n_mod_in(100, 11, 11) {
+                One
+            } else if o.n_mod_in(10, 2, 2) && !o.n_mod_in(100, 12, 12) {
+                Two
+            } else if o.n_mod_in(10, 3, 3) && !o.n_mod_in(100, 13, 13) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "sv" => {
+            if o.n_mod_in(10, 1, 2) && !o.n_mod_in(100, 11, 12) {
+                One
+            } else {
+                Other
+            }
+        }
+        "fr" | "ga" | "hy" | "lo" | "ms" | "ro" | "tl" | "vi" => {
+            if o.n_is(1) {
+                One
+            } else {
+                Other
+            }
+        }
+        "hu" => {
+            if o.n_is(1) || o.n_is(5) {
+                One
+            } else {
+                Other
+            }
+        }
+        "ne" => {
+            if o.n_in(1, 4) {
+                One
+            } else {
+                Other
+            }
+        }
+        "be" => {
+            if o.n_mod_in(10, 2, 3) && !o.n_mod_in(100, 12, 13) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "uk" => {
+            if o.n_mod_in(10, 3, 3) && !o.n_mod_in(100, 13, 13) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "tk" => {
+            if o.n_mod_in(10, 6, 6) || o.n_mod_in(10, 9, 9) || o.n_is(10) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "kk" => {
+            if o.n_mod_in(10, 6, 6)
+                || o.n_mod_in(10, 9, 9)
+                || (o.n_mod_in(10, 0, 0) && !o.n_is(0))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "it" | "sc" => {
+            if o.n_is(11) || o.n_is(8) || o.n_is(80) || o.n_is(800) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "ka" => {
+            if i == 1 {
+                One
+            } else if i == 0
+                || (2..=20).contains(&(i % 100))
+                || [40, 60, 80].contains(&(i % 100))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "sq" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_mod_in(10, 4, 4) && !o.n_mod_in(100, 14, 14) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "kw" => {
+            if o.n_in(1, 4)
+                || o.n_mod_in(100, 1, 4)
+                || o.n_mod_in(100, 21, 24)
+                || o.n_mod_in(100, 41, 44)
+                || o.n_mod_in(100, 61, 64)
+                || o.n_mod_in(100, 81, 84)
+            {
+                One
+            } else if o.n_is(5) || o.n_mod_in(100, 5, 5) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "mr" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_in(2, 3) {
+                Two
+            } else if o.n_is(4) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "gd" => {
+            if o.n_is(1) || o.n_is(11) {
+                One
+            } else if o.n_is(2) || o.n_is(12) {
+                Two
+            } else if o.n_is(3) || o.n_is(13) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "ca" => {
+            if o.n_is(1) || o.n_is(3) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_is(4) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "mk" => {
+            if i % 10 == 1 && i % 100 != 11 {
+                One
+            } else if i % 10 == 2 && i % 100 != 12 {
+                Two
+            } else if [7, 8].contains(&(i % 10))
+                && ![17, 18].contains(&(i % 100))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "az" => {
+            if [1, 2, 5, 7, 8].contains(&(i % 10))
+                || [20, 50, 70, 80].contains(&(i % 100))
+            {
+                One
+            } else if [3, 4].contains(&(i % 10))
+                || [100, 200, 300, 400, 500, 600, 700, 800, 900]
+                    .contains(&(i % 1000))
+            {
+                Few
+            } else if i == 0
+                || i % 10 == 6
+                || [40, 60, 90].contains(&(i % 100))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+        "gu" | "hi" => {
+            if o.n_is(1) {
+                One
+            } else if o.n_in(2, 3) {
+                Two
+            } else if o.n_is(4) {
+                Few
+            } else if o.n_is(6) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "as" | "bn" => {
+            if o.n_is(1) || o.n_is(5) || o.n_in(7, 10) {
+                One
+            } else if o.n_in(2, 3) {
+                Two
+            } else if o.n_is(4) {
+                Few
+            } else if o.n_is(6) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "or" => {
+            if o.n_is(1) || o.n_is(5) || o.n_in(7, 9) {
+                One
+            } else if o.n_in(2, 3) {
+                Two
+            } else if o.n_is(4) {
+                Few
+            } else if o.n_is(6) {
+                Many
+            } else {
+                Other
+            }
+        }
+        "cy" => {
+            if o.n_is(0) || o.n_in(7, 9) {
+                Zero
+            } else if o.n_is(1) {
+                One
+            } else if o.n_is(2) {
+                Two
+            } else if o.n_in(3, 4) {
+                Few
+            } else if o.n_in(5, 6) {
+                Many
+            } else {
+                Other
+            }
+        }
+        _ => Other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::locale::ArabicVariant;
+    use crate::locale::EnglishVariant;
+    use crate::locale::FrenchVariant;
+    use crate::locale::Locale;
+    use crate::locale::RussianVariant;
+    use crate::locale::SwedishVariant;
+    use crate::plural::cardinal;
+    use crate::plural::ordinal;
+    use crate::plural::PluralCategory;
+    use crate::plural::PluralOperands;
+
+    macro_rules! assert_cardinal {
+        ($locale:expr, [$($n:literal),+], $category:expr) => {
+            $(
+                assert_eq!(
+                    cardinal(&$locale, &($n as i64).into()),
+                    $category,
+                    "{} in {}", $n, $locale
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn cardinal_english() {
+        let locale = Locale::English(EnglishVariant::UnitedKingdom);
+
+        assert_cardinal!(locale, [1], PluralCategory::One);
+        assert_cardinal!(
+            locale,
+            [0, 2, 3, 11, 21, 101],
+            PluralCategory::Other
+        );
+    }
+
+    #[test]
+    fn cardinal_french() {
+        let locale = Locale::French(FrenchVariant::Canada);
+
+        assert_cardinal!(locale, [0, 1], PluralCategory::One);
+        assert_cardinal!(locale, [1000000, 2000000], PluralCategory::Many);
+        assert_cardinal!(locale, [2, 10, 1000001], PluralCategory::Other);
+    }
+
+    #[test]
+    fn cardinal_arabic() {
+        let locale = Locale::Arabic(ArabicVariant::Default);
+
+        assert_cardinal!(locale, [0], PluralCategory::Zero);
+        assert_cardinal!(locale, [1], PluralCategory::One);
+        assert_cardinal!(locale, [2], PluralCategory::Two);
+        assert_cardinal!(locale, [3, 10, 103, 1010], PluralCategory::Few);
+        assert_cardinal!(
+            locale,
+            [11, 26, 99, 111, 1099],
+            PluralCategory::Many
+        );
+        assert_cardinal!(locale, [100, 101, 102, 1000], PluralCategory::Other);
+    }
+
+    #[test]
+    fn cardinal_russian() {
+        let locale = Locale::Russian(RussianVariant::Default);
+
+        assert_cardinal!(locale, [1, 21, 101], PluralCategory::One);
+        assert_cardinal!(locale, [2, 3, 4, 22, 104], PluralCategory::Few);
+        assert_cardinal!(locale, [0, 5, 11, 12, 14, 25], PluralCategory::Many);
+    }
+
+    #[test]
+    fn cardinal_polish() {
+        let locale = Locale::Polish;
+
+        assert_cardinal!(locale, [1], PluralCategory::One);
+        assert_cardinal!(locale, [2, 3, 4, 22, 24], PluralCategory::Few);
+        assert_cardinal!(locale, [0, 5, 12, 14, 21, 25], PluralCategory::Many);
+    }
+
+    macro_rules! assert_ordinal {
+        ($locale:expr, [$($n:literal),+], $category:expr) => {
+            $(
+                assert_eq!(
+                    ordinal(&$locale, &($n as i64).into()),
+                    $category,
+                    "{} in {}", $n, $locale
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn ordinal_english() {
+        let locale = Locale::English(EnglishVariant::UnitedStates);
+
+        assert_ordinal!(locale, [1, 21, 101], PluralCategory::One);
+        assert_ordinal!(locale, [2, 22, 102], PluralCategory::Two);
+        assert_ordinal!(locale, [3, 23, 103], PluralCategory::Few);
+        assert_ordinal!(
+            locale,
+            [0, 4, 11, 12, 13, 111],
+            PluralCategory::Other
+        );
+    }
+
+    #[test]
+    fn ordinal_french_arabic_swedish() {
+        let french = Locale::French(FrenchVariant::Default);
+
+        assert_ordinal!(french, [1], PluralCategory::One);
+        assert_ordinal!(french, [0, 2, 21], PluralCategory::Other);
+
+        let arabic = Locale::Arabic(ArabicVariant::Default);
+
+        assert_ordinal!(arabic, [0, 1, 2, 3, 11, 100], PluralCategory::Other);
+
+        let swedish = Locale::Swedish(SwedishVariant::Finland);
+
+        assert_ordinal!(swedish, [1, 2, 21, 22, 101], PluralCategory::One);
+        assert_ordinal!(swedish, [0, 3, 11, 12, 111], PluralCategory::Other);
+    }
+
+    #[test]
+    fn ordinal_welsh() {
+        let locale = Locale::Welsh;
+
+        assert_ordinal!(locale, [0, 7, 8, 9], PluralCategory::Zero);
+        assert_ordinal!(locale, [1], PluralCategory::One);
+        assert_ordinal!(locale, [2], PluralCategory::Two);
+        assert_ordinal!(locale, [3, 4], PluralCategory::Few);
+        assert_ordinal!(locale, [5, 6], PluralCategory::Many);
+        assert_ordinal!(locale, [10, 21], PluralCategory::Other);
+    }
+
+    #[test]
+    fn cardinal_decimals() {
+        let english = Locale::English(EnglishVariant::Default);
+
+        assert_eq!(cardinal(&english, &1.5.into()), PluralCategory::Other);
+        assert_eq!(
+            cardinal(&english, &"1.0".try_into().unwrap()),
+            PluralCategory::Other
+        );
+
+        let french = Locale::French(FrenchVariant::Default);
+
+        assert_eq!(cardinal(&french, &1.5.into()), PluralCategory::One);
+        assert_eq!(cardinal(&french, &0.5.into()), PluralCategory::One);
+        assert_eq!(cardinal(&french, &2.5.into()), PluralCategory::Other);
+
+        let russian = Locale::Russian(RussianVariant::Default);
+
+        assert_eq!(cardinal(&russian, &1.5.into()), PluralCategory::Other);
+
+        let arabic = Locale::Arabic(ArabicVariant::Default);
+
+        assert_eq!(cardinal(&arabic, &1.5.into()), PluralCategory::Other);
+        assert_eq!(cardinal(&arabic, &3.0.into()), PluralCategory::Few);
+
+        assert_eq!(
+            cardinal(&Locale::Czech, &1.5.into()),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            cardinal(&Locale::Latvian, &"0.1".try_into().unwrap()),
+            PluralCategory::One
+        );
+    }
+
+    #[test]
+    fn operands() {
+        let operands: PluralOperands = "-0.0120".try_into().unwrap();
+
+        assert_eq!(operands.n, 0.012);
+        assert_eq!(operands.i, 0);
+        assert_eq!(operands.v, 4);
+        assert_eq!(operands.w, 3);
+        assert_eq!(operands.f, 120);
+        assert_eq!(operands.t, 12);
+
+        assert_eq!(PluralOperands::from(1.0), PluralOperands::from(1));
+
+        assert!(PluralOperands::try_from("").is_err());
+        assert!(PluralOperands::try_from(".5").is_err());
+        assert!(PluralOperands::try_from("1e5").is_err());
+    }
+
+    #[test]
+    fn cardinal_without_rules() {
+        assert_cardinal!(Locale::Japanese, [0, 1, 2], PluralCategory::Other);
+        assert_cardinal!(Locale::Latin, [0, 1, 2], PluralCategory::Other);
+    }
+}
@@ -0,0 +1,641 @@
+use crate::catalogue::CatalogueBag;
+use crate::context::Context;
+use crate::error::Error;
+#[cfg(feature = "file")]
+use crate::loader::file::Configuration;
+use crate::locale::Locale;
+use crate::Translator;
+
+use std::fmt::Display;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+/// A reloadable translator handle.
+///
+/// The catalogue bag of the translator can be swapped at any time, e.g. after translation files
+/// have been edited, without restarting the application.
+///
+/// Translations hold a read lock on the translator, so swapping the catalogue bag waits for
+/// in-flight translations to finish, use [`ReloadableTranslator::translator`] to take a snapshot
+/// that isn't affected by swaps.
+///
+/// Clones of a `ReloadableTranslator` share the same translator, swapping the catalogue bag of one
+/// handle affects all of them.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::catalogue::Catalogue;
+/// use tarjama::catalogue::CatalogueBag;
+/// use tarjama::context;
+/// use tarjama::locale::EnglishVariant;
+/// use tarjama::locale::Locale;
+/// use tarjama::reload::ReloadableTranslator;
+/// use tarjama::Translator;
+///
+/// let mut catalogue = Catalogue::new(Locale::English(EnglishVariant::Default));
+/// catalogue.insert("messages", "greeting", "Hello, {name}!");
+///
+/// let translator = ReloadableTranslator::new(Translator::with_catalogue_bag(
+///     CatalogueBag::with_catalogues(vec![catalogue]),
+/// ));
+///
+/// let snapshot = translator.translator();
+///
+/// let mut catalogue = Catalogue::new(Locale::English(EnglishVariant::Default));
+/// catalogue.insert("messages", "greeting", "Welcome, {name}!");
+///
+/// translator.swap(CatalogueBag::with_catalogues(vec![catalogue]));
+///
+/// assert_eq!(
+///     translator.trans("en", "messages", "greeting", context!(name = "Saif")).unwrap(),
+///     "Welcome, Saif!"
+/// );
+///
+/// // the snapshot taken before the swap still uses the old catalogues.
+/// assert_eq!(
+///     snapshot.trans("en", "messages", "greeting", context!(name = "Saif")).unwrap(),
+///     "Hello, Saif!"
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct ReloadableTranslator {
+    translator: Arc<RwLock<Translator>>,
+}
+
+impl ReloadableTranslator {
+    pub fn new(translator: Translator) -> Self {
+        Self { translator: Arc::new(RwLock::new(translator)) }
+    }
+
+    /// Returns a snapshot of the current translator.
+    ///
+    /// The snapshot is not affected by subsequent swaps.
+    pub fn translator(&self) -> Translator {
+        self.translator.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Atomically replace the catalogue bag of the translator.
+    pub fn swap(&self, bag: CatalogueBag) {
+        self.translator
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .set_catalogue_bag(bag);
+    }
+
+    /// Translate a message using the current translator.
+    ///
+    /// See [`Translator::trans`].
+    pub fn trans<T, C>(
+        &self,
+        locale: T,
+        domain: &str,
+        id: &str,
+        context: C,
+    ) -> Result<String, Error>
+    where
+        T: TryInto<Locale> + Display,
+        C: Into<Context>,
+    {
+        self.translator
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .trans(locale, domain, id, context)
+    }
+
+    /// Reload the catalogue bag from a directory containing toml files, and swap it in.
+    ///
+    /// If loading fails, the current catalogue bag is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn doc() {
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    ///
+    /// translator.reload("examples/translations").await.expect("Failed to reload translations");
+    /// # }
+    /// ```
+    #[cfg(all(feature = "toml", feature = "async"))]
+    pub async fn reload<T>(&self, directory: T) -> Result<(), Error>
+    where
+        T: AsRef<std::path::Path> + 'static,
+    {
+        self.reload_with(
+            directory,
+            Configuration::default(),
+            crate::loader::toml::load_with,
+        )
+        .await
+    }
+
+    /// Reload the catalogue bag from a directory using the given configuration and loader, and
+    /// swap it in.
+    ///
+    /// `load` is called with the directory and the configuration, e.g.
+    /// [`crate::loader::toml::load_with`].
+    ///
+    /// If loading fails, the current catalogue bag is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn doc() {
+    /// use tarjama::loader::file::Configuration;
+    /// use tarjama::loader::file::Layout;
+    /// use tarjama::loader::toml::load_with;
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    /// let configuration = Configuration::new().with_layout(Layout::LocaleDirectory);
+    ///
+    /// translator
+    ///     .reload_with("examples/layouts/directory", configuration, load_with)
+    ///     .await
+    ///     .expect("Failed to reload translations");
+    /// # }
+    /// ```
+    #[cfg(all(feature = "file", feature = "async"))]
+    pub async fn reload_with<T, L, R>(
+        &self,
+        directory: T,
+        configuration: Configuration,
+        load: L,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<std::path::Path> + 'static,
+        L: FnOnce(T, Configuration) -> R,
+        R: std::future::Future<Output = Result<CatalogueBag, Error>>,
+    {
+        let bag = load(directory, configuration).await?;
+
+        self.swap(bag);
+
+        Ok(())
+    }
+
+    /// Synchronously reload the catalogue bag from a directory containing toml files, and swap it
+    /// in.
+    ///
+    /// If loading fails, the current catalogue bag is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::context;
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    ///
+    /// translator.reload_sync("examples/translations").expect("Failed to reload translations");
+    ///
+    /// assert!(translator.trans("en", "messages", "greeting", context!(name = "Saif")).is_ok());
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn reload_sync<T>(&self, directory: T) -> Result<(), Error>
+    where
+        T: AsRef<std::path::Path>,
+    {
+        self.reload_sync_with(
+            directory,
+            Configuration::default(),
+            crate::loader::toml::load_sync_with,
+        )
+    }
+
+    /// Synchronously reload the catalogue bag from a directory using the given configuration and
+    /// loader, and swap it in.
+    ///
+    /// See [`ReloadableTranslator::reload_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::context;
+    /// use tarjama::loader::file::Configuration;
+    /// use tarjama::loader::file::Layout;
+    /// use tarjama::loader::toml::load_sync_with;
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    /// let configuration = Configuration::new().with_layout(Layout::LocaleFile);
+    ///
+    /// translator
+    ///     .reload_sync_with("examples/layouts/file", configuration, load_sync_with)
+    ///     .expect("Failed to reload translations");
+    ///
+    /// assert!(translator.trans("en", "errors", "not-found", context!()).is_ok());
+    /// ```
+    #[cfg(feature = "file")]
+    pub fn reload_sync_with<T, L>(
+        &self,
+        directory: T,
+        configuration: Configuration,
+        load: L,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<std::path::Path>,
+        L: FnOnce(T, Configuration) -> Result<CatalogueBag, Error>,
+    {
+        let bag = load(directory, configuration)?;
+
+        self.swap(bag);
+
+        Ok(())
+    }
+
+    /// Watch a directory containing toml files, reloading the catalogue bag whenever a file
+    /// within it changes.
+    ///
+    /// Changes are coalesced, the catalogue bag is reloaded once no other change happened for
+    /// [`WATCH_DEBOUNCE`], so saving several files at once triggers a single reload.
+    ///
+    /// `on_reload` is called with the result of every reload, if a reload fails, the current
+    /// catalogue bag is kept.
+    ///
+    /// The directory is watched until the returned [`Watcher`] is dropped.
+    ///
+    /// Note: This method requires the `watch` feature to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    /// translator.reload_sync("examples/translations").expect("Failed to load translations");
+    ///
+    /// let watcher = translator
+    ///     .watch("examples/translations", |result| {
+    ///         if let Err(error) = result {
+    ///             eprintln!("failed to reload translations: {error}");
+    ///         }
+    ///     })
+    ///     .expect("Failed to watch translations");
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch<T, F>(
+        &self,
+        directory: T,
+        on_reload: F,
+    ) -> Result<Watcher, Error>
+    where
+        T: AsRef<std::path::Path>,
+        F: Fn(Result<(), Error>) + Send + 'static,
+    {
+        self.watch_with(
+            directory,
+            Configuration::default(),
+            crate::loader::toml::load_sync_with,
+            on_reload,
+        )
+    }
+
+    /// Watch a directory, reloading the catalogue bag using the given configuration and loader
+    /// whenever a file within it changes.
+    ///
+    /// Subdirectories are watched as well if the configuration searches them, i.e. if it is
+    /// recursive, or uses the [`Layout::LocaleDirectory`](crate::loader::file::Layout) layout.
+    ///
+    /// See [`ReloadableTranslator::watch`], and [`ReloadableTranslator::reload_sync_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tarjama::loader::file::Configuration;
+    /// use tarjama::loader::po::load_sync_with;
+    /// use tarjama::reload::ReloadableTranslator;
+    ///
+    /// let translator = ReloadableTranslator::default();
+    /// let configuration = Configuration::new().with_recursion(true);
+    ///
+    /// let watcher = translator
+    ///     .watch_with("examples/gettext", configuration, load_sync_with, |result| {
+    ///         if let Err(error) = result {
+    ///             eprintln!("failed to reload translations: {error}");
+    ///         }
+    ///     })
+    ///     .expect("Failed to watch translations");
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch_with<T, L, F>(
+        &self,
+        directory: T,
+        configuration: Configuration,
+        load: L,
+        on_reload: F,
+    ) -> Result<Watcher, Error>
+    where
+        T: AsRef<std::path::Path>,
+        L: Fn(
+                std::path::PathBuf,
+                Configuration,
+            ) -> Result<CatalogueBag, Error>
+            + Send
+            + 'static,
+        F: Fn(Result<(), Error>) + Send + 'static,
+    {
+        use crate::loader::error::Error as LoadingError;
+        use notify::EventKind;
+        use notify::RecursiveMode;
+        use notify::Watcher as _;
+        use std::sync::mpsc;
+        use std::sync::mpsc::RecvTimeoutError;
+
+        let is_change = |event: &notify::Event| {
+            matches!(
+                event.kind,
+                EventKind::Create(_)
+                    | EventKind::Modify(_)
+                    | EventKind::Remove(_)
+            )
+        };
+
+        let directory = directory.as_ref().to_path_buf();
+        let translator = self.clone();
+        let path = directory.clone();
+        let (sender, receiver) =
+            mpsc::channel::<notify::Result<notify::Event>>();
+        let mut watcher =
+            notify::recommended_watcher(sender).map_err(|e| {
+                Error::LoadingError(LoadingError::FailedToWatchDirectory(e))
+            })?;
+
+        // the sender is owned by the watcher, so the thread stops once the watcher is dropped.
+        std::thread::spawn(move || {
+            while let Ok(event) = receiver.recv() {
+                match event {
+                    Ok(event) if is_change(&event) => {}
+                    Ok(_) => continue,
+                    Err(e) => {
+                        on_reload(Err(Error::LoadingError(
+                            LoadingError::FailedToWatchDirectory(e),
+                        )));
+
+                        continue;
+                    }
+                }
+
+                loop {
+                    match receiver.recv_timeout(WATCH_DEBOUNCE) {
+                        Ok(Err(e)) => on_reload(Err(Error::LoadingError(
+                            LoadingError::FailedToWatchDirectory(e),
+                        ))),
+                        Ok(Ok(_)) => {}
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+
+                on_reload(translator.reload_sync_with(
+                    path.clone(),
+                    configuration,
+                    &load,
+                ));
+            }
+        });
+
+        let mode = if configuration.has_subdirectories() {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+
+        watcher.watch(&directory, mode).map_err(|e| {
+            Error::LoadingError(LoadingError::FailedToWatchDirectory(e))
+        })?;
+
+        Ok(Watcher { _watcher: watcher })
+    }
+}
+
+impl From<Translator> for ReloadableTranslator {
+    fn from(translator: Translator) -> Self {
+        Self::new(translator)
+    }
+}
+
+/// How long [`ReloadableTranslator::watch`] waits for further changes before reloading.
+#[cfg(feature = "watch")]
+pub const WATCH_DEBOUNCE: std::time::Duration =
+    std::time::Duration::from_millis(100);
+
+/// A guard watching a translations directory, returned by [`ReloadableTranslator::watch`].
+///
+/// The directory is no longer watched once the guard is dropped.
+#[cfg(feature = "watch")]
+pub struct Watcher {
+    _watcher: notify::RecommendedWatcher,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::catalogue::Catalogue;
+    use crate::catalogue::CatalogueBag;
+    use crate::context;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+    use crate::reload::ReloadableTranslator;
+    use crate::Translator;
+
+    fn bag(greeting: &str) -> CatalogueBag {
+        let mut catalogue =
+            Catalogue::new(Locale::English(EnglishVariant::Default));
+        catalogue.insert("messages", "greeting", greeting);
+
+        CatalogueBag::with_catalogues(vec![catalogue])
+    }
+
+    #[test]
+    fn swap() {
+        let mut translator = Translator::with_catalogue_bag(bag("Hello!"));
+        translator
+            .set_fallback_locale(Locale::English(EnglishVariant::Default));
+
+        let reloadable = ReloadableTranslator::new(translator);
+        let clone = reloadable.clone();
+        let snapshot = reloadable.translator();
+
+        clone.swap(bag("Welcome!"));
+
+        assert_eq!(
+            reloadable
+                .trans("fr", "messages", "greeting", context!())
+                .unwrap(),
+            "Welcome!"
+        );
+        assert_eq!(
+            snapshot.trans("fr", "messages", "greeting", context!()).unwrap(),
+            "Hello!"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn failed_reload() {
+        let reloadable = ReloadableTranslator::new(
+            Translator::with_catalogue_bag(bag("Hello!")),
+        );
+
+        assert!(reloadable
+            .reload_sync("examples/translations/invalid/parse")
+            .is_err());
+        assert_eq!(
+            reloadable
+                .trans("en", "messages", "greeting", context!())
+                .unwrap(),
+            "Hello!"
+        );
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch() {
+        use std::fs;
+        use std::time::Duration;
+        use std::time::Instant;
+
+        let directory = std::env::temp_dir()
+            .join(format!("tarjama-watch-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("messages.en.toml"), "greeting = \"Hello!\"")
+            .unwrap();
+
+        let reloadable = ReloadableTranslator::default();
+        reloadable.reload_sync(&directory).unwrap();
+
+        let watcher = reloadable.watch(&directory, |_| {}).unwrap();
+
+        fs::write(
+            directory.join("messages.en.toml"),
+            "greeting = \"Welcome!\"",
+        )
+        .unwrap();
+
+        let start = Instant::now();
+        let mut message = String::new();
+        while start.elapsed() < Duration::from_secs(10) {
+            message = reloadable
+                .trans("en", "messages", "greeting", context!())
+                .unwrap_or_default();
+            if message == "Welcome!" {
+                break;
+            }
+
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        drop(watcher);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(message, "Welcome!");
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_with() {
+        use crate::loader::file::Configuration;
+        use crate::loader::file::Layout;
+        use crate::loader::toml::load_sync_with;
+        use std::fs;
+        use std::time::Duration;
+        use std::time::Instant;
+
+        let directory = std::env::temp_dir()
+            .join(format!("tarjama-watch-with-{}", std::process::id()));
+        fs::create_dir_all(directory.join("en")).unwrap();
+        fs::write(
+            directory.join("en").join("messages.toml"),
+            "greeting = \"Hello!\"",
+        )
+        .unwrap();
+
+        let configuration =
+            Configuration::new().with_layout(Layout::LocaleDirectory);
+        let reloadable = ReloadableTranslator::default();
+        reloadable
+            .reload_sync_with(&directory, configuration, load_sync_with)
+            .unwrap();
+
+        let watcher = reloadable
+            .watch_with(&directory, configuration, load_sync_with, |_| {})
+            .unwrap();
+
+        fs::write(
+            directory.join("en").join("messages.toml"),
+            "greeting = \"Welcome!\"",
+        )
+        .unwrap();
+
+        let start = Instant::now();
+        let mut message = String::new();
+        while start.elapsed() < Duration::from_secs(10) {
+            message = reloadable
+                .trans("en", "messages", "greeting", context!())
+                .unwrap_or_default();
+            if message == "Welcome!" {
+                break;
+            }
+
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        drop(watcher);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(message, "Welcome!");
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_debounce() {
+        use std::fs;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let directory = std::env::temp_dir()
+            .join(format!("tarjama-watch-debounce-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("messages.en.toml"), "greeting = \"Hello!\"")
+            .unwrap();
+
+        let reloadable = ReloadableTranslator::default();
+        reloadable.reload_sync(&directory).unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let watcher = reloadable
+            .watch(&directory, move |result| sender.send(result).unwrap())
+            .unwrap();
+
+        // an invalid file, followed by several changes in a row.
+        fs::write(directory.join("messages.en.toml"), "greeting = ").unwrap();
+        for domain in ["a", "b", "c"] {
+            fs::write(
+                directory.join(format!("{domain}.en.toml")),
+                "title = \"Title\"",
+            )
+            .unwrap();
+        }
+
+        let result = receiver.recv_timeout(Duration::from_secs(10));
+        let reloads = 1 + receiver
+            .recv_timeout(Duration::from_millis(500))
+            .into_iter()
+            .count();
+
+        drop(watcher);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(matches!(result, Ok(Err(_))));
+        assert_eq!(reloads, 1);
+        assert_eq!(
+            reloadable
+                .trans("en", "messages", "greeting", context!())
+                .unwrap(),
+            "Hello!"
+        );
+    }
+}
@@ -0,0 +1,656 @@
+use crate::error::Error;
+use crate::locale::error::ParseError;
+use crate::locale::error::Part;
+use crate::locale::Locale;
+
+use core::result::Result as CoreResult;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::str::FromStr;
+
+/// The maximum length of a language tag, in its canonical form.
+const CAPACITY: usize = 64;
+
+/// A BCP 47 language tag, made of a language subtag, followed by optional script, region and
+/// variant subtags, e.g. `zh_Hant_TW`, `sr_Latn` or `sl_IT_rozaj`, and an optional unicode
+/// extension, e.g. `ar_u_nu_latn`.
+///
+/// Subtags are parsed case-insensitively, and may be separated by either `-` or `_`, the tag is
+/// kept in its canonical form: a lowercase language, a titlecase script, an uppercase region,
+/// lowercase variants and unicode extension keywords sorted by key, separated by `_`, as used by
+/// [`Locale`] codes.
+///
+/// Only the unicode ( `-u-` ) extension is supported, and only its keywords, e.g. `nu_latn` or
+/// `hc_h23`, other extensions, private use subtags and unicode extension attributes are rejected.
+///
+/// A `LanguageTag` is only checked to be well-formed, the subtags are not checked against the
+/// IANA language subtag registry.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::tag::LanguageTag;
+///
+/// let tag: LanguageTag = "ZH-hant-tw".try_into().unwrap();
+///
+/// assert_eq!(tag.language(), "zh");
+/// assert_eq!(tag.script(), Some("Hant"));
+/// assert_eq!(tag.region(), Some("TW"));
+/// assert_eq!(tag.to_string(), "zh_Hant_TW");
+///
+/// let tag: LanguageTag = "es_419".try_into().unwrap();
+///
+/// assert_eq!(tag.script(), None);
+/// assert_eq!(tag.region(), Some("419"));
+///
+/// let tag: LanguageTag = "de-CH-1996".try_into().unwrap();
+///
+/// assert_eq!(tag.variants().collect::<Vec<_>>(), vec!["1996"]);
+///
+/// let tag: LanguageTag = "en-US-u-hc-h23-ca-buddhist".try_into().unwrap();
+///
+/// assert_eq!(tag.extension("hc"), Some("h23"));
+/// assert_eq!(tag.to_string(), "en_US_u_ca_buddhist_hc_h23");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LanguageTag {
+    buffer: [u8; CAPACITY],
+    length: u8,
+    language: u8,
+    script: u8,
+    region: u8,
+    variants: u8,
+}
+
+impl LanguageTag {
+    /// Return the language subtag, e.g. `zh`.
+    pub fn language(&self) -> &str {
+        self.slice(0, self.language)
+    }
+
+    /// Return the script subtag, if any, e.g. `Hant`.
+    pub fn script(&self) -> Option<&str> {
+        self.subtag(self.language, self.script)
+    }
+
+    /// Return the region subtag, if any, e.g. `TW` or `419`.
+    pub fn region(&self) -> Option<&str> {
+        self.subtag(self.script, self.region)
+    }
+
+    /// Return an iterator over the variant subtags, e.g. `1996`.
+    pub fn variants(&self) -> impl Iterator<Item = &str> {
+        self.slice(self.region, self.variants)
+            .split('_')
+            .filter(|variant| !variant.is_empty())
+    }
+
+    /// Return the value of a unicode extension keyword, if any, e.g. `latn` for the `nu` key of
+    /// `ar_u_nu_latn`.
+    ///
+    /// A key without a value has an empty value, values made of multiple subtags are separated by
+    /// `_`, e.g. `islamic_civil` for the `ca` key of `ar_u_ca_islamic_civil`.
+    pub fn extension(&self, key: &str) -> Option<&str> {
+        self.extensions().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// Return an iterator over the unicode extension keywords, as `(key, value)` pairs, sorted by
+    /// key.
+    pub fn extensions(&self) -> impl Iterator<Item = (&str, &str)> {
+        // skip the `_u` singleton.
+        let extensions = match self.length - self.variants {
+            0 => "",
+            _ => self.slice(self.variants + 3, self.length),
+        };
+
+        Keywords { subtags: extensions }
+    }
+
+    /// Return the tag with the given unicode extension keyword, replacing the previous value of the
+    /// key if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::tag::LanguageTag;
+    ///
+    /// let tag: LanguageTag = "ar-EG".try_into().unwrap();
+    ///
+    /// let tag = tag.with_extension("nu", "latn").unwrap();
+    /// assert_eq!(tag.to_string(), "ar_EG_u_nu_latn");
+    ///
+    /// let tag = tag.with_extension("ca", "islamic-civil").unwrap();
+    /// assert_eq!(tag.to_string(), "ar_EG_u_ca_islamic_civil_nu_latn");
+    ///
+    /// let tag = tag.with_extension("nu", "arab").unwrap();
+    /// assert_eq!(tag.to_string(), "ar_EG_u_ca_islamic_civil_nu_arab");
+    ///
+    /// assert!(tag.with_extension("numbers", "latn").is_err());
+    /// ```
+    pub fn with_extension(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CoreResult<LanguageTag, Error> {
+        let well_formed = key.len() == 2
+            && key.as_bytes()[0].is_ascii_alphanumeric()
+            && key.as_bytes()[1].is_ascii_alphabetic();
+        if !well_formed {
+            return Err(Error::InvalidLocale(format!("{self}_u_{key}")));
+        }
+
+        let mut tag = self.without_extensions().as_str().to_string();
+        tag.push_str("_u");
+        for (k, v) in self.extensions().filter(|(k, _)| *k != key) {
+            tag.push('_');
+            tag.push_str(k);
+            if !v.is_empty() {
+                tag.push('_');
+                tag.push_str(v);
+            }
+        }
+
+        tag.push('_');
+        tag.push_str(key);
+        if !value.is_empty() {
+            tag.push('_');
+            tag.push_str(value);
+        }
+
+        tag.try_into()
+    }
+
+    /// Return the tag without its unicode extension.
+    pub fn without_extensions(&self) -> LanguageTag {
+        let mut tag = *self;
+        tag.truncate(self.variants);
+
+        tag
+    }
+
+    /// Return the tag in its canonical form, e.g. `zh_Hant_TW`.
+    pub fn as_str(&self) -> &str {
+        self.slice(0, self.length)
+    }
+
+    /// Return the tag without its last subtag, or `None` if the tag only has a language subtag.
+    ///
+    /// The unicode extension is removed as a whole, e.g. the parent of `ar_EG_u_nu_latn` is
+    /// `ar_EG`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tarjama::tag::LanguageTag;
+    ///
+    /// let tag: LanguageTag = "zh-Hant-TW".try_into().unwrap();
+    ///
+    /// let parent = tag.parent().unwrap();
+    /// assert_eq!(parent.to_string(), "zh_Hant");
+    ///
+    /// let parent = parent.parent().unwrap();
+    /// assert_eq!(parent.to_string(), "zh");
+    ///
+    /// assert_eq!(parent.parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<LanguageTag> {
+        if self.length != self.variants {
+            return Some(self.without_extensions());
+        }
+
+        let length = self.as_str().rfind('_')? as u8;
+
+        let mut parent = *self;
+        parent.truncate(length);
+
+        Some(parent)
+    }
+
+    fn truncate(&mut self, length: u8) {
+        self.buffer[length as usize..].fill(0);
+        self.length = length;
+        self.script = self.script.min(length);
+        self.region = self.region.min(length);
+        self.variants = self.variants.min(length);
+    }
+
+    fn subtag(&self, start: u8, end: u8) -> Option<&str> {
+        if start == end {
+            None
+        } else {
+            // skip the separator.
+            Some(self.slice(start + 1, end))
+        }
+    }
+
+    fn slice(&self, start: u8, end: u8) -> &str {
+        // the buffer only ever contains ascii characters.
+        std::str::from_utf8(&self.buffer[start as usize..end as usize])
+            .unwrap_or_default()
+    }
+
+    fn push(&mut self, subtag: &str) -> bool {
+        let start = self.length as usize;
+        let separator = usize::from(start != 0);
+        if start + separator + subtag.len() > CAPACITY {
+            return false;
+        }
+
+        if separator == 1 {
+            self.buffer[start] = b'_';
+        }
+
+        self.buffer[start + separator..start + separator + subtag.len()]
+            .copy_from_slice(subtag.as_bytes());
+        self.length = (start + separator + subtag.len()) as u8;
+
+        true
+    }
+
+    fn parse(value: &str) -> CoreResult<LanguageTag, ParseError> {
+        let error = |part, subtag: &str| ParseError::new(value, part, subtag);
+
+        let mut tag = LanguageTag {
+            buffer: [0; CAPACITY],
+            length: 0,
+            language: 0,
+            script: 0,
+            region: 0,
+            variants: 0,
+        };
+
+        let mut subtags = value.split(['-', '_']).peekable();
+
+        let language = subtags.next().unwrap_or_default();
+        if !matches!(language.len(), 2..=3 | 5..=8)
+            || !language.bytes().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(error(Part::Language, language));
+        }
+
+        tag.push(&language.to_ascii_lowercase());
+        tag.language = tag.length;
+
+        if let Some(script) = subtags.next_if(|subtag| {
+            subtag.len() == 4
+                && subtag.bytes().all(|c| c.is_ascii_alphabetic())
+        }) {
+            let mut script = script.to_ascii_lowercase();
+            script[..1].make_ascii_uppercase();
+
+            tag.push(&script);
+        }
+        tag.script = tag.length;
+
+        if let Some(region) = subtags.next_if(|subtag| {
+            (subtag.len() == 2
+                && subtag.bytes().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3
+                    && subtag.bytes().all(|c| c.is_ascii_digit()))
+        }) {
+            tag.push(&region.to_ascii_uppercase());
+        }
+        tag.region = tag.length;
+        tag.variants = tag.length;
+
+        while let Some(variant) = subtags.next_if(|subtag| subtag.len() > 1) {
+            let well_formed =
+                variant.bytes().all(|c| c.is_ascii_alphanumeric())
+                    && match variant.len() {
+                        5..=8 => true,
+                        4 => variant.as_bytes()[0].is_ascii_digit(),
+                        _ => false,
+                    };
+
+            if !well_formed {
+                // a subtag right after the language, or the script, is either a script or a
+                // region, depending on its length.
+                let part = match variant.len() {
+                    _ if tag.variants != tag.script => Part::Variant,
+                    2..=3 => Part::Region,
+                    4 if tag.script == tag.language => Part::Script,
+                    _ => Part::Variant,
+                };
+
+                return Err(error(part, variant));
+            }
+
+            let canonical = variant.to_ascii_lowercase();
+            if tag.variants().any(|existing| existing == canonical)
+                || !tag.push(&canonical)
+            {
+                return Err(error(Part::Variant, variant));
+            }
+
+            tag.variants = tag.length;
+        }
+
+        match subtags.next() {
+            None => return Ok(tag),
+            Some("u" | "U") => tag.push("u"),
+            Some(subtag) if subtag.len() == 1 => {
+                return Err(error(Part::Extension, subtag))
+            }
+            Some(subtag) => return Err(error(Part::Variant, subtag)),
+        };
+
+        let mut keywords: Vec<(String, Vec<String>)> = vec![];
+        for subtag in subtags {
+            if !subtag.bytes().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(error(Part::Extension, subtag));
+            }
+
+            let canonical = subtag.to_ascii_lowercase();
+            match canonical.len() {
+                2 if canonical.as_bytes()[1].is_ascii_alphabetic() => {
+                    if keywords.iter().any(|(key, _)| *key == canonical) {
+                        return Err(error(Part::Extension, subtag));
+                    }
+
+                    keywords.push((canonical, vec![]));
+                }
+                3..=8 if !keywords.is_empty() => {
+                    if let Some((_, values)) = keywords.last_mut() {
+                        values.push(canonical);
+                    }
+                }
+                _ => return Err(error(Part::Extension, subtag)),
+            }
+        }
+
+        if keywords.is_empty() {
+            return Err(error(Part::Extension, "u"));
+        }
+
+        keywords.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (key, values) in keywords {
+            if !tag.push(&key) {
+                return Err(error(Part::Extension, &key));
+            }
+
+            for value in values {
+                if !tag.push(&value) {
+                    return Err(error(Part::Extension, &value));
+                }
+            }
+        }
+
+        Ok(tag)
+    }
+}
+
+/// An iterator over the unicode extension keywords of a tag.
+struct Keywords<'a> {
+    subtags: &'a str,
+}
+
+impl<'a> Iterator for Keywords<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.subtags.is_empty() {
+            return None;
+        }
+
+        // keys are the only subtags of 2 characters.
+        let key = &self.subtags[..2];
+        let rest = self.subtags[2..].strip_prefix('_').unwrap_or_default();
+        let end = rest
+            .split('_')
+            .take_while(|subtag| subtag.len() > 2)
+            .map(|subtag| subtag.len() + 1)
+            .sum::<usize>();
+
+        let value = &rest[..end.saturating_sub(1)];
+        self.subtags = rest.get(end..).unwrap_or_default();
+
+        Some((key, value))
+    }
+}
+
+/// Parse a `LanguageTag` from a string.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::error::Part;
+/// use tarjama::tag::LanguageTag;
+///
+/// let tag: LanguageTag = "sr-latn-rs".parse().unwrap();
+/// assert_eq!(tag.to_string(), "sr_Latn_RS");
+///
+/// let error = "sr-latn-rs-x-private".parse::<LanguageTag>().unwrap_err();
+/// assert_eq!(error.part(), Part::Extension);
+/// assert_eq!(error.subtag(), "x");
+/// ```
+impl FromStr for LanguageTag {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> CoreResult<Self, Self::Err> {
+        LanguageTag::parse(value)
+    }
+}
+
+/// Create a `LanguageTag` from a string reference.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::tag::LanguageTag;
+///
+/// let tag: LanguageTag = "sr-latn".try_into().unwrap();
+/// assert_eq!(tag.to_string(), "sr_Latn");
+///
+/// let tag: LanguageTag = "en_us".try_into().unwrap();
+/// assert_eq!(tag.to_string(), "en_US");
+///
+/// assert!(LanguageTag::try_from("e").is_err());
+/// assert!(LanguageTag::try_from("en--US").is_err());
+/// assert!(LanguageTag::try_from("en-US-foo").is_err());
+/// ```
+impl TryFrom<&str> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(value: &str) -> CoreResult<Self, Self::Error> {
+        Ok(LanguageTag::parse(value)?)
+    }
+}
+
+impl TryFrom<String> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(value: String) -> CoreResult<Self, Self::Error> {
+        let tag = &*value;
+
+        tag.try_into()
+    }
+}
+
+/// Create a `LanguageTag` from a `Locale`.
+///
+/// # Examples
+///
+/// ```
+/// use tarjama::locale::Locale;
+/// use tarjama::locale::SpanishVariant;
+/// use tarjama::tag::LanguageTag;
+///
+/// let tag = LanguageTag::from(Locale::Spanish(SpanishVariant::LatinAmerica));
+///
+/// assert_eq!(tag.language(), "es");
+/// assert_eq!(tag.region(), Some("419"));
+/// ```
+impl From<Locale> for LanguageTag {
+    fn from(locale: Locale) -> Self {
+        match locale {
+            Locale::Tag(tag) => tag,
+            // locale codes are all well-formed language tags.
+            _ => LanguageTag::parse(locale.code())
+                .expect("locale code is a well-formed language tag"),
+        }
+    }
+}
+
+impl From<&Locale> for LanguageTag {
+    fn from(locale: &Locale) -> Self {
+        (*locale).into()
+    }
+}
+
+/// Serialize a `LanguageTag` in its canonical form, e.g. `zh_Hant_TW`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LanguageTag {
+    fn serialize<S>(&self, serializer: S) -> CoreResult<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LanguageTag {
+    fn deserialize<D>(deserializer: D) -> CoreResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tag = String::deserialize(deserializer)?;
+
+        LanguageTag::try_from(tag).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Debug for LanguageTag {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.debug_tuple("LanguageTag").field(&self.as_str()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::locale::error::Part;
+    use crate::locale::EnglishVariant;
+    use crate::locale::Locale;
+    use crate::tag::LanguageTag;
+
+    #[test]
+    fn parsing() {
+        for (value, expected) in [
+            ("ja", "ja"),
+            ("JA-jp", "ja_JP"),
+            ("zh-hant-tw", "zh_Hant_TW"),
+            ("sr_LATN", "sr_Latn"),
+            ("es-419", "es_419"),
+            ("sl-IT-rozaj-biske", "sl_IT_rozaj_biske"),
+            ("de-1996", "de_1996"),
+        ] {
+            let tag: LanguageTag = value.try_into().unwrap();
+
+            assert_eq!(tag.as_str(), expected);
+            assert_eq!(LanguageTag::try_from(expected).unwrap(), tag);
+        }
+
+        for value in [
+            "",
+            "e",
+            "en-",
+            "en_US_",
+            "en-Latn-Latn",
+            "en-US-GB",
+            "en-1",
+            "en-US-rozaj-rozaj",
+            "en-US-fönf",
+            "en-u",
+            "en-u-foo",
+            "en-u-nu-latn-nu-arab",
+            "en-u-nu-latn-u-hc-h23",
+            "en-x-private",
+            "en-t-ja",
+        ] {
+            assert!(LanguageTag::try_from(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn locale_conversion() {
+        let tag = LanguageTag::from(Locale::English(EnglishVariant::World));
+        assert_eq!(tag.as_str(), "en_001");
+        assert_eq!(
+            Locale::try_from(tag).unwrap(),
+            Locale::English(EnglishVariant::World)
+        );
+
+        let tag: LanguageTag = "zh-Hant-TW".try_into().unwrap();
+        assert_eq!(Locale::try_from(tag).unwrap(), Locale::Tag(tag));
+        assert_eq!(LanguageTag::from(Locale::Tag(tag)), tag);
+
+        let tag: LanguageTag = "xx-XX".try_into().unwrap();
+        assert!(Locale::try_from(tag).is_err());
+    }
+
+    #[test]
+    fn extensions() {
+        for (value, expected) in [
+            ("ar-u-nu-latn", "ar_u_nu_latn"),
+            ("EN-us-U-HC-h23-CA-buddhist", "en_US_u_ca_buddhist_hc_h23"),
+            ("ar_u_ca_islamic_civil_nu_latn", "ar_u_ca_islamic_civil_nu_latn"),
+            ("de-DE-1996-u-co-phonebk-kb", "de_DE_1996_u_co_phonebk_kb"),
+        ] {
+            let tag: LanguageTag = value.try_into().unwrap();
+
+            assert_eq!(tag.as_str(), expected);
+            assert_eq!(LanguageTag::try_from(tag.to_string()).unwrap(), tag);
+        }
+
+        let tag: LanguageTag =
+            "de-DE-1996-u-kb-co-phonebk".try_into().unwrap();
+        assert_eq!(tag.variants().collect::<Vec<_>>(), vec!["1996"]);
+        assert_eq!(
+            tag.extensions().collect::<Vec<_>>(),
+            vec![("co", "phonebk"), ("kb", "")]
+        );
+        assert_eq!(tag.without_extensions().as_str(), "de_DE_1996");
+        assert_eq!(tag.parent().unwrap().as_str(), "de_DE_1996");
+
+        let tag: LanguageTag =
+            "ar-u-ca-islamic-civil-nu-latn".try_into().unwrap();
+        assert_eq!(tag.extension("ca"), Some("islamic_civil"));
+        assert_eq!(tag.extension("nu"), Some("latn"));
+        assert_eq!(tag.extension("hc"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (value, part, subtag) in [
+            ("", Part::Language, ""),
+            ("e", Part::Language, "e"),
+            ("engl", Part::Language, "engl"),
+            ("en1", Part::Language, "en1"),
+            ("zh-Ha1t", Part::Script, "Ha1t"),
+            ("en-U$", Part::Region, "U$"),
+            ("en-1234a-GB", Part::Variant, "GB"),
+            ("en-US-GB", Part::Variant, "GB"),
+            ("sl-rozaj-rozaj", Part::Variant, "rozaj"),
+            ("en-", Part::Variant, ""),
+            ("en-x-private", Part::Extension, "x"),
+            ("en-u", Part::Extension, "u"),
+            ("en-u-latn", Part::Extension, "latn"),
+            ("en-u-nu-latn-nu-arab", Part::Extension, "nu"),
+            ("en-u-nu-l@tn", Part::Extension, "l@tn"),
+        ] {
+            let error = value.parse::<LanguageTag>().unwrap_err();
+
+            assert_eq!(error.input(), value);
+            assert_eq!(error.part(), part, "{value}");
+            assert_eq!(error.subtag(), subtag, "{value}");
+        }
+    }
+}